@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: Apache-2.0
+
+/// Whether a function reads or modifies contract state, and whether it accepts hbar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AbiStateMutability {
+    /// Doesn't read or modify state.
+    Pure,
+
+    /// Reads, but doesn't modify, state.
+    View,
+
+    /// May modify state, but doesn't accept hbar.
+    #[default]
+    NonPayable,
+
+    /// May modify state, and accepts hbar.
+    Payable,
+}
+
+impl AbiStateMutability {
+    /// Returns `true` if the function doesn't modify state,
+    /// so it can be called with a query rather than a transaction.
+    #[must_use]
+    pub fn is_read_only(self) -> bool {
+        matches!(self, Self::Pure | Self::View)
+    }
+}
@@ -0,0 +1,303 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// The type of a Solidity ABI parameter, such as `uint256`, `bytes32[]` or `(address,uint256)[3]`.
+///
+/// Parses from, and displays as, the canonical type string used in function signatures.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AbiType {
+    /// `address`.
+    Address,
+
+    /// `bool`.
+    Bool,
+
+    /// `int<M>`, where `M` is the size in bits.
+    Int(usize),
+
+    /// `uint<M>`, where `M` is the size in bits.
+    Uint(usize),
+
+    /// `bytes<M>`, where `M` is the size in bytes.
+    FixedBytes(usize),
+
+    /// `bytes`.
+    Bytes,
+
+    /// `string`.
+    String,
+
+    /// `function`, an address followed by a function selector.
+    Function,
+
+    /// `T[]`.
+    Array(Box<AbiType>),
+
+    /// `T[k]`.
+    FixedArray(Box<AbiType>, usize),
+
+    /// `(T1,T2,...,Tn)`, which is how structs are encoded.
+    Tuple(Vec<AbiType>),
+}
+
+impl AbiType {
+    /// Returns `true` if values of this type are encoded out of line, after the static values.
+    #[must_use]
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            Self::Bytes | Self::String | Self::Array(_) => true,
+            Self::FixedArray(ty, _) => ty.is_dynamic(),
+            Self::Tuple(types) => types.iter().any(Self::is_dynamic),
+            _ => false,
+        }
+    }
+
+    /// The number of bytes a value of this type takes up in the head of its enclosing tuple.
+    ///
+    /// Saturates at `usize::MAX`, which is only possible for a type that wasn't parsed (parsing rejects those).
+    #[must_use]
+    pub fn head_len(&self) -> usize {
+        self.checked_head_len().unwrap_or(usize::MAX)
+    }
+
+    /// Returns [`head_len`](Self::head_len), or `None` if it overflows.
+    fn checked_head_len(&self) -> Option<usize> {
+        if self.is_dynamic() {
+            return Some(32);
+        }
+
+        match self {
+            Self::FixedArray(ty, len) => ty.checked_head_len()?.checked_mul(*len),
+            Self::Tuple(types) => {
+                types.iter().try_fold(0_usize, |sum, ty| sum.checked_add(ty.checked_head_len()?))
+            }
+            _ => Some(32),
+        }
+    }
+
+    /// Parse the `type` of a parameter in ABI JSON, where structs are written as `tuple`
+    /// (or `tuple[]`, `tuple[2]`, ...) and their fields are given separately as `components`.
+    ///
+    /// # Errors
+    /// - If `ty` isn't a valid ABI type.
+    pub fn from_json_type(ty: &str, components: &[AbiType]) -> Result<Self, ParseError> {
+        match ty.strip_prefix("tuple") {
+            Some(suffix) => {
+                Self::from_str(&format!("{}{suffix}", Self::Tuple(components.to_vec())))
+            }
+            None => Self::from_str(ty),
+        }
+    }
+}
+
+impl fmt::Display for AbiType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Address => f.write_str("address"),
+            Self::Bool => f.write_str("bool"),
+            Self::Int(bits) => write!(f, "int{bits}"),
+            Self::Uint(bits) => write!(f, "uint{bits}"),
+            Self::FixedBytes(len) => write!(f, "bytes{len}"),
+            Self::Bytes => f.write_str("bytes"),
+            Self::String => f.write_str("string"),
+            Self::Function => f.write_str("function"),
+            Self::Array(ty) => write!(f, "{ty}[]"),
+            Self::FixedArray(ty, len) => write!(f, "{ty}[{len}]"),
+            Self::Tuple(types) => {
+                f.write_str("(")?;
+
+                for (i, ty) in types.iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(",")?;
+                    }
+
+                    write!(f, "{ty}")?;
+                }
+
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+impl FromStr for AbiType {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (ty, rest) = parse_type(s)?;
+
+        if !rest.is_empty() {
+            return Err(ParseError::new(format!("unexpected `{rest}` after ABI type in `{s}`")));
+        }
+
+        Ok(ty)
+    }
+}
+
+/// Parse a single type from the start of `s`, returning it and whatever follows it.
+///
+/// # Errors
+/// - If `s` doesn't start with a valid ABI type.
+pub fn parse_type(s: &str) -> Result<(AbiType, &str), ParseError> {
+    let (mut ty, mut rest) = match s.strip_prefix('(') {
+        Some(s) => parse_tuple(s)?,
+        None => {
+            let end = s.find(|it: char| !it.is_ascii_alphanumeric()).unwrap_or(s.len());
+            (parse_elementary(&s[..end])?, &s[end..])
+        }
+    };
+
+    while let Some(s) = rest.strip_prefix('[') {
+        let end = s
+            .find(']')
+            .ok_or_else(|| ParseError::new(format!("unclosed `[` in ABI type `{s}`")))?;
+
+        ty = match &s[..end] {
+            "" => AbiType::Array(Box::new(ty)),
+            len => {
+                let len = len.parse().map_err(|_| {
+                    ParseError::new(format!("invalid array length `{len}` in ABI type"))
+                })?;
+
+                AbiType::FixedArray(Box::new(ty), len)
+            }
+        };
+
+        rest = &s[end + 1..];
+    }
+
+    if ty.checked_head_len().is_none() {
+        return Err(ParseError::new(format!("ABI type `{ty}` is too large")));
+    }
+
+    Ok((ty, rest))
+}
+
+// `s` starts just after the opening `(`.
+fn parse_tuple(mut s: &str) -> Result<(AbiType, &str), ParseError> {
+    let mut types = Vec::new();
+
+    if let Some(rest) = s.strip_prefix(')') {
+        return Ok((AbiType::Tuple(types), rest));
+    }
+
+    loop {
+        let (ty, rest) = parse_type(s)?;
+        types.push(ty);
+
+        if let Some(rest) = rest.strip_prefix(',') {
+            s = rest;
+        } else if let Some(rest) = rest.strip_prefix(')') {
+            return Ok((AbiType::Tuple(types), rest));
+        } else {
+            return Err(ParseError::new(format!(
+                "expected `,` or `)` in ABI type, found `{rest}`"
+            )));
+        }
+    }
+}
+
+fn parse_elementary(name: &str) -> Result<AbiType, ParseError> {
+    let ty = match name {
+        "address" => AbiType::Address,
+        "bool" => AbiType::Bool,
+        "bytes" => AbiType::Bytes,
+        "string" => AbiType::String,
+        "function" => AbiType::Function,
+        "int" => AbiType::Int(256),
+        "uint" => AbiType::Uint(256),
+        _ => {
+            if let Some(bits) = name.strip_prefix("uint").and_then(parse_int_bits) {
+                AbiType::Uint(bits)
+            } else if let Some(bits) = name.strip_prefix("int").and_then(parse_int_bits) {
+                AbiType::Int(bits)
+            } else if let Some(len) = name.strip_prefix("bytes").and_then(parse_fixed_bytes_len) {
+                AbiType::FixedBytes(len)
+            } else {
+                return Err(ParseError::new(format!("unknown ABI type `{name}`")));
+            }
+        }
+    };
+
+    Ok(ty)
+}
+
+fn parse_int_bits(bits: &str) -> Option<usize> {
+    bits.parse().ok().filter(|bits| (8..=256).contains(bits) && bits % 8 == 0)
+}
+
+fn parse_fixed_bytes_len(len: &str) -> Option<usize> {
+    len.parse().ok().filter(|len| (1..=32).contains(len))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::AbiType;
+
+    #[test]
+    fn parse_elementary() {
+        assert_eq!(AbiType::from_str("address").unwrap(), AbiType::Address);
+        assert_eq!(AbiType::from_str("uint").unwrap(), AbiType::Uint(256));
+        assert_eq!(AbiType::from_str("int24").unwrap(), AbiType::Int(24));
+        assert_eq!(AbiType::from_str("bytes7").unwrap(), AbiType::FixedBytes(7));
+        assert_eq!(AbiType::from_str("bytes").unwrap(), AbiType::Bytes);
+    }
+
+    #[test]
+    fn parse_nested() {
+        let ty = AbiType::from_str("(address,uint256[2])[][3]").unwrap();
+
+        assert_eq!(
+            ty,
+            AbiType::FixedArray(
+                Box::new(AbiType::Array(Box::new(AbiType::Tuple(vec![
+                    AbiType::Address,
+                    AbiType::FixedArray(Box::new(AbiType::Uint(256)), 2),
+                ])))),
+                3
+            )
+        );
+
+        assert_eq!(ty.to_string(), "(address,uint256[2])[][3]");
+        assert!(!AbiType::from_str("(address,uint256[2])[3]").unwrap().is_dynamic());
+        assert!(ty.is_dynamic());
+    }
+
+    #[test]
+    fn parse_json_tuple() {
+        let ty = AbiType::from_json_type("tuple[]", &[AbiType::Uint(8), AbiType::String]).unwrap();
+
+        assert_eq!(ty.to_string(), "(uint8,string)[]");
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for ty in ["uint7", "uint264", "bytes33", "bytes0", "address[", "(uint8", "uint8)", "foo"] {
+            assert!(AbiType::from_str(ty).is_err(), "{ty}");
+        }
+    }
+
+    #[test]
+    fn parse_too_large() {
+        assert!(AbiType::from_str(&format!("uint256[{}]", usize::MAX)).is_err());
+        assert!(AbiType::from_str("(uint8[4294967296],bool)[4294967296]").is_err());
+        assert!(
+            AbiType::from_json_type(&format!("tuple[{}]", usize::MAX), &[AbiType::Bool]).is_err()
+        );
+
+        // dynamic elements are stored as an offset, so they take up one word no matter how many there are.
+        assert!(AbiType::from_str(&format!("string[{}]", usize::MAX)).is_ok());
+    }
+
+    #[test]
+    fn head_len() {
+        assert_eq!(AbiType::from_str("(uint8,bytes32)[3]").unwrap().head_len(), 6 * 32);
+        assert_eq!(AbiType::from_str("(uint8,bytes)[3]").unwrap().head_len(), 32);
+    }
+}
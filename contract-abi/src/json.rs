@@ -0,0 +1,378 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+use crate::{
+    AbiStateMutability,
+    AbiType,
+    ParseError,
+};
+
+/// The ABI of a Solidity contract in the standard JSON format, as produced by `solc --abi`.
+///
+/// This only describes the contract, the SDK's `ContractAbi` is built from it to encode and decode values.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct JsonAbi {
+    /// The functions of the contract, in the order they were declared in the ABI.
+    pub functions: Vec<JsonFunction>,
+
+    /// The constructor of the contract, if it declares one.
+    pub constructor: Option<JsonConstructor>,
+
+    /// The events of the contract, in the order they were declared in the ABI.
+    pub events: Vec<JsonEvent>,
+
+    /// The custom errors of the contract, in the order they were declared in the ABI.
+    pub errors: Vec<JsonError>,
+}
+
+impl JsonAbi {
+    /// Parse an ABI from its standard JSON representation.
+    ///
+    /// Accepts either the ABI array itself, or a compiler artifact with the array in an `abi` field.
+    ///
+    /// # Errors
+    /// - If `json` isn't a valid ABI.
+    pub fn from_json(json: &str) -> Result<Self, ParseError> {
+        let entries =
+            match serde_json::from_str(json).map_err(|error| ParseError::new(error.to_string()))? {
+                de::AbiJson::Entries(entries) | de::AbiJson::Artifact { abi: entries } => entries,
+            };
+
+        let mut abi = Self::default();
+
+        for entry in entries {
+            match entry {
+                de::AbiEntry::Function { name, inputs, outputs, state_mutability } => {
+                    abi.functions.push(JsonFunction {
+                        name,
+                        inputs: de::params(inputs)?,
+                        outputs: de::params(outputs)?,
+                        state_mutability: de::state_mutability(state_mutability.as_deref())?,
+                    });
+                }
+
+                de::AbiEntry::Constructor { inputs, state_mutability } => {
+                    abi.constructor = Some(JsonConstructor {
+                        inputs: de::params(inputs)?,
+                        state_mutability: de::state_mutability(state_mutability.as_deref())?,
+                    });
+                }
+
+                de::AbiEntry::Event { name, inputs, anonymous } => {
+                    abi.events.push(JsonEvent { name, inputs: de::params(inputs)?, anonymous });
+                }
+
+                de::AbiEntry::Error { name, inputs } => {
+                    abi.errors.push(JsonError { name, inputs: de::params(inputs)? });
+                }
+
+                de::AbiEntry::Other => {}
+            }
+        }
+
+        Ok(abi)
+    }
+}
+
+/// A parameter of a function, constructor, event, error or return value in ABI JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonParam {
+    /// The name of the parameter, which may be empty.
+    pub name: String,
+
+    /// The type of the parameter, with structs resolved to tuples.
+    pub kind: AbiType,
+
+    /// The named fields of the parameter, if it's a struct (or an array of structs).
+    pub components: Vec<JsonParam>,
+
+    /// Whether the parameter is stored in the log's topics rather than its data, only for event parameters.
+    pub indexed: bool,
+}
+
+/// A function in ABI JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonFunction {
+    /// The name of the function.
+    pub name: String,
+
+    /// The parameters of the function.
+    pub inputs: Vec<JsonParam>,
+
+    /// The return values of the function.
+    pub outputs: Vec<JsonParam>,
+
+    /// Whether the function reads or modifies contract state, and whether it accepts hbar.
+    pub state_mutability: AbiStateMutability,
+}
+
+impl JsonFunction {
+    /// Returns the canonical signature of the function, for instance `transfer(address,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        signature(&self.name, &self.inputs)
+    }
+
+    /// Returns the first 4 bytes of the Keccak-256 hash of the signature, which calls to the function start with.
+    #[must_use]
+    pub fn selector(&self) -> [u8; 4] {
+        let hash = Keccak256::digest(self.signature());
+
+        [hash[0], hash[1], hash[2], hash[3]]
+    }
+}
+
+/// The constructor of a contract in ABI JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonConstructor {
+    /// The parameters of the constructor.
+    pub inputs: Vec<JsonParam>,
+
+    /// Whether the constructor accepts hbar.
+    pub state_mutability: AbiStateMutability,
+}
+
+/// An event in ABI JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonEvent {
+    /// The name of the event.
+    pub name: String,
+
+    /// The parameters of the event.
+    pub inputs: Vec<JsonParam>,
+
+    /// Whether the event was declared `anonymous`, so its logs don't start with [`topic`](Self::topic).
+    pub anonymous: bool,
+}
+
+impl JsonEvent {
+    /// Returns the canonical signature of the event, for instance `Transfer(address,address,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        signature(&self.name, &self.inputs)
+    }
+
+    /// Returns the Keccak-256 hash of the signature, which is the first topic of the event's logs.
+    #[must_use]
+    pub fn topic(&self) -> [u8; 32] {
+        Keccak256::digest(self.signature()).into()
+    }
+}
+
+/// A custom error in ABI JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonError {
+    /// The name of the error.
+    pub name: String,
+
+    /// The parameters of the error.
+    pub inputs: Vec<JsonParam>,
+}
+
+fn signature(name: &str, params: &[JsonParam]) -> String {
+    format!("{name}{}", AbiType::Tuple(params.iter().map(|it| it.kind.clone()).collect()))
+}
+
+mod de {
+    use super::JsonParam;
+    use crate::{
+        AbiStateMutability,
+        AbiType,
+        ParseError,
+    };
+
+    #[derive(serde_derive::Deserialize)]
+    #[serde(untagged)]
+    pub(super) enum AbiJson {
+        Entries(Vec<AbiEntry>),
+        Artifact { abi: Vec<AbiEntry> },
+    }
+
+    #[derive(serde_derive::Deserialize)]
+    #[serde(tag = "type", rename_all = "camelCase")]
+    pub(super) enum AbiEntry {
+        #[serde(rename_all = "camelCase")]
+        Function {
+            name: String,
+            #[serde(default)]
+            inputs: Vec<AbiParamJson>,
+            #[serde(default)]
+            outputs: Vec<AbiParamJson>,
+            state_mutability: Option<String>,
+        },
+
+        #[serde(rename_all = "camelCase")]
+        Constructor {
+            #[serde(default)]
+            inputs: Vec<AbiParamJson>,
+            state_mutability: Option<String>,
+        },
+
+        Event {
+            name: String,
+            #[serde(default)]
+            inputs: Vec<AbiParamJson>,
+            #[serde(default)]
+            anonymous: bool,
+        },
+
+        Error {
+            name: String,
+            #[serde(default)]
+            inputs: Vec<AbiParamJson>,
+        },
+
+        // `fallback` and `receive`.
+        #[serde(other)]
+        Other,
+    }
+
+    #[derive(serde_derive::Deserialize)]
+    pub(super) struct AbiParamJson {
+        #[serde(default)]
+        name: String,
+        #[serde(rename = "type")]
+        kind: String,
+        #[serde(default)]
+        components: Vec<AbiParamJson>,
+        #[serde(default)]
+        indexed: bool,
+    }
+
+    pub(super) fn params(params: Vec<AbiParamJson>) -> Result<Vec<JsonParam>, ParseError> {
+        params.into_iter().map(param).collect()
+    }
+
+    fn param(param: AbiParamJson) -> Result<JsonParam, ParseError> {
+        let components = params(param.components)?;
+        let component_types: Vec<_> = components.iter().map(|it| it.kind.clone()).collect();
+
+        Ok(JsonParam {
+            name: param.name,
+            kind: AbiType::from_json_type(&param.kind, &component_types)?,
+            components,
+            indexed: param.indexed,
+        })
+    }
+
+    // ABIs from before solidity 0.4.16 don't have `stateMutability`, those functions are treated as non-payable.
+    pub(super) fn state_mutability(
+        state_mutability: Option<&str>,
+    ) -> Result<AbiStateMutability, ParseError> {
+        Ok(match state_mutability {
+            Some("pure") => AbiStateMutability::Pure,
+            Some("view") => AbiStateMutability::View,
+            Some("nonpayable") | None => AbiStateMutability::NonPayable,
+            Some("payable") => AbiStateMutability::Payable,
+            Some(it) => return Err(ParseError::new(format!("unknown state mutability `{it}`"))),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsonAbi;
+    use crate::{
+        AbiStateMutability,
+        AbiType,
+    };
+
+    const ABI: &str = r#"[
+        {
+            "type": "constructor",
+            "inputs": [{ "name": "owner", "type": "address", "internalType": "address" }],
+            "stateMutability": "payable"
+        },
+        {
+            "type": "function",
+            "name": "getPosition",
+            "inputs": [{ "name": "id", "type": "uint64" }],
+            "outputs": [
+                {
+                    "name": "",
+                    "type": "tuple[]",
+                    "components": [
+                        { "name": "owner", "type": "address" },
+                        { "name": "label", "type": "string" }
+                    ]
+                }
+            ],
+            "stateMutability": "view"
+        },
+        { "type": "function", "name": "poke", "inputs": [], "outputs": [] },
+        {
+            "type": "event",
+            "name": "Moved",
+            "inputs": [
+                { "name": "id", "type": "uint64", "indexed": true },
+                { "name": "label", "type": "string", "indexed": false }
+            ],
+            "anonymous": false
+        },
+        { "type": "error", "name": "PositionNotFound", "inputs": [{ "name": "id", "type": "uint64" }] },
+        { "type": "receive", "stateMutability": "payable" }
+    ]"#;
+
+    #[test]
+    fn from_json() {
+        let abi = JsonAbi::from_json(ABI).unwrap();
+
+        let constructor = abi.constructor.unwrap();
+        assert_eq!(constructor.inputs[0].kind, AbiType::Address);
+        assert_eq!(constructor.state_mutability, AbiStateMutability::Payable);
+
+        assert_eq!(abi.functions.len(), 2);
+        assert_eq!(abi.functions[0].signature(), "getPosition(uint64)");
+        assert_eq!(abi.functions[0].outputs[0].kind.to_string(), "(address,string)[]");
+        assert_eq!(abi.functions[0].outputs[0].components[1].name, "label");
+
+        // functions without `stateMutability` are from before it existed, when they were non-payable.
+        assert_eq!(abi.functions[1].state_mutability, AbiStateMutability::NonPayable);
+
+        assert_eq!(abi.events[0].signature(), "Moved(uint64,string)");
+        assert!(abi.events[0].inputs[0].indexed && !abi.events[0].inputs[1].indexed);
+
+        assert_eq!(abi.errors[0].name, "PositionNotFound");
+    }
+
+    #[test]
+    fn from_json_artifact() {
+        let abi =
+            JsonAbi::from_json(&format!(r#"{{ "contractName": "Pool", "abi": {ABI} }}"#)).unwrap();
+
+        assert_eq!(abi.functions.len(), 2);
+    }
+
+    #[test]
+    fn from_json_invalid() {
+        assert!(JsonAbi::from_json("{}").is_err());
+        assert!(JsonAbi::from_json(
+            r#"[{ "type": "function", "name": "f", "inputs": [{ "type": "uint7" }] }]"#
+        )
+        .is_err());
+        assert!(JsonAbi::from_json(
+            r#"[{ "type": "function", "name": "f", "stateMutability": "free" }]"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn selector() {
+        let abi = JsonAbi::from_json(ABI).unwrap();
+
+        // keccak256("poke()")
+        assert_eq!(abi.functions[1].selector(), [0x18, 0x17, 0x83, 0x58]);
+    }
+
+    #[test]
+    fn topic() {
+        let abi = JsonAbi::from_json(ABI).unwrap();
+
+        // keccak256("Moved(uint64,string)")
+        assert_eq!(abi.events[0].topic()[..4], [0x18, 0xd4, 0xb8, 0xd0]);
+    }
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Solidity ABI types and the JSON ABI format produced by `solc --abi`.
+//!
+//! This is the part of the [Hedera SDK](https://docs.rs/hedera)'s ABI support that doesn't depend on the SDK,
+//! so that `hedera-contract-macros` can read ABIs at compile time without depending on the SDK itself.
+//! Use the SDK to encode and decode values.
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs, clippy::missing_errors_doc)]
+
+mod abi_state_mutability;
+mod abi_type;
+mod error;
+#[cfg(feature = "json")]
+mod json;
+
+pub use abi_state_mutability::AbiStateMutability;
+pub use abi_type::{
+    parse_type,
+    AbiType,
+};
+pub use error::ParseError;
+#[cfg(feature = "json")]
+pub use json::{
+    JsonAbi,
+    JsonConstructor,
+    JsonError,
+    JsonEvent,
+    JsonFunction,
+    JsonParam,
+};
@@ -0,0 +1,446 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::Path;
+
+use hedera_contract_abi::{
+    AbiType,
+    JsonAbi,
+    JsonEvent,
+    JsonFunction,
+};
+use proc_macro2::{
+    Ident,
+    Literal,
+    Span,
+    TokenStream,
+};
+use quote::{
+    format_ident,
+    quote,
+};
+use syn::LitStr;
+
+use crate::types::{
+    abi_type,
+    decode_fields,
+    encode,
+    rust_type,
+};
+
+// methods every binding has, which generated methods must not collide with.
+const RESERVED_METHODS: &[&str] = &["new", "contract_id", "deploy"];
+
+// names used by the generated code, which parameters must not shadow.
+const RESERVED_PARAMS: &[&str] = &["contract_id", "bytecode", "builder", "flow"];
+
+pub(crate) fn contract(name: &Ident, path: &LitStr) -> syn::Result<TokenStream> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| syn::Error::new(path.span(), "`CARGO_MANIFEST_DIR` isn't set"))?;
+
+    let full_path = Path::new(&manifest_dir).join(path.value());
+
+    let json = std::fs::read_to_string(&full_path).map_err(|error| {
+        syn::Error::new(path.span(), format!("failed to read `{}`: {error}", full_path.display()))
+    })?;
+
+    let abi = JsonAbi::from_json(&json).map_err(|error| syn::Error::new(path.span(), error))?;
+
+    let full_path = full_path.to_string_lossy();
+    let doc = format!("Typed bindings for the contract with the ABI in `{}`.", path.value());
+
+    let deploy = deploy(&abi);
+
+    let functions = overloaded_names(abi.functions.iter().map(|it| it.name.as_str()));
+    let functions = abi.functions.iter().zip(functions).map(|(it, name)| function(it, &name));
+
+    let events = overloaded_names(abi.events.iter().map(|it| it.name.as_str()));
+    let (event_methods, event_structs): (Vec<_>, Vec<_>) =
+        abi.events.iter().zip(events).map(|(it, event_name)| event(name, it, &event_name)).unzip();
+
+    Ok(quote! {
+        // rebuilds the bindings when the ABI changes.
+        const _: &str = include_str!(#full_path);
+
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct #name {
+            contract_id: ::hedera::__private::ContractId,
+        }
+
+        impl #name {
+            /// Bind to the deployed contract `contract_id`.
+            #[must_use]
+            pub fn new(contract_id: ::hedera::__private::ContractId) -> Self {
+                Self { contract_id }
+            }
+
+            /// Returns the contract these bindings call.
+            #[must_use]
+            pub fn contract_id(&self) -> ::hedera::__private::ContractId {
+                self.contract_id
+            }
+
+            #deploy
+
+            #(#functions)*
+
+            #(#event_methods)*
+        }
+
+        #(#event_structs)*
+    })
+}
+
+fn deploy(abi: &JsonAbi) -> TokenStream {
+    let inputs = abi.constructor.as_ref().map(|it| it.inputs.as_slice()).unwrap_or_default();
+    let params = params(inputs.iter().map(|it| (it.name.as_str(), &it.kind)));
+
+    let constructor_parameters = (!params.is_empty()).then(|| {
+        let types = params.iter().map(|(_, ty)| abi_type(ty));
+        let args = args(&params);
+
+        quote! {
+            flow.constructor_parameters(
+                ::hedera::__private::encode_constructor(&[#(#types),*], &[#(#args),*])?,
+            );
+        }
+    });
+
+    let declarations = declarations(&params);
+
+    quote! {
+        /// Build a [`ContractCreateFlow`](::hedera::ContractCreateFlow) that deploys `bytecode`
+        /// with the given constructor arguments.
+        ///
+        /// The gas and any other options still need to be set on the flow.
+        ///
+        /// # Errors
+        /// - [`Error::AbiEncode`](::hedera::Error::AbiEncode) if an argument is out of range for its Solidity type.
+        pub fn deploy(
+            bytecode: ::std::vec::Vec<u8>,
+            #(#declarations),*
+        ) -> ::hedera::__private::Result<::hedera::__private::ContractCreateFlow> {
+            let mut flow = ::hedera::__private::ContractCreateFlow::new();
+            flow.bytecode(bytecode);
+            #constructor_parameters
+            Ok(flow)
+        }
+    }
+}
+
+fn function(function: &JsonFunction, name: &str) -> TokenStream {
+    let signature = function.signature();
+    let selector = function.selector().map(Literal::u8_unsuffixed);
+    let method = ident(name, RESERVED_METHODS);
+    let decode_method = ident(&format!("decode_{name}_output"), &[]);
+
+    let params = params(function.inputs.iter().map(|it| (it.name.as_str(), &it.kind)));
+    let declarations = declarations(&params);
+    let input_types = params.iter().map(|(_, ty)| abi_type(ty));
+    let args = args(&params);
+
+    let (doc, builder) = if function.state_mutability.is_read_only() {
+        (format!("Query `{signature}`."), quote!(::hedera::__private::ContractCallQuery))
+    } else {
+        (format!("Call `{signature}`."), quote!(::hedera::__private::ContractExecuteTransaction))
+    };
+
+    let output_types: Vec<_> = function.outputs.iter().map(|it| it.kind.clone()).collect();
+    let output_abi_types = output_types.iter().map(abi_type);
+
+    // a single return value isn't wrapped in a tuple.
+    let (output_type, decode_outputs) = match output_types.as_slice() {
+        [ty] => (rust_type(ty), decode_fields(&output_types, |mut fields| fields.remove(0))),
+        _ => (
+            rust_type(&AbiType::Tuple(output_types.clone())),
+            decode_fields(&output_types, |fields| quote!((#(#fields,)*))),
+        ),
+    };
+
+    let decode_doc = format!("Decode the return values of `{signature}`.");
+
+    quote! {
+        #[doc = #doc]
+        ///
+        /// # Errors
+        /// - [`Error::AbiEncode`](::hedera::Error::AbiEncode) if an argument is out of range for its Solidity type.
+        pub fn #method(&self, #(#declarations),*) -> ::hedera::__private::Result<#builder> {
+            let mut builder = #builder::new();
+
+            builder
+                .contract_id(self.contract_id)
+                .function_parameters(::hedera::__private::encode_call(
+                    [#(#selector),*],
+                    &[#(#input_types),*],
+                    &[#(#args),*],
+                )?);
+
+            Ok(builder)
+        }
+
+        #[doc = #decode_doc]
+        ///
+        /// # Errors
+        /// - [`Error::AbiDecode`](::hedera::Error::AbiDecode) if the result doesn't hold the return values.
+        pub fn #decode_method(
+            result: &::hedera::__private::ContractFunctionResult,
+        ) -> ::hedera::__private::Result<#output_type> {
+            let values =
+                ::hedera::__private::decode(&[#(#output_abi_types),*], result.as_bytes())?;
+
+            let decode = |value: &[::hedera::__private::AbiValue]| -> Option<#output_type> { #decode_outputs };
+
+            ::hedera::__private::expect_value(decode(&values))
+        }
+    }
+}
+
+fn event(contract: &Ident, event: &JsonEvent, name: &str) -> (TokenStream, TokenStream) {
+    let struct_name = format_ident!("{contract}{}Event", pascal_case(name));
+    let method = ident(&format!("{name}_events"), &[]);
+
+    // indexed values that don't fit in a topic are replaced by their hash.
+    let types: Vec<_> = event
+        .inputs
+        .iter()
+        .map(|it| {
+            if it.indexed && it.kind.is_dynamic() {
+                AbiType::FixedBytes(32)
+            } else {
+                it.kind.clone()
+            }
+        })
+        .collect();
+
+    let fields = params(event.inputs.iter().zip(&types).map(|(it, ty)| (it.name.as_str(), ty)));
+
+    let field_declarations = fields.iter().zip(&event.inputs).map(|((name, ty), input)| {
+        let ty = rust_type(ty);
+        let doc = if input.indexed {
+            format!("`{}`, indexed.", input.kind)
+        } else {
+            format!("`{}`.", input.kind)
+        };
+
+        quote! {
+            #[doc = #doc]
+            pub #name: #ty
+        }
+    });
+
+    let field_names = fields.iter().map(|(name, _)| name);
+    let build =
+        decode_fields(&types, |values| quote!(Self { contract_id, #(#field_names: #values),* }));
+
+    let signature = event.signature();
+    let topic = event.topic().map(Literal::u8_unsuffixed);
+    let event_name = &event.name;
+    let anonymous = event.anonymous;
+
+    let event_params = event.inputs.iter().map(|it| {
+        let name = &it.name;
+        let ty = abi_type(&it.kind);
+        let indexed = it.indexed;
+
+        quote!(::hedera::__private::AbiEventParam::new(#name, #ty, #indexed))
+    });
+
+    let struct_doc = format!("The `{signature}` event of [`{contract}`].");
+    let method_doc = format!(
+        "Decode the `{}` events in `result` emitted by this contract, in the order they were emitted.",
+        event.name
+    );
+
+    let method = quote! {
+        #[doc = #method_doc]
+        ///
+        /// # Errors
+        /// - [`Error::AbiDecode`](::hedera::Error::AbiDecode) if one of the events fails to decode.
+        pub fn #method(
+            &self,
+            result: &::hedera::__private::ContractFunctionResult,
+        ) -> ::hedera::__private::Result<::std::vec::Vec<#struct_name>> {
+            ::hedera::__private::decode_events(&#struct_name::abi_event(), result, self.contract_id)?
+                .iter()
+                .map(|values| #struct_name::from_values(self.contract_id, values))
+                .collect()
+        }
+    };
+
+    let event_struct = quote! {
+        #[doc = #struct_doc]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct #struct_name {
+            /// The contract that emitted the event.
+            pub contract_id: ::hedera::__private::ContractId,
+
+            #(#field_declarations,)*
+        }
+
+        impl #struct_name {
+            /// The canonical signature of the event.
+            pub const SIGNATURE: &'static str = #signature;
+
+            /// The Keccak-256 hash of the signature, which is the first topic of non-anonymous events.
+            pub const TOPIC: [u8; 32] = [#(#topic),*];
+
+            /// Decode the event from `log`.
+            ///
+            /// # Errors
+            /// - [`Error::AbiDecode`](::hedera::Error::AbiDecode) if `log` isn't this event.
+            pub fn decode(log: &::hedera::__private::ContractLogInfo) -> ::hedera::__private::Result<Self> {
+                let values = ::hedera::__private::decode_log(&Self::abi_event(), log)?;
+
+                Self::from_values(log.contract_id, &values)
+            }
+
+            fn abi_event() -> ::hedera::__private::AbiEvent {
+                ::hedera::__private::AbiEvent {
+                    name: ::std::borrow::ToOwned::to_owned(#event_name),
+                    inputs: ::std::vec![#(#event_params),*],
+                    anonymous: #anonymous,
+                }
+            }
+
+            fn from_values(
+                contract_id: ::hedera::__private::ContractId,
+                values: &[::hedera::__private::AbiValue],
+            ) -> ::hedera::__private::Result<Self> {
+                let decode = |value: &[::hedera::__private::AbiValue]| -> Option<Self> { #build };
+
+                ::hedera::__private::expect_value(decode(values))
+            }
+        }
+    };
+
+    (method, event_struct)
+}
+
+/// The identifiers and types of parameters, named `arg<i>` if they don't have a name.
+fn params<'a>(params: impl Iterator<Item = (&'a str, &'a AbiType)>) -> Vec<(Ident, AbiType)> {
+    params
+        .enumerate()
+        .map(|(i, (name, ty))| match name {
+            "" => (format_ident!("arg{i}"), ty.clone()),
+            name => (ident(&snake_case(name), RESERVED_PARAMS), ty.clone()),
+        })
+        .collect()
+}
+
+fn declarations(params: &[(Ident, AbiType)]) -> Vec<TokenStream> {
+    params
+        .iter()
+        .map(|(name, ty)| {
+            let ty = rust_type(ty);
+            quote!(#name: #ty)
+        })
+        .collect()
+}
+
+fn args(params: &[(Ident, AbiType)]) -> Vec<TokenStream> {
+    params.iter().map(|(name, ty)| encode(ty, &quote!(#name))).collect()
+}
+
+/// Snake case method names for `names`, with overloads suffixed by their index among the overloads.
+fn overloaded_names<'a>(names: impl Iterator<Item = &'a str> + Clone) -> Vec<String> {
+    let mut seen = std::collections::HashMap::<&str, usize>::new();
+
+    names
+        .clone()
+        .map(|name| {
+            let overloads = names.clone().filter(|it| *it == name).count();
+            let index = seen.entry(name).or_default();
+            *index += 1;
+
+            match overloads {
+                1 => snake_case(name),
+                _ => format!("{}_{}", snake_case(name), *index - 1),
+            }
+        })
+        .collect()
+}
+
+/// An identifier for `name`, escaped if it's a keyword, and suffixed with `_` if it's in `reserved`.
+fn ident(name: &str, reserved: &[&str]) -> Ident {
+    if reserved.contains(&name) || matches!(name, "self" | "Self" | "super" | "crate") {
+        return format_ident!("{name}_");
+    }
+
+    match syn::parse_str::<Ident>(name) {
+        Ok(ident) => ident,
+        Err(_) => Ident::new_raw(name, Span::call_site()),
+    }
+}
+
+/// Converts Solidity's `camelCase` (or `PascalCase`) names to `snake_case`, so `balanceOf` becomes `balance_of`.
+fn snake_case(name: &str) -> String {
+    let chars: Vec<_> = name.chars().map(|it| if it == '$' { '_' } else { it }).collect();
+    let mut out = String::with_capacity(name.len() + 4);
+
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_ascii_uppercase() {
+            out.push(c);
+            continue;
+        }
+
+        let prev = i.checked_sub(1).map(|i| chars[i]);
+        let next = chars.get(i + 1);
+
+        // `balanceOf` -> `balance_of`, `ERC20Name` -> `erc20_name`, `getURI` -> `get_uri`.
+        let starts_word = prev.is_some_and(|it| it.is_ascii_lowercase() || it.is_ascii_digit())
+            || (prev.is_some_and(|it| it.is_ascii_uppercase())
+                && next.is_some_and(char::is_ascii_lowercase));
+
+        if starts_word && !out.ends_with('_') {
+            out.push('_');
+        }
+
+        out.push(c.to_ascii_lowercase());
+    }
+
+    out
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|it| !it.is_empty())
+        .map(|it| {
+            let mut chars = it.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        overloaded_names,
+        pascal_case,
+        snake_case,
+    };
+
+    #[test]
+    fn snake_case_names() {
+        assert_eq!(snake_case("balanceOf"), "balance_of");
+        assert_eq!(snake_case("ERC20Name"), "erc20_name");
+        assert_eq!(snake_case("getURI"), "get_uri");
+        assert_eq!(snake_case("_owner"), "_owner");
+        assert_eq!(snake_case("transfer_from"), "transfer_from");
+    }
+
+    #[test]
+    fn pascal_case_names() {
+        assert_eq!(pascal_case("transfer"), "Transfer");
+        assert_eq!(pascal_case("owner_changed_1"), "OwnerChanged1");
+    }
+
+    #[test]
+    fn overloads() {
+        let names = ["poke", "transfer", "poke"];
+
+        assert_eq!(overloaded_names(names.into_iter()), ["poke_0", "transfer", "poke_1"]);
+    }
+}
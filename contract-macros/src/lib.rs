@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Typed bindings for smart contracts, generated from their Solidity ABI JSON.
+//!
+//! ```ignore
+//! hedera_contract_macros::contract!(Pool, "abi/Pool.json");
+//!
+//! // deploy the contract, with typed constructor arguments.
+//! let receipt = Pool::deploy(bytecode, owner)?.gas(200_000).execute(&client).await?.get_receipt(&client).await?;
+//! let pool = Pool::new(receipt.contract_id.unwrap());
+//!
+//! // call a function, and decode what it returned.
+//! let result = pool.get_position(7)?.gas(30_000).execute(&client).await?;
+//! let (owner, amounts, label) = Pool::decode_get_position_output(&result)?;
+//!
+//! // decode the events a transaction emitted.
+//! let record = pool.poke()?.gas(30_000).execute(&client).await?.get_record(&client).await?;
+//! let moved = pool.moved_events(&record.contract_function_result.unwrap())?;
+//! ```
+//!
+//! For every function in the ABI, the contract gets a method that returns a
+//! `hedera::ContractCallQuery` for `view` and `pure` functions,
+//! or a `hedera::ContractExecuteTransaction` otherwise,
+//! with the contract ID and the encoded function parameters already set,
+//! and a `decode_<function>_output` function for its return values.
+//!
+//! Integers are passed as the smallest Rust integer that fits, like `i32` for `int24`,
+//! so building a call returns an error rather than panicking if an argument is out of range for its Solidity type.
+//!
+//! Overloaded functions and events get their index among the overloads as a suffix, like `poke_0` and `poke_1`.
+//!
+//! For every event, there's a `<Contract><Event>Event` struct and a `<event>_events` method on the contract.
+//!
+//! Paths are relative to the `CARGO_MANIFEST_DIR` of the crate using the macro.
+
+mod expand;
+mod types;
+
+use proc_macro::TokenStream;
+use syn::parse::{
+    Parse,
+    ParseStream,
+};
+use syn::{
+    parse_macro_input,
+    Ident,
+    LitStr,
+    Token,
+};
+
+struct ContractInput {
+    name: Ident,
+    path: LitStr,
+}
+
+impl Parse for ContractInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let path = input.parse()?;
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(Self { name, path })
+    }
+}
+
+/// Generate typed bindings named `name` for the contract with the ABI JSON at `path`.
+///
+/// See the [crate documentation](crate) for what's generated.
+#[proc_macro]
+pub fn contract(input: TokenStream) -> TokenStream {
+    let ContractInput { name, path } = parse_macro_input!(input as ContractInput);
+
+    expand::contract(&name, &path).unwrap_or_else(syn::Error::into_compile_error).into()
+}
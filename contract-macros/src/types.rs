@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! How each ABI type maps to a Rust type, and how values convert to and from `hedera::AbiValue`.
+
+use hedera_contract_abi::AbiType;
+use proc_macro2::{
+    Literal,
+    TokenStream,
+};
+use quote::{
+    format_ident,
+    quote,
+};
+
+/// The Rust type used for values of `ty` in the generated bindings.
+///
+/// Integers use the smallest primitive that fits, falling back to `BigInt`/`BigUint` past 128 bits.
+pub(crate) fn rust_type(ty: &AbiType) -> TokenStream {
+    match ty {
+        AbiType::Address => quote!(::hedera::__private::EvmAddress),
+        AbiType::Bool => quote!(bool),
+        AbiType::Int(bits) => match bits {
+            ..=8 => quote!(i8),
+            ..=16 => quote!(i16),
+            ..=32 => quote!(i32),
+            ..=64 => quote!(i64),
+            ..=128 => quote!(i128),
+            _ => quote!(::hedera::__private::num_bigint::BigInt),
+        },
+        AbiType::Uint(bits) => match bits {
+            ..=8 => quote!(u8),
+            ..=16 => quote!(u16),
+            ..=32 => quote!(u32),
+            ..=64 => quote!(u64),
+            ..=128 => quote!(u128),
+            _ => quote!(::hedera::__private::num_bigint::BigUint),
+        },
+        AbiType::FixedBytes(len) => {
+            let len = Literal::usize_unsuffixed(*len);
+            quote!([u8; #len])
+        }
+        AbiType::Bytes => quote!(::std::vec::Vec<u8>),
+        AbiType::String => quote!(::std::string::String),
+        AbiType::Function => quote!((::hedera::__private::EvmAddress, [u8; 4])),
+        AbiType::Array(ty) => {
+            let ty = rust_type(ty);
+            quote!(::std::vec::Vec<#ty>)
+        }
+        AbiType::FixedArray(ty, len) => {
+            let ty = rust_type(ty);
+            let len = Literal::usize_unsuffixed(*len);
+            quote!([#ty; #len])
+        }
+        AbiType::Tuple(types) => {
+            let types = types.iter().map(rust_type);
+            quote!((#(#types,)*))
+        }
+    }
+}
+
+/// An expression that builds `ty` as a `hedera::AbiType`, so the generated code never parses a type.
+pub(crate) fn abi_type(ty: &AbiType) -> TokenStream {
+    match ty {
+        AbiType::Address => quote!(::hedera::__private::AbiType::Address),
+        AbiType::Bool => quote!(::hedera::__private::AbiType::Bool),
+        AbiType::Int(bits) => {
+            let bits = Literal::usize_unsuffixed(*bits);
+            quote!(::hedera::__private::AbiType::Int(#bits))
+        }
+        AbiType::Uint(bits) => {
+            let bits = Literal::usize_unsuffixed(*bits);
+            quote!(::hedera::__private::AbiType::Uint(#bits))
+        }
+        AbiType::FixedBytes(len) => {
+            let len = Literal::usize_unsuffixed(*len);
+            quote!(::hedera::__private::AbiType::FixedBytes(#len))
+        }
+        AbiType::Bytes => quote!(::hedera::__private::AbiType::Bytes),
+        AbiType::String => quote!(::hedera::__private::AbiType::String),
+        AbiType::Function => quote!(::hedera::__private::AbiType::Function),
+        AbiType::Array(ty) => {
+            let ty = abi_type(ty);
+            quote!(::hedera::__private::AbiType::Array(::std::boxed::Box::new(#ty)))
+        }
+        AbiType::FixedArray(ty, len) => {
+            let ty = abi_type(ty);
+            let len = Literal::usize_unsuffixed(*len);
+            quote!(::hedera::__private::AbiType::FixedArray(::std::boxed::Box::new(#ty), #len))
+        }
+        AbiType::Tuple(types) => {
+            let types = types.iter().map(abi_type);
+            quote!(::hedera::__private::AbiType::Tuple(::std::vec![#(#types),*]))
+        }
+    }
+}
+
+/// An expression converting `value`, of the [`rust_type`] of `ty`, into an `AbiValue`.
+pub(crate) fn encode(ty: &AbiType, value: &TokenStream) -> TokenStream {
+    match ty {
+        AbiType::Address
+        | AbiType::Bool
+        | AbiType::Int(_)
+        | AbiType::Uint(_)
+        | AbiType::FixedBytes(_)
+        | AbiType::String => quote!(::hedera::__private::AbiValue::from(#value)),
+        AbiType::Bytes => quote!(::hedera::__private::AbiValue::Bytes(#value)),
+        AbiType::Function => quote! {{
+            let (address, selector) = #value;
+            ::hedera::__private::AbiValue::Function(address, selector)
+        }},
+        AbiType::Array(ty) => {
+            let element = encode(ty, &quote!(value));
+            quote! {
+                ::hedera::__private::AbiValue::Array(
+                    ::std::iter::IntoIterator::into_iter(#value).map(|value| #element).collect(),
+                )
+            }
+        }
+        AbiType::FixedArray(ty, _) => {
+            let element = encode(ty, &quote!(value));
+            // not `.into_iter()`, which iterates arrays by reference before edition 2021.
+            quote! {
+                ::hedera::__private::AbiValue::FixedArray(
+                    ::std::iter::IntoIterator::into_iter(#value).map(|value| #element).collect(),
+                )
+            }
+        }
+        AbiType::Tuple(types) => {
+            let names: Vec<_> = (0..types.len()).map(|i| format_ident!("field_{i}")).collect();
+            let fields = types.iter().zip(&names).map(|(ty, name)| encode(ty, &quote!(#name)));
+
+            quote! {{
+                let (#(#names,)*) = #value;
+                ::hedera::__private::AbiValue::Tuple(::std::vec![#(#fields),*])
+            }}
+        }
+    }
+}
+
+/// An expression converting `value`, an `&AbiValue` of type `ty`, into an `Option` of the [`rust_type`] of `ty`.
+pub(crate) fn decode(ty: &AbiType, value: &TokenStream) -> TokenStream {
+    match ty {
+        AbiType::Address => quote!(#value.as_address()),
+        AbiType::Bool => quote!(#value.as_bool()),
+        AbiType::Int(bits) if *bits > 128 => quote!(#value.as_int()),
+        AbiType::Uint(bits) if *bits > 128 => quote!(#value.as_uint()),
+        AbiType::Int(_) => {
+            let rust_type = rust_type(ty);
+            quote!(#value.as_int().and_then(|value| <#rust_type>::try_from(&value).ok()))
+        }
+        AbiType::Uint(_) => {
+            let rust_type = rust_type(ty);
+            quote!(#value.as_uint().and_then(|value| <#rust_type>::try_from(&value).ok()))
+        }
+        AbiType::FixedBytes(_) => {
+            let rust_type = rust_type(ty);
+            quote!(#value.as_bytes().and_then(|value| <#rust_type>::try_from(value).ok()))
+        }
+        AbiType::Bytes => quote!(#value.as_bytes().map(<[u8]>::to_vec)),
+        AbiType::String => quote!(#value.as_str().map(::std::borrow::ToOwned::to_owned)),
+        AbiType::Function => quote! {
+            match #value {
+                ::hedera::__private::AbiValue::Function(address, selector) => Some((*address, *selector)),
+                _ => None,
+            }
+        },
+        AbiType::Array(ty) => {
+            let element = decode(ty, &quote!(value));
+
+            quote! {
+                #value.as_array().and_then(|value| {
+                    value.iter().map(|value| #element).collect::<Option<::std::vec::Vec<_>>>()
+                })
+            }
+        }
+        AbiType::FixedArray(element_type, _) => {
+            let element = decode(element_type, &quote!(value));
+            let rust_type = rust_type(ty);
+
+            quote! {
+                #value
+                    .as_array()
+                    .and_then(|value| {
+                        value.iter().map(|value| #element).collect::<Option<::std::vec::Vec<_>>>()
+                    })
+                    .and_then(|value| <#rust_type>::try_from(value).ok())
+            }
+        }
+        AbiType::Tuple(types) => {
+            let fields = decode_fields(types, |fields| quote!((#(#fields,)*)));
+            quote!(#value.as_tuple().and_then(|value| #fields))
+        }
+    }
+}
+
+/// A `match` on `value: &[AbiValue]`, which decodes each element as the corresponding type in `types`,
+/// and passes the `Option`-unwrapped fields to `build` to make the result.
+pub(crate) fn decode_fields(
+    types: &[AbiType],
+    build: impl FnOnce(Vec<TokenStream>) -> TokenStream,
+) -> TokenStream {
+    let names: Vec<_> = (0..types.len()).map(|i| format_ident!("field_{i}")).collect();
+
+    let fields = types
+        .iter()
+        .zip(&names)
+        .map(|(ty, name)| {
+            let field = decode(ty, &quote!(#name));
+            quote!(#field?)
+        })
+        .collect();
+
+    let result = build(fields);
+
+    quote! {
+        match value {
+            [#(#names),*] => Some(#result),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use hedera::{
+    AbiValue,
+    ContractAbi,
+    ContractFunctionResult,
+    ContractId,
+    ContractLogInfo,
+    Error,
+    EvmAddress,
+};
+use hex_literal::hex;
+use num_bigint::BigUint;
+
+hedera_contract_macros::contract!(Pool, "tests/abi/Pool.json");
+
+const OWNER: [u8; 20] = hex!("1111111111111111111111111111111111111111");
+
+fn abi() -> ContractAbi {
+    ContractAbi::from_json(include_str!("abi/Pool.json")).unwrap()
+}
+
+fn result(bytes: Vec<u8>, logs: Vec<ContractLogInfo>) -> ContractFunctionResult {
+    ContractFunctionResult {
+        contract_id: ContractId::new(0, 0, 1001),
+        evm_address: None,
+        bytes,
+        error_message: None,
+        bloom: Vec::new(),
+        gas_used: 0,
+        gas: 0,
+        hbar_amount: 0,
+        contract_function_parameters_bytes: Vec::new(),
+        sender_account_id: None,
+        logs,
+        contract_nonces: Vec::new(),
+        signer_nonce: None,
+    }
+}
+
+#[test]
+fn deploy() {
+    let flow = Pool::deploy(vec![0x60, 0x80], EvmAddress::from(OWNER)).unwrap();
+
+    assert_eq!(flow.get_bytecode(), [0x60, 0x80]);
+    assert_eq!(
+        flow.get_constructor_parameters(),
+        abi().encode_constructor(&[EvmAddress::from(OWNER).into()]).unwrap()
+    );
+}
+
+#[test]
+fn call() {
+    let abi = abi();
+    let pool = Pool::new(ContractId::new(0, 0, 1001));
+
+    let query = pool.get_position(7).unwrap();
+
+    assert_eq!(query.get_contract_id(), Some(pool.contract_id()));
+    assert_eq!(
+        query.get_contract_parameters(),
+        abi.encode_call("getPosition", &[7_u64.into()]).unwrap()
+    );
+
+    let transaction = pool.poke_0().unwrap();
+
+    assert_eq!(transaction.get_contract_id(), Some(pool.contract_id()));
+    assert_eq!(transaction.get_function_parameters(), abi.functions[2].encode_input(&[]).unwrap());
+
+    let transaction = pool.poke_1(-5).unwrap();
+
+    assert_eq!(
+        transaction.get_function_parameters(),
+        abi.functions[3].encode_input(&[AbiValue::from(-5_i32)]).unwrap()
+    );
+}
+
+#[test]
+fn call_out_of_range() {
+    let pool = Pool::new(ContractId::new(0, 0, 1001));
+
+    // `poke(int24)` takes an `i32`, which can hold values that don't fit in an `int24`.
+    assert!(pool.poke_1(-(1 << 23)).is_ok());
+    assert!(matches!(pool.poke_1(1 << 23), Err(Error::AbiEncode(_))));
+}
+
+#[test]
+fn decode_output() {
+    let abi = abi();
+
+    let position = AbiValue::Tuple(vec![
+        EvmAddress::from(OWNER).into(),
+        AbiValue::FixedArray(vec![1_u128.into(), u128::MAX.into()]),
+        "home".into(),
+    ]);
+
+    let bytes = abi.functions[0].encode_output(&[position]).unwrap();
+
+    assert_eq!(
+        Pool::decode_get_position_output(&result(bytes, Vec::new())).unwrap(),
+        (EvmAddress::from(OWNER), [1, u128::MAX], "home".to_owned())
+    );
+
+    let supply = BigUint::from(2_u8).pow(200);
+    let bytes = abi.functions[1]
+        .encode_output(&[
+            supply.clone().into(),
+            AbiValue::Array(vec![(-1_i32).into(), 3_i32.into()]),
+        ])
+        .unwrap();
+
+    assert_eq!(
+        Pool::decode_total_supply_output(&result(bytes, Vec::new())).unwrap(),
+        (supply, vec![-1, 3])
+    );
+
+    // too short for the return values.
+    assert!(Pool::decode_get_position_output(&result(vec![0; 32], Vec::new())).is_err());
+}
+
+#[test]
+fn decode_events() {
+    let pool = Pool::new(ContractId::new(0, 0, 1001));
+
+    let label_hash = hex!("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa");
+
+    let log = |contract: u64, id: u8| ContractLogInfo {
+        contract_id: ContractId::new(0, 0, contract),
+        bloom: Vec::new(),
+        topics: vec![
+            PoolMovedEvent::TOPIC.to_vec(),
+            [[0; 31].as_slice(), &[id]].concat(),
+            label_hash.to_vec(),
+        ],
+        data: [[0; 12].as_slice(), &OWNER].concat(),
+    };
+
+    assert_eq!(PoolMovedEvent::SIGNATURE, "Moved(uint64,string,address)");
+    assert_eq!(PoolMovedEvent::TOPIC, abi().event("Moved").unwrap().topic());
+
+    let expected = |id: u64| PoolMovedEvent {
+        contract_id: ContractId::new(0, 0, 1001),
+        id,
+        label: label_hash,
+        to: EvmAddress::from(OWNER),
+    };
+
+    assert_eq!(PoolMovedEvent::decode(&log(1001, 1)).unwrap(), expected(1));
+
+    let result = result(Vec::new(), vec![log(1001, 1), log(1002, 2), log(1001, 3)]);
+
+    assert_eq!(pool.moved_events(&result).unwrap(), [expected(1), expected(3)]);
+}
@@ -233,7 +233,11 @@ fn main() -> anyhow::Result<()> {
         .services_same("VirtualAddress");
 
     cfg.out_dir(&sdk_out_dir).compile_protos(
-        &["./sdk/transaction_list.proto"],
+        &[
+            "./sdk/transaction_list.proto",
+            "./sdk/topic_message_envelope.proto",
+            "./sdk/encrypted_topic_message.proto",
+        ],
         &["./sdk/", out_path.to_str().unwrap()],
     )?;
 
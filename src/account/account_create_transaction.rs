@@ -11,6 +11,10 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::staked_id::StakedId;
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -255,7 +259,12 @@ impl AccountCreateTransaction {
     }
 }
 
-impl TransactionData for AccountCreateTransactionData {}
+impl TransactionData for AccountCreateTransactionData {
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        validation::validate_memo("account_memo", &self.account_memo, errors);
+        validation::validate_auto_renew_period("auto_renew_period", self.auto_renew_period, errors);
+    }
+}
 
 impl TransactionExecute for AccountCreateTransactionData {
     fn execute(
@@ -39,17 +39,24 @@ pub async fn verify_signature(
     key.verify(msg, signature)
 }
 
-/// Returns `Ok(())` if the given account's public key has signed the given transaction.
+/// Returns `Ok(())` if the given account's key has signed the given transaction.
+///
+/// The account's full [`Key`] is checked, including any nested key lists and thresholds.
+///
 /// # Errors
-/// - [`Error::SignatureVerify`] if the private key associated with the account's public key did _not_ sign this transaction,
-///   or the signature associated was invalid.
+/// - [`Error::SignatureVerify`] if the signatures on this transaction don't satisfy the account's key,
+///   or the signatures associated were invalid.
 /// - See [`AccountInfoQuery::execute`]
 pub async fn verify_transaction_signature<D: TransactionExecute>(
     client: &Client,
     account_id: AccountId,
     transaction: &mut Transaction<D>,
 ) -> crate::Result<()> {
-    let key = query_pk(client, account_id).await?;
+    let key = AccountInfoQuery::new().account_id(account_id).execute(client).await?.key;
 
-    key.verify_transaction(transaction)
+    if key.verify_transaction(transaction)?.is_satisfied() {
+        Ok(())
+    } else {
+        Err(Error::signature_verify(format!("`{account_id}`: key not satisfied by transaction")))
+    }
 }
@@ -14,6 +14,10 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::staked_id::StakedId;
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -273,7 +277,14 @@ impl AccountUpdateTransaction {
     }
 }
 
-impl TransactionData for AccountUpdateTransactionData {}
+impl TransactionData for AccountUpdateTransactionData {
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        if let Some(memo) = &self.account_memo {
+            validation::validate_memo("account_memo", memo, errors);
+        }
+        validation::validate_auto_renew_period("auto_renew_period", self.auto_renew_period, errors);
+    }
+}
 
 impl TransactionExecute for AccountUpdateTransactionData {
     fn execute(
@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use hedera_proto::services;
+use prost::Message;
+use time::Duration;
+
+use crate::protobuf::ToProtobuf;
+use crate::{
+    AccountId,
+    FromProtobuf,
+    KeyList,
+};
+
+/// A hash of some content, such as a certificate, attached to an account for as long as it's valid.
+///
+/// Response from [`LiveHashQuery`](crate::LiveHashQuery).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiveHash {
+    /// The account the live hash is attached to.
+    pub account_id: AccountId,
+
+    /// The SHA-384 hash of the content.
+    pub hash: Vec<u8>,
+
+    /// The keys, any one of which can delete the live hash.
+    pub keys: KeyList,
+
+    /// How long the live hash remains valid for.
+    pub duration: Option<Duration>,
+}
+
+impl LiveHash {
+    /// Create a new `LiveHash` from protobuf-encoded `bytes`.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the bytes fails to produce a valid protobuf.
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the protobuf fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        FromProtobuf::<services::LiveHash>::from_bytes(bytes)
+    }
+
+    /// Convert `self` to a protobuf-encoded [`Vec<u8>`].
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_protobuf().encode_to_vec()
+    }
+}
+
+impl FromProtobuf<services::response::Response> for LiveHash {
+    fn from_protobuf(pb: services::response::Response) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        let response = pb_getv!(pb, CryptoGetLiveHash, services::response::Response);
+        let live_hash = pb_getf!(response, live_hash)?;
+        Self::from_protobuf(live_hash)
+    }
+}
+
+impl FromProtobuf<services::LiveHash> for LiveHash {
+    fn from_protobuf(pb: services::LiveHash) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        let account_id = pb_getf!(pb, account_id)?;
+
+        Ok(Self {
+            account_id: AccountId::from_protobuf(account_id)?,
+            hash: pb.hash,
+            keys: Option::from_protobuf(pb.keys)?.unwrap_or_default(),
+            duration: pb.duration.map(Into::into),
+        })
+    }
+}
+
+impl ToProtobuf for LiveHash {
+    type Protobuf = services::LiveHash;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::LiveHash {
+            account_id: Some(self.account_id.to_protobuf()),
+            hash: self.hash.clone(),
+            keys: Some(self.keys.to_protobuf()),
+            duration: self.duration.to_protobuf(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use crate::{
+        AccountId,
+        KeyList,
+        LiveHash,
+        PrivateKey,
+    };
+
+    #[test]
+    fn to_from_bytes() {
+        let live_hash = LiveHash {
+            account_id: AccountId::new(0, 0, 5007),
+            hash: Vec::from([0xab; 48]),
+            keys: KeyList::from([PrivateKey::generate_ed25519().public_key()]),
+            duration: Some(Duration::days(30)),
+        };
+
+        assert_eq!(LiveHash::from_bytes(&live_hash.to_bytes()).unwrap(), live_hash);
+    }
+}
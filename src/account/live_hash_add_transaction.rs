@@ -0,0 +1,385 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use hedera_proto::services;
+use hedera_proto::services::crypto_service_client::CryptoServiceClient;
+use time::Duration;
+use tonic::transport::Channel;
+
+use crate::ledger_id::RefLedgerId;
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::transaction::{
+    AnyTransactionData,
+    ChunkInfo,
+    ToTransactionDataProtobuf,
+    TransactionData,
+    TransactionExecute,
+};
+use crate::{
+    AccountId,
+    BoxGrpcFuture,
+    Error,
+    Key,
+    KeyList,
+    Transaction,
+    ValidateChecksums,
+};
+
+/// Attach a hash of some content, such as a certificate, to an account for a period of time.
+///
+/// The account's key must sign, as well as all of the live hash's keys.
+///
+/// Live hashes can't be scheduled.
+pub type LiveHashAddTransaction = Transaction<LiveHashAddTransactionData>;
+
+#[derive(Debug, Clone, Default)]
+pub struct LiveHashAddTransactionData {
+    /// The account to attach the live hash to.
+    account_id: Option<AccountId>,
+
+    /// The SHA-384 hash of the content.
+    hash: Vec<u8>,
+
+    /// The keys, any one of which can delete the live hash.
+    keys: Option<KeyList>,
+
+    /// How long the live hash remains valid for.
+    duration: Option<Duration>,
+}
+
+impl LiveHashAddTransaction {
+    /// Returns the account to attach the live hash to.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.data().account_id
+    }
+
+    /// Sets the account to attach the live hash to.
+    pub fn account_id(&mut self, id: AccountId) -> &mut Self {
+        self.data_mut().account_id = Some(id);
+        self
+    }
+
+    /// Returns the SHA-384 hash of the content.
+    #[must_use]
+    pub fn get_hash(&self) -> &[u8] {
+        &self.data().hash
+    }
+
+    /// Sets the SHA-384 hash of the content.
+    pub fn hash(&mut self, hash: impl Into<Vec<u8>>) -> &mut Self {
+        self.data_mut().hash = hash.into();
+        self
+    }
+
+    /// Returns the keys, any one of which can delete the live hash.
+    #[must_use]
+    pub fn get_keys(&self) -> Option<&KeyList> {
+        self.data().keys.as_ref()
+    }
+
+    /// Sets the keys, any one of which can delete the live hash.
+    pub fn keys<K: Into<Key>>(&mut self, keys: impl IntoIterator<Item = K>) -> &mut Self {
+        self.data_mut().keys = Some(keys.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Returns how long the live hash remains valid for.
+    #[must_use]
+    pub fn get_duration(&self) -> Option<Duration> {
+        self.data().duration
+    }
+
+    /// Sets how long the live hash remains valid for.
+    pub fn duration(&mut self, duration: Duration) -> &mut Self {
+        self.data_mut().duration = Some(duration);
+        self
+    }
+}
+
+impl TransactionData for LiveHashAddTransactionData {}
+
+impl TransactionExecute for LiveHashAddTransactionData {
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Transaction,
+    ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
+        Box::pin(async { CryptoServiceClient::new(channel).add_live_hash(request).await })
+    }
+}
+
+impl ValidateChecksums for LiveHashAddTransactionData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        self.account_id.validate_checksums(ledger_id)
+    }
+}
+
+impl ToTransactionDataProtobuf for LiveHashAddTransactionData {
+    fn to_transaction_data_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::transaction_body::Data {
+        let _ = chunk_info.assert_single_transaction();
+
+        services::transaction_body::Data::CryptoAddLiveHash(self.to_protobuf())
+    }
+}
+
+impl From<LiveHashAddTransactionData> for AnyTransactionData {
+    fn from(transaction: LiveHashAddTransactionData) -> Self {
+        Self::LiveHashAdd(transaction)
+    }
+}
+
+impl FromProtobuf<services::CryptoAddLiveHashTransactionBody> for LiveHashAddTransactionData {
+    fn from_protobuf(pb: services::CryptoAddLiveHashTransactionBody) -> crate::Result<Self> {
+        let Some(live_hash) = pb.live_hash else {
+            return Ok(Self::default());
+        };
+
+        Ok(Self {
+            account_id: Option::from_protobuf(live_hash.account_id)?,
+            hash: live_hash.hash,
+            keys: Option::from_protobuf(live_hash.keys)?,
+            duration: live_hash.duration.map(Into::into),
+        })
+    }
+}
+
+impl ToProtobuf for LiveHashAddTransactionData {
+    type Protobuf = services::CryptoAddLiveHashTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::CryptoAddLiveHashTransactionBody {
+            live_hash: Some(services::LiveHash {
+                account_id: self.account_id.to_protobuf(),
+                hash: self.hash.clone(),
+                keys: self.keys.to_protobuf(),
+                duration: self.duration.to_protobuf(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use hedera_proto::services;
+    use time::Duration;
+
+    use crate::account::LiveHashAddTransactionData;
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::transaction::test_helpers::{
+        check_body,
+        transaction_body,
+        unused_private_key,
+    };
+    use crate::{
+        AccountId,
+        AnyTransaction,
+        KeyList,
+        LiveHashAddTransaction,
+        PublicKey,
+        ScheduleCreateTransaction,
+    };
+
+    const ACCOUNT_ID: AccountId = AccountId::new(0, 0, 5007);
+    const HASH: [u8; 4] = [0x01, 0x02, 0x03, 0x04];
+    const DURATION: Duration = Duration::days(30);
+
+    fn key() -> PublicKey {
+        unused_private_key().public_key()
+    }
+
+    fn make_transaction() -> LiveHashAddTransaction {
+        let mut tx = LiveHashAddTransaction::new_for_tests();
+
+        tx.account_id(ACCOUNT_ID).hash(HASH).keys([key()]).duration(DURATION).freeze().unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn serialize() {
+        let tx = make_transaction();
+
+        let tx = transaction_body(tx);
+
+        let tx = check_body(tx);
+
+        expect![[r#"
+            CryptoAddLiveHash(
+                CryptoAddLiveHashTransactionBody {
+                    live_hash: Some(
+                        LiveHash {
+                            account_id: Some(
+                                AccountId {
+                                    shard_num: 0,
+                                    realm_num: 0,
+                                    account: Some(
+                                        AccountNum(
+                                            5007,
+                                        ),
+                                    ),
+                                },
+                            ),
+                            hash: [
+                                1,
+                                2,
+                                3,
+                                4,
+                            ],
+                            keys: Some(
+                                KeyList {
+                                    keys: [
+                                        Key {
+                                            key: Some(
+                                                Ed25519(
+                                                    [
+                                                        224,
+                                                        200,
+                                                        236,
+                                                        39,
+                                                        88,
+                                                        165,
+                                                        135,
+                                                        159,
+                                                        250,
+                                                        194,
+                                                        38,
+                                                        161,
+                                                        60,
+                                                        12,
+                                                        81,
+                                                        107,
+                                                        121,
+                                                        158,
+                                                        114,
+                                                        227,
+                                                        81,
+                                                        65,
+                                                        160,
+                                                        221,
+                                                        130,
+                                                        143,
+                                                        148,
+                                                        211,
+                                                        121,
+                                                        136,
+                                                        164,
+                                                        183,
+                                                    ],
+                                                ),
+                                            ),
+                                        },
+                                    ],
+                                },
+                            ),
+                            duration: Some(
+                                Duration {
+                                    seconds: 2592000,
+                                },
+                            ),
+                        },
+                    ),
+                },
+            )
+        "#]]
+        .assert_debug_eq(&tx)
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let tx = transaction_body(tx);
+
+        let tx2 = transaction_body(tx2);
+
+        assert_eq!(tx, tx2);
+    }
+
+    #[test]
+    fn from_proto_body() {
+        let tx = services::CryptoAddLiveHashTransactionBody {
+            live_hash: Some(services::LiveHash {
+                account_id: Some(ACCOUNT_ID.to_protobuf()),
+                hash: HASH.to_vec(),
+                keys: Some(KeyList::from([key()]).to_protobuf()),
+                duration: Some(DURATION.into()),
+            }),
+        };
+
+        let tx = LiveHashAddTransactionData::from_protobuf(tx).unwrap();
+
+        assert_eq!(tx.account_id, Some(ACCOUNT_ID));
+        assert_eq!(tx.hash, HASH);
+        assert_eq!(tx.keys, Some(KeyList::from([key()])));
+        assert_eq!(tx.duration, Some(DURATION));
+    }
+
+    #[test]
+    fn from_proto_body_without_live_hash() {
+        let tx = services::CryptoAddLiveHashTransactionBody { live_hash: None };
+
+        let tx = LiveHashAddTransactionData::from_protobuf(tx).unwrap();
+
+        assert_eq!(tx.account_id, None);
+        assert!(tx.hash.is_empty());
+    }
+
+    #[test]
+    fn get_set_account_id() {
+        let mut tx = LiveHashAddTransaction::new();
+        tx.account_id(ACCOUNT_ID);
+
+        assert_eq!(tx.get_account_id(), Some(ACCOUNT_ID));
+    }
+
+    #[test]
+    fn get_set_hash() {
+        let mut tx = LiveHashAddTransaction::new();
+        tx.hash(HASH);
+
+        assert_eq!(tx.get_hash(), HASH);
+    }
+
+    #[test]
+    fn get_set_keys() {
+        let mut tx = LiveHashAddTransaction::new();
+        tx.keys([key()]);
+
+        assert_eq!(tx.get_keys(), Some(&KeyList::from([key()])));
+    }
+
+    #[test]
+    fn get_set_duration() {
+        let mut tx = LiveHashAddTransaction::new();
+        tx.duration(DURATION);
+
+        assert_eq!(tx.get_duration(), Some(DURATION));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_hash_frozen_panics() {
+        make_transaction().hash(HASH);
+    }
+
+    #[test]
+    #[should_panic]
+    fn schedule_panics() {
+        let mut tx = LiveHashAddTransaction::new();
+        tx.account_id(ACCOUNT_ID).hash(HASH);
+
+        ScheduleCreateTransaction::new().scheduled_transaction(tx);
+    }
+}
@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use hedera_proto::services;
+use hedera_proto::services::crypto_service_client::CryptoServiceClient;
+use tonic::transport::Channel;
+
+use crate::ledger_id::RefLedgerId;
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::transaction::{
+    AnyTransactionData,
+    ChunkInfo,
+    ToTransactionDataProtobuf,
+    TransactionData,
+    TransactionExecute,
+};
+use crate::{
+    AccountId,
+    BoxGrpcFuture,
+    Error,
+    Transaction,
+    ValidateChecksums,
+};
+
+/// Delete a live hash from an account before it expires.
+///
+/// Either the account's key or one of the live hash's keys must sign.
+///
+/// Live hashes can't be scheduled.
+pub type LiveHashDeleteTransaction = Transaction<LiveHashDeleteTransactionData>;
+
+#[derive(Debug, Clone, Default)]
+pub struct LiveHashDeleteTransactionData {
+    /// The account the live hash is attached to.
+    account_id: Option<AccountId>,
+
+    /// The SHA-384 hash of the live hash to delete.
+    hash: Vec<u8>,
+}
+
+impl LiveHashDeleteTransaction {
+    /// Returns the account the live hash is attached to.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.data().account_id
+    }
+
+    /// Sets the account the live hash is attached to.
+    pub fn account_id(&mut self, id: AccountId) -> &mut Self {
+        self.data_mut().account_id = Some(id);
+        self
+    }
+
+    /// Returns the SHA-384 hash of the live hash to delete.
+    #[must_use]
+    pub fn get_hash(&self) -> &[u8] {
+        &self.data().hash
+    }
+
+    /// Sets the SHA-384 hash of the live hash to delete.
+    pub fn hash(&mut self, hash: impl Into<Vec<u8>>) -> &mut Self {
+        self.data_mut().hash = hash.into();
+        self
+    }
+}
+
+impl TransactionData for LiveHashDeleteTransactionData {}
+
+impl TransactionExecute for LiveHashDeleteTransactionData {
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Transaction,
+    ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
+        Box::pin(async { CryptoServiceClient::new(channel).delete_live_hash(request).await })
+    }
+}
+
+impl ValidateChecksums for LiveHashDeleteTransactionData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        self.account_id.validate_checksums(ledger_id)
+    }
+}
+
+impl ToTransactionDataProtobuf for LiveHashDeleteTransactionData {
+    fn to_transaction_data_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::transaction_body::Data {
+        let _ = chunk_info.assert_single_transaction();
+
+        services::transaction_body::Data::CryptoDeleteLiveHash(self.to_protobuf())
+    }
+}
+
+impl From<LiveHashDeleteTransactionData> for AnyTransactionData {
+    fn from(transaction: LiveHashDeleteTransactionData) -> Self {
+        Self::LiveHashDelete(transaction)
+    }
+}
+
+impl FromProtobuf<services::CryptoDeleteLiveHashTransactionBody> for LiveHashDeleteTransactionData {
+    fn from_protobuf(pb: services::CryptoDeleteLiveHashTransactionBody) -> crate::Result<Self> {
+        Ok(Self {
+            account_id: Option::from_protobuf(pb.account_of_live_hash)?,
+            hash: pb.live_hash_to_delete,
+        })
+    }
+}
+
+impl ToProtobuf for LiveHashDeleteTransactionData {
+    type Protobuf = services::CryptoDeleteLiveHashTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::CryptoDeleteLiveHashTransactionBody {
+            account_of_live_hash: self.account_id.to_protobuf(),
+            live_hash_to_delete: self.hash.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use hedera_proto::services;
+
+    use crate::account::LiveHashDeleteTransactionData;
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::transaction::test_helpers::{
+        check_body,
+        transaction_body,
+    };
+    use crate::{
+        AccountId,
+        AnyTransaction,
+        LiveHashDeleteTransaction,
+    };
+
+    const ACCOUNT_ID: AccountId = AccountId::new(0, 0, 5007);
+    const HASH: [u8; 4] = [0x01, 0x02, 0x03, 0x04];
+
+    fn make_transaction() -> LiveHashDeleteTransaction {
+        let mut tx = LiveHashDeleteTransaction::new_for_tests();
+
+        tx.account_id(ACCOUNT_ID).hash(HASH).freeze().unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn serialize() {
+        let tx = make_transaction();
+
+        let tx = transaction_body(tx);
+
+        let tx = check_body(tx);
+
+        expect![[r#"
+            CryptoDeleteLiveHash(
+                CryptoDeleteLiveHashTransactionBody {
+                    account_of_live_hash: Some(
+                        AccountId {
+                            shard_num: 0,
+                            realm_num: 0,
+                            account: Some(
+                                AccountNum(
+                                    5007,
+                                ),
+                            ),
+                        },
+                    ),
+                    live_hash_to_delete: [
+                        1,
+                        2,
+                        3,
+                        4,
+                    ],
+                },
+            )
+        "#]]
+        .assert_debug_eq(&tx)
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let tx = transaction_body(tx);
+
+        let tx2 = transaction_body(tx2);
+
+        assert_eq!(tx, tx2);
+    }
+
+    #[test]
+    fn from_proto_body() {
+        let tx = services::CryptoDeleteLiveHashTransactionBody {
+            account_of_live_hash: Some(ACCOUNT_ID.to_protobuf()),
+            live_hash_to_delete: HASH.to_vec(),
+        };
+
+        let tx = LiveHashDeleteTransactionData::from_protobuf(tx).unwrap();
+
+        assert_eq!(tx.account_id, Some(ACCOUNT_ID));
+        assert_eq!(tx.hash, HASH);
+    }
+
+    #[test]
+    fn get_set_account_id() {
+        let mut tx = LiveHashDeleteTransaction::new();
+        tx.account_id(ACCOUNT_ID);
+
+        assert_eq!(tx.get_account_id(), Some(ACCOUNT_ID));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_account_id_frozen_panics() {
+        make_transaction().account_id(ACCOUNT_ID);
+    }
+
+    #[test]
+    fn get_set_hash() {
+        let mut tx = LiveHashDeleteTransaction::new();
+        tx.hash(HASH);
+
+        assert_eq!(tx.get_hash(), HASH);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_hash_frozen_panics() {
+        make_transaction().hash(HASH);
+    }
+}
@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use hedera_proto::services;
+use hedera_proto::services::crypto_service_client::CryptoServiceClient;
+use tonic::transport::Channel;
+
+use crate::ledger_id::RefLedgerId;
+use crate::query::{
+    AnyQueryData,
+    QueryExecute,
+    ToQueryProtobuf,
+};
+use crate::{
+    AccountId,
+    BoxGrpcFuture,
+    Error,
+    LiveHash,
+    Query,
+    ToProtobuf,
+    ValidateChecksums,
+};
+
+/// Get a single live hash attached to an account, by its hash.
+pub type LiveHashQuery = Query<LiveHashQueryData>;
+
+#[derive(Debug, Clone, Default)]
+pub struct LiveHashQueryData {
+    account_id: Option<AccountId>,
+    hash: Vec<u8>,
+}
+
+impl From<LiveHashQueryData> for AnyQueryData {
+    #[inline]
+    fn from(data: LiveHashQueryData) -> Self {
+        Self::LiveHash(data)
+    }
+}
+
+impl LiveHashQuery {
+    /// Gets the account the live hash is attached to.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.data.account_id
+    }
+
+    /// Sets the account the live hash is attached to.
+    pub fn account_id(&mut self, id: AccountId) -> &mut Self {
+        self.data.account_id = Some(id);
+        self
+    }
+
+    /// Gets the SHA-384 hash of the live hash to get.
+    #[must_use]
+    pub fn get_hash(&self) -> &[u8] {
+        &self.data.hash
+    }
+
+    /// Sets the SHA-384 hash of the live hash to get.
+    pub fn hash(&mut self, hash: impl Into<Vec<u8>>) -> &mut Self {
+        self.data.hash = hash.into();
+        self
+    }
+}
+
+impl ToQueryProtobuf for LiveHashQueryData {
+    fn to_query_protobuf(&self, header: services::QueryHeader) -> services::Query {
+        let account_id = self.account_id.to_protobuf();
+
+        services::Query {
+            query: Some(services::query::Query::CryptoGetLiveHash(
+                services::CryptoGetLiveHashQuery {
+                    header: Some(header),
+                    account_id,
+                    hash: self.hash.clone(),
+                },
+            )),
+        }
+    }
+}
+
+impl QueryExecute for LiveHashQueryData {
+    type Response = LiveHash;
+
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Query,
+    ) -> BoxGrpcFuture<'_, services::Response> {
+        Box::pin(async { CryptoServiceClient::new(channel).get_live_hash(request).await })
+    }
+}
+
+impl ValidateChecksums for LiveHashQueryData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        self.account_id.validate_checksums(ledger_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hedera_proto::services;
+
+    use crate::query::ToQueryProtobuf;
+    use crate::{
+        AccountId,
+        LiveHashQuery,
+        ToProtobuf,
+    };
+
+    const ACCOUNT_ID: AccountId = AccountId::new(0, 0, 5005);
+    const HASH: [u8; 4] = [0x01, 0x02, 0x03, 0x04];
+
+    #[test]
+    fn to_query_protobuf() {
+        let query = LiveHashQuery::new()
+            .account_id(ACCOUNT_ID)
+            .hash(HASH)
+            .data
+            .to_query_protobuf(services::QueryHeader::default());
+
+        let Some(services::query::Query::CryptoGetLiveHash(query)) = query.query else {
+            panic!("expected a `CryptoGetLiveHash` query");
+        };
+
+        assert_eq!(query.account_id, Some(ACCOUNT_ID.to_protobuf()));
+        assert_eq!(query.hash, HASH);
+    }
+
+    #[test]
+    fn get_set_account_id() {
+        let mut query = LiveHashQuery::new();
+        query.account_id(ACCOUNT_ID);
+
+        assert_eq!(query.get_account_id(), Some(ACCOUNT_ID));
+    }
+
+    #[test]
+    fn get_set_hash() {
+        let mut query = LiveHashQuery::new();
+        query.hash(HASH);
+
+        assert_eq!(query.get_hash(), HASH);
+    }
+}
@@ -14,6 +14,10 @@ pub mod account_info_flow;
 mod account_info_query;
 mod account_records_query;
 mod account_update_transaction;
+mod live_hash;
+mod live_hash_add_transaction;
+mod live_hash_delete_transaction;
+mod live_hash_query;
 mod proxy_staker;
 
 pub use account_allowance_approve_transaction::AccountAllowanceApproveTransaction;
@@ -35,6 +39,13 @@ pub use account_records_query::AccountRecordsQuery;
 pub(crate) use account_records_query::AccountRecordsQueryData;
 pub use account_update_transaction::AccountUpdateTransaction;
 pub(crate) use account_update_transaction::AccountUpdateTransactionData;
+pub use live_hash::LiveHash;
+pub use live_hash_add_transaction::LiveHashAddTransaction;
+pub(crate) use live_hash_add_transaction::LiveHashAddTransactionData;
+pub use live_hash_delete_transaction::LiveHashDeleteTransaction;
+pub(crate) use live_hash_delete_transaction::LiveHashDeleteTransactionData;
+pub use live_hash_query::LiveHashQuery;
+pub(crate) use live_hash_query::LiveHashQueryData;
 pub use proxy_staker::{
     AllProxyStakers,
     ProxyStaker,
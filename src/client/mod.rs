@@ -76,6 +76,7 @@ struct ClientBuilder {
     max_query_payment: Option<NonZeroU64>,
     ledger_id: Option<LedgerId>,
     auto_validate_checksums: bool,
+    auto_validate_transactions: bool,
     regenerate_transaction_ids: bool,
     update_network: bool,
     backoff: ClientBackoff,
@@ -91,6 +92,7 @@ impl ClientBuilder {
             max_query_payment: None,
             ledger_id: None,
             auto_validate_checksums: false,
+            auto_validate_transactions: false,
             regenerate_transaction_ids: true,
             update_network: true,
             backoff: ClientBackoff::default(),
@@ -113,6 +115,7 @@ impl ClientBuilder {
             max_query_payment,
             ledger_id,
             auto_validate_checksums,
+            auto_validate_transactions,
             regenerate_transaction_ids,
             update_network,
             backoff,
@@ -136,6 +139,7 @@ impl ClientBuilder {
             max_query_payment_tinybar: AtomicU64::new(max_query_payment.map_or(0, NonZeroU64::get)),
             ledger_id: ArcSwapOption::new(ledger_id.map(Arc::new)),
             auto_validate_checksums: AtomicBool::new(auto_validate_checksums),
+            auto_validate_transactions: AtomicBool::new(auto_validate_transactions),
             regenerate_transaction_ids: AtomicBool::new(regenerate_transaction_ids),
             network_update_tx,
             backoff: RwLock::new(backoff),
@@ -150,6 +154,7 @@ struct ClientInner {
     max_query_payment_tinybar: AtomicU64,
     ledger_id: ArcSwapOption<LedgerId>,
     auto_validate_checksums: AtomicBool,
+    auto_validate_transactions: AtomicBool,
     regenerate_transaction_ids: AtomicBool,
     network_update_tx: watch::Sender<Option<Duration>>,
     backoff: RwLock<ClientBackoff>,
@@ -418,6 +423,21 @@ impl Client {
         self.0.auto_validate_checksums.store(value, Ordering::Relaxed);
     }
 
+    /// Returns true if transactions should be automatically validated before being submitted.
+    ///
+    /// This is `false` by default.
+    #[must_use]
+    pub fn auto_validate_transactions(&self) -> bool {
+        self.0.auto_validate_transactions.load(Ordering::Relaxed)
+    }
+
+    /// Enable or disable automatic local validation of transactions before they're submitted.
+    ///
+    /// See [`Transaction::validate`](crate::Transaction::validate).
+    pub fn set_auto_validate_transactions(&self, value: bool) {
+        self.0.auto_validate_transactions.store(value, Ordering::Relaxed);
+    }
+
     /// Returns true if transaction IDs should be automatically regenerated.
     ///
     /// This is `true` by default.
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::abi_function::param_types;
+use super::signature::parse_signature;
+use super::{
+    codec,
+    AbiParam,
+    AbiType,
+    AbiValue,
+};
+use crate::contract::contract_function_selector::ContractFunctionSelector;
+use crate::Error;
+
+/// A custom error in a Solidity ABI, as declared with `error InsufficientBalance(uint256 available)`.
+///
+/// Contracts revert with custom errors the same way functions are called,
+/// a 4 byte selector followed by the encoded parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiError {
+    /// The name of the error.
+    pub name: String,
+
+    /// The parameters of the error.
+    pub inputs: Vec<AbiParam>,
+}
+
+impl AbiError {
+    /// Create a new error with the given `name` and `inputs`.
+    #[must_use]
+    pub fn new(name: impl Into<String>, inputs: Vec<AbiParam>) -> Self {
+        Self { name: name.into(), inputs }
+    }
+
+    /// Parse an error from a human-readable signature, such as `InsufficientBalance(uint256 available)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `signature` isn't a valid error signature.
+    pub fn parse(signature: &str) -> crate::Result<Self> {
+        let (name, params) = parse_signature(signature)?;
+
+        let inputs = params
+            .into_iter()
+            .map(|it| {
+                if it.indexed {
+                    return Err(Error::basic_parse("error parameters can't be `indexed`"));
+                }
+
+                Ok(AbiParam::new(it.name, it.kind))
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self::new(name, inputs))
+    }
+
+    /// Returns the canonical signature of the error, for instance `InsufficientBalance(uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        format!("{}{}", self.name, AbiType::Tuple(param_types(&self.inputs)))
+    }
+
+    /// Returns the 4 byte selector that revert data for this error starts with.
+    #[must_use]
+    pub fn selector(&self) -> [u8; 4] {
+        let mut selector = ContractFunctionSelector::new(&self.name);
+
+        for input in &self.inputs {
+            selector.add_abi_type(input.kind.clone());
+        }
+
+        selector.finish()
+    }
+
+    /// Decode the parameters of this error from revert data, selector included.
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`] if `data` isn't this error.
+    pub fn decode(&self, data: &[u8]) -> crate::Result<Vec<AbiValue>> {
+        let args = data
+            .strip_prefix(&self.selector())
+            .ok_or_else(|| Error::abi_decode(format!("not a `{}` error", self.signature())))?;
+
+        codec::decode(&param_types(&self.inputs), args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AbiError;
+    use crate::{
+        AbiFunction,
+        AbiValue,
+    };
+
+    #[test]
+    fn parse() {
+        let error =
+            AbiError::parse("InsufficientBalance(uint available, uint256 required)").unwrap();
+
+        assert_eq!(error.signature(), "InsufficientBalance(uint256,uint256)");
+        assert_eq!(error.inputs[1].name, "required");
+        assert!(AbiError::parse("Bad(uint256 indexed)").is_err());
+    }
+
+    #[test]
+    fn decode() {
+        let error = AbiError::parse("InsufficientBalance(uint256,uint256)").unwrap();
+
+        // errors are encoded exactly like a call to a function of the same signature.
+        let data = AbiFunction::new("InsufficientBalance", error.inputs.clone(), Vec::new())
+            .encode_input(&[5_u8.into(), 10_u8.into()])
+            .unwrap();
+
+        assert_eq!(error.decode(&data).unwrap(), [AbiValue::from(5_u8), 10_u8.into()]);
+        assert!(error.decode(&data[4..]).is_err());
+    }
+}
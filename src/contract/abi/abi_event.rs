@@ -0,0 +1,296 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+use super::signature::parse_signature;
+use super::{
+    codec,
+    AbiParam,
+    AbiType,
+    AbiValue,
+};
+use crate::{
+    ContractId,
+    ContractLogInfo,
+    Error,
+};
+
+/// A parameter of an event in a Solidity ABI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiEventParam {
+    /// The name of the parameter, which may be empty.
+    pub name: String,
+
+    /// The type of the parameter.
+    pub kind: AbiType,
+
+    /// The named fields of the parameter, if it's a struct (or an array of structs).
+    pub components: Vec<AbiParam>,
+
+    /// Whether the parameter is stored in the log's topics rather than its data.
+    pub indexed: bool,
+}
+
+impl AbiEventParam {
+    /// Create a new parameter with the given `name` and `kind`.
+    #[must_use]
+    pub fn new(name: impl Into<String>, kind: AbiType, indexed: bool) -> Self {
+        Self { name: name.into(), kind, components: Vec::new(), indexed }
+    }
+}
+
+/// An event in a Solidity ABI.
+///
+/// Matches and decodes the [`ContractLogInfo`]s emitted by a contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiEvent {
+    /// The name of the event.
+    pub name: String,
+
+    /// The parameters of the event.
+    pub inputs: Vec<AbiEventParam>,
+
+    /// Whether the event was declared `anonymous`, so its logs don't start with [`topic`](Self::topic).
+    pub anonymous: bool,
+}
+
+impl AbiEvent {
+    /// Create a new event with the given `name` and `inputs`.
+    #[must_use]
+    pub fn new(name: impl Into<String>, inputs: Vec<AbiEventParam>) -> Self {
+        Self { name: name.into(), inputs, anonymous: false }
+    }
+
+    /// Parse an event from a human-readable signature, such as `Transfer(address indexed,address indexed,uint256)`.
+    ///
+    /// Parameters may also be named, as in `Transfer(address indexed from, address indexed to, uint256 value)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `signature` isn't a valid event signature.
+    pub fn parse(signature: &str) -> crate::Result<Self> {
+        let (name, params) = parse_signature(signature)?;
+
+        let inputs =
+            params.into_iter().map(|it| AbiEventParam::new(it.name, it.kind, it.indexed)).collect();
+
+        Ok(Self::new(name, inputs))
+    }
+
+    /// Returns the canonical signature of the event, for instance `Transfer(address,address,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        let types = self.inputs.iter().map(|it| it.kind.clone()).collect();
+
+        format!("{}{}", self.name, AbiType::Tuple(types))
+    }
+
+    /// Returns the Keccak-256 hash of the signature, which is the first topic of the event's logs.
+    #[must_use]
+    pub fn topic(&self) -> [u8; 32] {
+        Keccak256::digest(self.signature()).into()
+    }
+
+    /// Returns `true` if `log` looks like it was emitted by this event.
+    ///
+    /// For an anonymous event only the number of topics can be checked.
+    #[must_use]
+    pub fn matches(&self, log: &ContractLogInfo) -> bool {
+        self.indexed_topics(log).is_some()
+    }
+
+    /// Decode the parameters of the event from `log`, in the order they were declared.
+    ///
+    /// Indexed parameters are decoded from the log's topics, except for dynamic types like `string`,
+    /// which are only stored as the Keccak-256 hash of their encoding, and are returned as [`AbiValue::FixedBytes`].
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`] if `log` wasn't emitted by this event, or its data doesn't match the parameters.
+    pub fn decode_log(&self, log: &ContractLogInfo) -> crate::Result<ContractEvent> {
+        let mut topics = self
+            .indexed_topics(log)
+            .ok_or_else(|| Error::abi_decode(format!("log isn't a `{}` event", self.signature())))?
+            .iter();
+
+        let data_types: Vec<_> =
+            self.inputs.iter().filter(|it| !it.indexed).map(|it| it.kind.clone()).collect();
+
+        let mut data = codec::decode(&data_types, &log.data)?.into_iter();
+
+        let values = self
+            .inputs
+            .iter()
+            .map(|input| {
+                if !input.indexed {
+                    // `decode` returns exactly one value per type.
+                    return Ok(data.next().unwrap());
+                }
+
+                let topic = topic_word(topics.next().unwrap())?;
+
+                if input.kind.is_dynamic() {
+                    Ok(AbiValue::FixedBytes(topic.to_vec()))
+                } else {
+                    codec::decode_value(&input.kind, &topic)
+                }
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(ContractEvent { contract_id: log.contract_id, name: self.name.clone(), values })
+    }
+
+    // the topics holding indexed parameters, if the log could be from this event.
+    fn indexed_topics<'a>(&self, log: &'a ContractLogInfo) -> Option<&'a [Vec<u8>]> {
+        let topics = if self.anonymous {
+            &log.topics[..]
+        } else {
+            let (topic0, topics) = log.topics.split_first()?;
+
+            if topic_word(topic0).ok()? != self.topic() {
+                return None;
+            }
+
+            topics
+        };
+
+        (topics.len() == self.inputs.iter().filter(|it| it.indexed).count()).then_some(topics)
+    }
+}
+
+// topics are 32 byte words, but may come back without their leading zeros.
+fn topic_word(topic: &[u8]) -> crate::Result<[u8; 32]> {
+    let padding = 32_usize.checked_sub(topic.len()).ok_or_else(|| {
+        Error::abi_decode(format!("log topic is {} bytes, expected at most 32", topic.len()))
+    })?;
+
+    let mut word = [0; 32];
+    word[padding..].copy_from_slice(topic);
+
+    Ok(word)
+}
+
+/// An event decoded from a [`ContractLogInfo`] by [`AbiEvent::decode_log`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractEvent {
+    /// The contract that emitted the event.
+    pub contract_id: ContractId,
+
+    /// The name of the event.
+    pub name: String,
+
+    /// The values of the event's parameters, in the order they were declared.
+    pub values: Vec<AbiValue>,
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::AbiEvent;
+    use crate::{
+        AbiValue,
+        ContractId,
+        ContractLogInfo,
+        EvmAddress,
+    };
+
+    fn transfer_log() -> ContractLogInfo {
+        ContractLogInfo {
+            contract_id: ContractId::new(0, 0, 1001),
+            bloom: Vec::new(),
+            topics: vec![
+                hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").to_vec(),
+                hex!("0000000000000000000000001111111111111111111111111111111111111111").to_vec(),
+                hex!("0000000000000000000000002222222222222222222222222222222222222222").to_vec(),
+            ],
+            data: hex!("00000000000000000000000000000000000000000000000000000000000003e8").to_vec(),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let event =
+            AbiEvent::parse("Transfer(address indexed from, address indexed to, uint value)")
+                .unwrap();
+
+        assert_eq!(event.signature(), "Transfer(address,address,uint256)");
+        assert_eq!(event.inputs[1].name, "to");
+        assert!(event.inputs[1].indexed && !event.inputs[2].indexed);
+    }
+
+    #[test]
+    fn topic() {
+        let event = AbiEvent::parse("Transfer(address indexed,address indexed,uint256)").unwrap();
+
+        assert_eq!(
+            event.topic(),
+            hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+        );
+    }
+
+    #[test]
+    fn decode_log() {
+        let event = AbiEvent::parse("Transfer(address indexed,address indexed,uint256)").unwrap();
+
+        let decoded = event.decode_log(&transfer_log()).unwrap();
+
+        assert_eq!(decoded.name, "Transfer");
+        assert_eq!(decoded.contract_id, ContractId::new(0, 0, 1001));
+        assert_eq!(
+            decoded.values,
+            [
+                AbiValue::from(EvmAddress::from([0x11; 20])),
+                AbiValue::from(EvmAddress::from([0x22; 20])),
+                AbiValue::from(1000_u32),
+            ]
+        );
+    }
+
+    #[test]
+    fn mismatched_log() {
+        // same signature, but the indexing differs, so the number of topics doesn't match.
+        let event = AbiEvent::parse("Transfer(address indexed,address,uint256)").unwrap();
+
+        assert!(!event.matches(&transfer_log()));
+        assert!(event.decode_log(&transfer_log()).is_err());
+
+        let event = AbiEvent::parse("Approval(address indexed,address indexed,uint256)").unwrap();
+
+        assert!(!event.matches(&transfer_log()));
+    }
+
+    #[test]
+    fn indexed_dynamic_is_hashed() {
+        let event = AbiEvent::parse("Named(string indexed name, string label)").unwrap();
+
+        let log = ContractLogInfo {
+            contract_id: ContractId::new(0, 0, 1001),
+            bloom: Vec::new(),
+            topics: vec![event.topic().to_vec(), vec![0xab; 32]],
+            data: crate::contract::abi::codec::encode(&[crate::AbiType::String], &["hi".into()])
+                .unwrap(),
+        };
+
+        assert_eq!(
+            event.decode_log(&log).unwrap().values,
+            [AbiValue::FixedBytes(vec![0xab; 32]), AbiValue::from("hi")]
+        );
+    }
+
+    #[test]
+    fn anonymous() {
+        let mut event = AbiEvent::parse("Poked(uint8 indexed)").unwrap();
+        event.anonymous = true;
+
+        let log = ContractLogInfo {
+            contract_id: ContractId::new(0, 0, 1001),
+            bloom: Vec::new(),
+            topics: vec![vec![0x07]],
+            data: Vec::new(),
+        };
+
+        assert_eq!(event.decode_log(&log).unwrap().values, [AbiValue::from(7_u8)]);
+    }
+}
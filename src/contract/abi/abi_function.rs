@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{
+    codec,
+    AbiStateMutability,
+    AbiType,
+    AbiValue,
+};
+use crate::contract::contract_function_selector::ContractFunctionSelector;
+use crate::Error;
+
+/// A named parameter of a function, constructor or return value in a Solidity ABI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiParam {
+    /// The name of the parameter, which may be empty.
+    pub name: String,
+
+    /// The type of the parameter.
+    pub kind: AbiType,
+
+    /// The named fields of the parameter, if it's a struct (or an array of structs).
+    ///
+    /// These are in the same order as the fields of the [`AbiType::Tuple`] in `kind`.
+    pub components: Vec<AbiParam>,
+}
+
+impl AbiParam {
+    /// Create a new parameter with the given `name` and `kind`.
+    #[must_use]
+    pub fn new(name: impl Into<String>, kind: AbiType) -> Self {
+        Self { name: name.into(), kind, components: Vec::new() }
+    }
+}
+
+/// A function in a Solidity ABI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiFunction {
+    /// The name of the function.
+    pub name: String,
+
+    /// The parameters of the function.
+    pub inputs: Vec<AbiParam>,
+
+    /// The return values of the function.
+    pub outputs: Vec<AbiParam>,
+
+    /// Whether the function reads or modifies contract state, and whether it accepts hbar.
+    pub state_mutability: AbiStateMutability,
+}
+
+impl AbiFunction {
+    /// Create a new function with the given `name`, `inputs` and `outputs`.
+    #[must_use]
+    pub fn new(name: impl Into<String>, inputs: Vec<AbiParam>, outputs: Vec<AbiParam>) -> Self {
+        Self { name: name.into(), inputs, outputs, state_mutability: AbiStateMutability::default() }
+    }
+
+    /// Returns the canonical signature of the function, for instance `transfer(address,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        format!("{}{}", self.name, AbiType::Tuple(param_types(&self.inputs)))
+    }
+
+    /// Returns the 4 byte selector that identifies calls to this function.
+    #[must_use]
+    pub fn selector(&self) -> [u8; 4] {
+        let mut selector = ContractFunctionSelector::new(&self.name);
+
+        for input in &self.inputs {
+            selector.add_abi_type(input.kind.clone());
+        }
+
+        selector.finish()
+    }
+
+    /// Encode a call to this function with the given arguments, selector included.
+    ///
+    /// The result can be passed to [`ContractExecuteTransaction::function_parameters`](crate::ContractExecuteTransaction::function_parameters)
+    /// or [`ContractCallQuery::function_parameters`](crate::ContractCallQuery::function_parameters).
+    ///
+    /// # Errors
+    /// - [`Error::AbiEncode`] if `args` don't match the parameters of the function.
+    pub fn encode_input(&self, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        let mut bytes = self.selector().to_vec();
+        bytes.extend(codec::encode(&param_types(&self.inputs), args)?);
+
+        Ok(bytes)
+    }
+
+    /// Decode the arguments of a call to this function, selector included.
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`] if `data` isn't a call to this function.
+    pub fn decode_input(&self, data: &[u8]) -> crate::Result<Vec<AbiValue>> {
+        let args = data
+            .strip_prefix(&self.selector())
+            .ok_or_else(|| Error::abi_decode(format!("not a call to `{}`", self.signature())))?;
+
+        codec::decode(&param_types(&self.inputs), args)
+    }
+
+    /// Encode the return values of this function.
+    ///
+    /// # Errors
+    /// - [`Error::AbiEncode`] if `values` don't match the outputs of the function.
+    pub fn encode_output(&self, values: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        codec::encode(&param_types(&self.outputs), values)
+    }
+
+    /// Decode the return values of this function, for instance from [`ContractFunctionResult::as_bytes`](crate::ContractFunctionResult::as_bytes).
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`] if `data` doesn't match the outputs of the function.
+    pub fn decode_output(&self, data: &[u8]) -> crate::Result<Vec<AbiValue>> {
+        codec::decode(&param_types(&self.outputs), data)
+    }
+}
+
+/// The constructor of a contract in a Solidity ABI.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AbiConstructor {
+    /// The parameters of the constructor.
+    pub inputs: Vec<AbiParam>,
+
+    /// Whether the constructor accepts hbar.
+    pub state_mutability: AbiStateMutability,
+}
+
+impl AbiConstructor {
+    /// Encode the arguments to the constructor.
+    ///
+    /// Unlike function calls there's no selector, the result can be passed to
+    /// [`ContractCreateTransaction::constructor_parameters`](crate::ContractCreateTransaction::constructor_parameters).
+    ///
+    /// # Errors
+    /// - [`Error::AbiEncode`] if `args` don't match the parameters of the constructor.
+    pub fn encode_input(&self, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        codec::encode(&param_types(&self.inputs), args)
+    }
+
+    /// Decode the arguments to the constructor, as they follow the creation bytecode.
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`] if `data` doesn't match the parameters of the constructor.
+    pub fn decode_input(&self, data: &[u8]) -> crate::Result<Vec<AbiValue>> {
+        codec::decode(&param_types(&self.inputs), data)
+    }
+}
+
+pub(crate) fn param_types(params: &[AbiParam]) -> Vec<AbiType> {
+    params.iter().map(|it| it.kind.clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{
+        AbiFunction,
+        AbiParam,
+    };
+    use crate::contract::contract_function_selector::ContractFunctionSelector;
+    use crate::{
+        AbiType,
+        AbiValue,
+        EvmAddress,
+    };
+
+    fn transfer() -> AbiFunction {
+        AbiFunction::new(
+            "transfer",
+            vec![
+                AbiParam::new("to", AbiType::Address),
+                AbiParam::new("amount", AbiType::Uint(256)),
+            ],
+            vec![AbiParam::new("", AbiType::Bool)],
+        )
+    }
+
+    #[test]
+    fn selector() {
+        assert_eq!(transfer().signature(), "transfer(address,uint256)");
+        assert_eq!(hex::encode(transfer().selector()), "a9059cbb");
+    }
+
+    #[test]
+    fn tuple_selector_matches_builder() {
+        let function = AbiFunction::new(
+            "swap",
+            vec![AbiParam::new("", AbiType::from_str("(address,uint256)[]").unwrap())],
+            Vec::new(),
+        );
+
+        assert_eq!(
+            function.selector(),
+            ContractFunctionSelector::new("swap").add_param_type("(address,uint256)[]").finish()
+        );
+    }
+
+    #[test]
+    fn input_round_trip() {
+        let args = [AbiValue::from(EvmAddress::from([0xab; 20])), AbiValue::from(1000_u64)];
+
+        let bytes = transfer().encode_input(&args).unwrap();
+
+        assert_eq!(bytes[..4], transfer().selector());
+        assert_eq!(transfer().decode_input(&bytes).unwrap(), args);
+        assert!(transfer().decode_input(&bytes[4..]).is_err());
+    }
+
+    #[test]
+    fn output_round_trip() {
+        let bytes = transfer().encode_output(&[true.into()]).unwrap();
+
+        assert_eq!(transfer().decode_output(&bytes).unwrap(), [AbiValue::Bool(true)]);
+    }
+}
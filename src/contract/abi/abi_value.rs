@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use num_bigint::{
+    BigInt,
+    BigUint,
+};
+
+use crate::EvmAddress;
+
+/// A value that can be encoded as, or was decoded from, a Solidity ABI type.
+///
+/// Integers of any width are accepted for any integer type, as long as they fit in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiValue {
+    /// An `address`.
+    Address(EvmAddress),
+
+    /// A `bool`.
+    Bool(bool),
+
+    /// An `int<M>`.
+    Int(BigInt),
+
+    /// A `uint<M>`.
+    Uint(BigUint),
+
+    /// A `bytes<M>`, which must be exactly `M` bytes long.
+    FixedBytes(Vec<u8>),
+
+    /// A `bytes`.
+    Bytes(Vec<u8>),
+
+    /// A `string`.
+    String(String),
+
+    /// A `function`, the address of a contract and the selector of one of its functions.
+    Function(EvmAddress, [u8; 4]),
+
+    /// A `T[]`.
+    Array(Vec<AbiValue>),
+
+    /// A `T[k]`.
+    FixedArray(Vec<AbiValue>),
+
+    /// A tuple, or struct, with its fields in declaration order.
+    Tuple(Vec<AbiValue>),
+}
+
+impl AbiValue {
+    /// Returns the value as an `address`, if it is one.
+    #[must_use]
+    pub fn as_address(&self) -> Option<EvmAddress> {
+        match self {
+            Self::Address(it) => Some(*it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a `bool`, if it is one.
+    #[must_use]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(it) => Some(*it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a signed integer, if it's an integer.
+    #[must_use]
+    pub fn as_int(&self) -> Option<BigInt> {
+        match self {
+            Self::Int(it) => Some(it.clone()),
+            Self::Uint(it) => Some(it.clone().into()),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as an unsigned integer, if it's a non-negative integer.
+    #[must_use]
+    pub fn as_uint(&self) -> Option<BigUint> {
+        match self {
+            Self::Int(it) => it.to_biguint(),
+            Self::Uint(it) => Some(it.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as bytes, if it's a `bytes` or `bytes<M>`.
+    #[must_use]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(it) | Self::FixedBytes(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a `string`, if it is one.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of the value, if it's a `T[]` or `T[k]`.
+    #[must_use]
+    pub fn as_array(&self) -> Option<&[AbiValue]> {
+        match self {
+            Self::Array(it) | Self::FixedArray(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the fields of the value, if it's a tuple.
+    #[must_use]
+    pub fn as_tuple(&self) -> Option<&[AbiValue]> {
+        match self {
+            Self::Tuple(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// A short description of what kind of value this is, for error messages.
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            Self::Address(_) => "an address",
+            Self::Bool(_) => "a bool",
+            Self::Int(_) | Self::Uint(_) => "an integer",
+            Self::FixedBytes(_) => "fixed bytes",
+            Self::Bytes(_) => "bytes",
+            Self::String(_) => "a string",
+            Self::Function(..) => "a function",
+            Self::Array(_) | Self::FixedArray(_) => "an array",
+            Self::Tuple(_) => "a tuple",
+        }
+    }
+}
+
+impl From<EvmAddress> for AbiValue {
+    fn from(value: EvmAddress) -> Self {
+        Self::Address(value)
+    }
+}
+
+impl From<bool> for AbiValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<BigInt> for AbiValue {
+    fn from(value: BigInt) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<BigUint> for AbiValue {
+    fn from(value: BigUint) -> Self {
+        Self::Uint(value)
+    }
+}
+
+impl From<String> for AbiValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for AbiValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for AbiValue {
+    fn from(value: [u8; N]) -> Self {
+        Self::FixedBytes(value.to_vec())
+    }
+}
+
+macro_rules! impl_from_int {
+    ($variant:ident: $($ty:ty),*) => {
+        $(
+            impl From<$ty> for AbiValue {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(Uint: u8, u16, u32, u64, u128);
+impl_from_int!(Int: i8, i16, i32, i64, i128);
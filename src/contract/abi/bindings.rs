@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runtime support for the contract bindings generated by `hedera-contract-macros`.
+//!
+//! The generated code passes selectors and types that were computed from the ABI when it was generated,
+//! so it only fails on arguments that don't fit their types, and on data that doesn't decode.
+
+use super::{
+    codec,
+    AbiEvent,
+    AbiType,
+    AbiValue,
+};
+use crate::{
+    ContractFunctionResult,
+    ContractId,
+    ContractLogInfo,
+    Error,
+};
+
+/// Encode a call to the function with `selector` and parameters of `types`, selector included.
+///
+/// # Errors
+/// - [`Error::AbiEncode`] if an argument doesn't fit its type, like a `BigUint` that's too large for a `uint160`.
+pub fn encode_call(
+    selector: [u8; 4],
+    types: &[AbiType],
+    args: &[AbiValue],
+) -> crate::Result<Vec<u8>> {
+    let mut bytes = selector.to_vec();
+    bytes.extend(codec::encode(types, args)?);
+
+    Ok(bytes)
+}
+
+/// Encode constructor arguments of `types`.
+///
+/// # Errors
+/// - [`Error::AbiEncode`] if an argument doesn't fit its type, like a `BigUint` that's too large for a `uint160`.
+pub fn encode_constructor(types: &[AbiType], args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+    codec::encode(types, args)
+}
+
+/// Decode values of `types`.
+///
+/// # Errors
+/// - [`Error::AbiDecode`] if `data` doesn't match `types`.
+pub fn decode(types: &[AbiType], data: &[u8]) -> crate::Result<Vec<AbiValue>> {
+    codec::decode(types, data)
+}
+
+/// Decode `log` as `event`.
+///
+/// # Errors
+/// - [`Error::AbiDecode`] if `log` isn't the event.
+pub fn decode_log(event: &AbiEvent, log: &ContractLogInfo) -> crate::Result<Vec<AbiValue>> {
+    Ok(event.decode_log(log)?.values)
+}
+
+/// Decode every log in `result` that's `event` and was emitted by `contract_id`.
+///
+/// # Errors
+/// - [`Error::AbiDecode`] if a log matching the event fails to decode.
+pub fn decode_events(
+    event: &AbiEvent,
+    result: &ContractFunctionResult,
+    contract_id: ContractId,
+) -> crate::Result<Vec<Vec<AbiValue>>> {
+    let events = result.decode_events(event, Some(contract_id))?;
+
+    Ok(events.into_iter().map(|it| it.values).collect())
+}
+
+/// Unwrap a value converted to its Rust type, which only fails if the decoded values don't match the generated types.
+///
+/// # Errors
+/// - [`Error::AbiDecode`] if `value` is `None`.
+pub fn expect_value<T>(value: Option<T>) -> crate::Result<T> {
+    value.ok_or_else(|| Error::abi_decode("decoded value doesn't match the type of the binding"))
+}
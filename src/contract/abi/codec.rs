@@ -0,0 +1,539 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Encoding and decoding of values in the Solidity contract ABI format.
+//!
+//! See <https://docs.soliditylang.org/en/latest/abi-spec.html#formal-specification-of-the-encoding>.
+
+use std::iter;
+
+use num_bigint::{
+    BigInt,
+    BigUint,
+    Sign,
+};
+
+use super::{
+    AbiType,
+    AbiValue,
+};
+use crate::{
+    Error,
+    EvmAddress,
+};
+
+const WORD: usize = 32;
+
+/// Encode `values` as a tuple of `types`, as is done for function arguments and return values.
+pub(crate) fn encode(types: &[AbiType], values: &[AbiValue]) -> crate::Result<Vec<u8>> {
+    if types.len() != values.len() {
+        return Err(Error::abi_encode(format!(
+            "expected {} values, found {}",
+            types.len(),
+            values.len()
+        )));
+    }
+
+    let mut out = Vec::new();
+    encode_sequence(types.iter(), values, &mut out)?;
+
+    Ok(out)
+}
+
+/// Decode a tuple of `types` from `data`, as is done for function arguments and return values.
+pub(crate) fn decode(types: &[AbiType], data: &[u8]) -> crate::Result<Vec<AbiValue>> {
+    decode_sequence(types.iter(), data)
+}
+
+/// Encode a single value as `ty`, as it would appear on its own (in a tail, or an event topic).
+pub(crate) fn encode_value(ty: &AbiType, value: &AbiValue, out: &mut Vec<u8>) -> crate::Result<()> {
+    match (ty, value) {
+        (AbiType::Address, AbiValue::Address(address)) => {
+            out.extend_from_slice(&left_pad(&address.to_bytes(), false));
+        }
+
+        (AbiType::Bool, AbiValue::Bool(value)) => {
+            out.extend_from_slice(&usize_word(usize::from(*value)))
+        }
+
+        (AbiType::Uint(bits), AbiValue::Uint(value)) => encode_uint(value, *bits, out)?,
+
+        (AbiType::Uint(bits), AbiValue::Int(value)) => {
+            let value = value.to_biguint().ok_or_else(|| {
+                Error::abi_encode(format!("`{value}` is negative, `{ty}` expected"))
+            })?;
+
+            encode_uint(&value, *bits, out)?;
+        }
+
+        (AbiType::Int(bits), AbiValue::Int(value)) => encode_int(value, *bits, out)?,
+
+        (AbiType::Int(bits), AbiValue::Uint(value)) => {
+            encode_int(&BigInt::from(value.clone()), *bits, out)?;
+        }
+
+        (AbiType::FixedBytes(len), AbiValue::FixedBytes(bytes)) => {
+            if bytes.len() != *len {
+                return Err(Error::abi_encode(format!(
+                    "{} bytes can't be encoded as `{ty}`",
+                    bytes.len()
+                )));
+            }
+
+            out.extend_from_slice(bytes);
+            right_pad(out);
+        }
+
+        (AbiType::Bytes, AbiValue::Bytes(bytes) | AbiValue::FixedBytes(bytes)) => {
+            encode_dynamic_bytes(bytes, out);
+        }
+
+        (AbiType::String, AbiValue::String(value)) => encode_dynamic_bytes(value.as_bytes(), out),
+
+        (AbiType::Function, AbiValue::Function(address, selector)) => {
+            out.extend_from_slice(&address.to_bytes());
+            out.extend_from_slice(selector);
+            right_pad(out);
+        }
+
+        (AbiType::Array(ty), AbiValue::Array(values) | AbiValue::FixedArray(values)) => {
+            out.extend_from_slice(&usize_word(values.len()));
+            encode_sequence(iter::repeat(&**ty).take(values.len()), values, out)?;
+        }
+
+        (AbiType::FixedArray(ty, len), AbiValue::Array(values) | AbiValue::FixedArray(values)) => {
+            if values.len() != *len {
+                return Err(Error::abi_encode(format!(
+                    "array of {} elements can't be encoded as `{ty}[{len}]`",
+                    values.len()
+                )));
+            }
+
+            encode_sequence(iter::repeat(&**ty).take(*len), values, out)?;
+        }
+
+        (AbiType::Tuple(types), AbiValue::Tuple(values)) => {
+            if values.len() != types.len() {
+                return Err(Error::abi_encode(format!(
+                    "tuple of {} fields can't be encoded as `{ty}`",
+                    values.len()
+                )));
+            }
+
+            encode_sequence(types.iter(), values, out)?;
+        }
+
+        _ => {
+            return Err(Error::abi_encode(format!("`{ty}` expected, found {}", value.kind())));
+        }
+    }
+
+    Ok(())
+}
+
+// the head of a sequence holds static values in place, and offsets (from the start of the head) to dynamic values,
+// which are appended after the head in the same order.
+fn encode_sequence<'a, I>(types: I, values: &[AbiValue], out: &mut Vec<u8>) -> crate::Result<()>
+where
+    I: Iterator<Item = &'a AbiType> + Clone,
+{
+    let too_large = || Error::abi_encode("values are too large to encode");
+
+    let head_len = types
+        .clone()
+        .try_fold(0_usize, |sum, ty| sum.checked_add(ty.head_len()))
+        .ok_or_else(too_large)?;
+
+    // note: `head_len` comes from the types, which may be much larger than the values actually given.
+    let mut head = Vec::new();
+    let mut tail = Vec::new();
+
+    for (ty, value) in types.zip(values) {
+        if ty.is_dynamic() {
+            let offset = head_len.checked_add(tail.len()).ok_or_else(too_large)?;
+            head.extend_from_slice(&usize_word(offset));
+            encode_value(ty, value, &mut tail)?;
+        } else {
+            encode_value(ty, value, &mut head)?;
+        }
+    }
+
+    out.append(&mut head);
+    out.append(&mut tail);
+
+    Ok(())
+}
+
+fn encode_uint(value: &BigUint, bits: usize, out: &mut Vec<u8>) -> crate::Result<()> {
+    if value.bits() > bits as u64 {
+        return Err(Error::abi_encode(format!("`{value}` is out of range for `uint{bits}`")));
+    }
+
+    out.extend_from_slice(&left_pad(&value.to_bytes_be(), false));
+
+    Ok(())
+}
+
+fn encode_int(value: &BigInt, bits: usize, out: &mut Vec<u8>) -> crate::Result<()> {
+    if !int_fits(value, bits) {
+        return Err(Error::abi_encode(format!("`{value}` is out of range for `int{bits}`")));
+    }
+
+    out.extend_from_slice(&left_pad(&value.to_signed_bytes_be(), value.sign() == Sign::Minus));
+
+    Ok(())
+}
+
+fn int_fits(value: &BigInt, bits: usize) -> bool {
+    // `-2^(bits - 1)..2^(bits - 1)`, so the magnitude of `value` (or `-value - 1`) needs at most `bits - 1` bits.
+    let magnitude = match value.sign() {
+        Sign::Minus => -value - 1,
+        _ => value.clone(),
+    };
+
+    magnitude.bits() < bits as u64
+}
+
+fn encode_dynamic_bytes(bytes: &[u8], out: &mut Vec<u8>) {
+    out.extend_from_slice(&usize_word(bytes.len()));
+    out.extend_from_slice(bytes);
+    right_pad(out);
+}
+
+fn usize_word(value: usize) -> [u8; WORD] {
+    left_pad(&value.to_be_bytes(), false)
+}
+
+fn left_pad(bytes: &[u8], is_negative: bool) -> [u8; WORD] {
+    let mut word = [if is_negative { 0xff } else { 0x00 }; WORD];
+    word[WORD - bytes.len()..].copy_from_slice(bytes);
+    word
+}
+
+/// Pads out `buf` so that its length is a multiple of 32.
+fn right_pad(buf: &mut Vec<u8>) {
+    buf.resize(buf.len().next_multiple_of(WORD), 0);
+}
+
+// `data` starts at the beginning of the sequence's head, since that's what offsets are relative to.
+fn decode_sequence<'a, I>(types: I, data: &[u8]) -> crate::Result<Vec<AbiValue>>
+where
+    I: Iterator<Item = &'a AbiType>,
+{
+    let mut offset = 0;
+
+    types
+        .map(|ty| {
+            let value = if ty.is_dynamic() {
+                let tail_offset = read_usize(data, offset)?;
+                decode_value(ty, slice_from(data, tail_offset)?)?
+            } else {
+                decode_value(ty, slice_from(data, offset)?)?
+            };
+
+            offset = offset.saturating_add(ty.head_len());
+
+            Ok(value)
+        })
+        .collect()
+}
+
+/// Decode a single value of type `ty` from the start of `data`.
+pub(crate) fn decode_value(ty: &AbiType, data: &[u8]) -> crate::Result<AbiValue> {
+    let value = match ty {
+        AbiType::Address => {
+            let word = read_word(data, 0)?;
+            let (padding, address) = word.split_at(WORD - 20);
+
+            if padding.iter().any(|it| *it != 0) {
+                return Err(Error::abi_decode("`address` has non-zero padding"));
+            }
+
+            AbiValue::Address(EvmAddress::from(<[u8; 20]>::try_from(address).unwrap()))
+        }
+
+        AbiType::Bool => match read_usize(data, 0)? {
+            0 => AbiValue::Bool(false),
+            1 => AbiValue::Bool(true),
+            value => return Err(Error::abi_decode(format!("`{value}` is not a valid `bool`"))),
+        },
+
+        AbiType::Uint(bits) => {
+            let value = BigUint::from_bytes_be(read_word(data, 0)?);
+
+            if value.bits() > *bits as u64 {
+                return Err(Error::abi_decode(format!("`{value}` is out of range for `{ty}`")));
+            }
+
+            AbiValue::Uint(value)
+        }
+
+        AbiType::Int(bits) => {
+            let value = BigInt::from_signed_bytes_be(read_word(data, 0)?);
+
+            if !int_fits(&value, *bits) {
+                return Err(Error::abi_decode(format!("`{value}` is out of range for `{ty}`")));
+            }
+
+            AbiValue::Int(value)
+        }
+
+        AbiType::FixedBytes(len) => {
+            let (bytes, padding) = read_word(data, 0)?.split_at(*len);
+
+            if padding.iter().any(|it| *it != 0) {
+                return Err(Error::abi_decode(format!("`{ty}` has non-zero padding")));
+            }
+
+            AbiValue::FixedBytes(bytes.to_vec())
+        }
+
+        AbiType::Bytes => AbiValue::Bytes(read_dynamic_bytes(data)?.to_vec()),
+
+        AbiType::String => AbiValue::String(
+            String::from_utf8(read_dynamic_bytes(data)?.to_vec()).map_err(Error::abi_decode)?,
+        ),
+
+        AbiType::Function => {
+            let word = read_word(data, 0)?;
+
+            AbiValue::Function(
+                EvmAddress::from(<[u8; 20]>::try_from(&word[..20]).unwrap()),
+                word[20..24].try_into().unwrap(),
+            )
+        }
+
+        AbiType::Array(ty) => {
+            let len = read_usize(data, 0)?;
+            let data = slice_from(data, WORD)?;
+
+            // every element takes up at least one word, so this bounds the allocation by the size of `data`.
+            if len.checked_mul(ty.head_len()).map_or(true, |it| it > data.len()) {
+                return Err(Error::abi_decode(format!("array length `{len}` is out of bounds")));
+            }
+
+            AbiValue::Array(decode_sequence(iter::repeat(&**ty).take(len), data)?)
+        }
+
+        AbiType::FixedArray(ty, len) => {
+            AbiValue::FixedArray(decode_sequence(iter::repeat(&**ty).take(*len), data)?)
+        }
+
+        AbiType::Tuple(types) => AbiValue::Tuple(decode_sequence(types.iter(), data)?),
+    };
+
+    Ok(value)
+}
+
+fn slice_from(data: &[u8], offset: usize) -> crate::Result<&[u8]> {
+    data.get(offset..)
+        .ok_or_else(|| Error::abi_decode(format!("offset `{offset}` is out of bounds")))
+}
+
+fn read_word(data: &[u8], offset: usize) -> crate::Result<&[u8; WORD]> {
+    data.get(offset..)
+        .and_then(|it| it.get(..WORD))
+        .map(|it| it.try_into().unwrap())
+        .ok_or_else(|| Error::abi_decode(format!("data ended before offset `{}`", offset + WORD)))
+}
+
+fn read_usize(data: &[u8], offset: usize) -> crate::Result<usize> {
+    let word = read_word(data, offset)?;
+    let (high, low) = word.split_at(WORD - 8);
+
+    if high.iter().any(|it| *it != 0) {
+        return Err(Error::abi_decode("offset or length is too large"));
+    }
+
+    usize::try_from(u64::from_be_bytes(low.try_into().unwrap()))
+        .map_err(|_| Error::abi_decode("offset or length is too large"))
+}
+
+fn read_dynamic_bytes(data: &[u8]) -> crate::Result<&[u8]> {
+    let len = read_usize(data, 0)?;
+
+    slice_from(data, WORD)?
+        .get(..len)
+        .ok_or_else(|| Error::abi_decode(format!("data ended before the end of `{len}` bytes")))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use hex_literal::hex;
+    use num_bigint::BigInt;
+
+    use super::{
+        decode,
+        encode,
+    };
+    use crate::{
+        AbiType,
+        AbiValue,
+        EvmAddress,
+    };
+
+    fn types(types: &[&str]) -> Vec<AbiType> {
+        types.iter().map(|it| AbiType::from_str(it).unwrap()).collect()
+    }
+
+    // from the examples in the solidity ABI specification.
+    #[test]
+    fn static_and_dynamic() {
+        let types = types(&["uint256", "uint32[]", "bytes10", "bytes"]);
+        let values = [
+            AbiValue::from(0x123_u32),
+            AbiValue::Array(vec![0x456_u32.into(), 0x789_u32.into()]),
+            AbiValue::from(*b"1234567890"),
+            AbiValue::Bytes(b"Hello, world!".to_vec()),
+        ];
+
+        let bytes = encode(&types, &values).unwrap();
+
+        assert_eq!(
+            bytes,
+            hex!(
+                "0000000000000000000000000000000000000000000000000000000000000123"
+                "0000000000000000000000000000000000000000000000000000000000000080"
+                "3132333435363738393000000000000000000000000000000000000000000000"
+                "00000000000000000000000000000000000000000000000000000000000000e0"
+                "0000000000000000000000000000000000000000000000000000000000000002"
+                "0000000000000000000000000000000000000000000000000000000000000456"
+                "0000000000000000000000000000000000000000000000000000000000000789"
+                "000000000000000000000000000000000000000000000000000000000000000d"
+                "48656c6c6f2c20776f726c642100000000000000000000000000000000000000"
+            )
+        );
+
+        assert_eq!(decode(&types, &bytes).unwrap(), values);
+    }
+
+    // from the examples in the solidity ABI specification.
+    #[test]
+    fn nested_dynamic_arrays() {
+        let types = types(&["uint256[][]", "string[]"]);
+        let values = [
+            AbiValue::Array(vec![
+                AbiValue::Array(vec![1_u8.into(), 2_u8.into()]),
+                AbiValue::Array(vec![3_u8.into()]),
+            ]),
+            AbiValue::Array(vec!["one".into(), "two".into(), "three".into()]),
+        ];
+
+        let bytes = encode(&types, &values).unwrap();
+
+        assert_eq!(
+            bytes,
+            hex!(
+                "0000000000000000000000000000000000000000000000000000000000000040"
+                "0000000000000000000000000000000000000000000000000000000000000140"
+                "0000000000000000000000000000000000000000000000000000000000000002"
+                "0000000000000000000000000000000000000000000000000000000000000040"
+                "00000000000000000000000000000000000000000000000000000000000000a0"
+                "0000000000000000000000000000000000000000000000000000000000000002"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "0000000000000000000000000000000000000000000000000000000000000002"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "0000000000000000000000000000000000000000000000000000000000000003"
+                "0000000000000000000000000000000000000000000000000000000000000003"
+                "0000000000000000000000000000000000000000000000000000000000000060"
+                "00000000000000000000000000000000000000000000000000000000000000a0"
+                "00000000000000000000000000000000000000000000000000000000000000e0"
+                "0000000000000000000000000000000000000000000000000000000000000003"
+                "6f6e650000000000000000000000000000000000000000000000000000000000"
+                "0000000000000000000000000000000000000000000000000000000000000003"
+                "74776f0000000000000000000000000000000000000000000000000000000000"
+                "0000000000000000000000000000000000000000000000000000000000000005"
+                "7468726565000000000000000000000000000000000000000000000000000000"
+            )
+        );
+
+        assert_eq!(decode(&types, &bytes).unwrap(), values);
+    }
+
+    #[test]
+    fn tuples_and_fixed_arrays() {
+        let address = EvmAddress::from([0x11; 20]);
+
+        let types = types(&["(address,string)[]", "(bool,int8)[2]", "(uint8,(bytes,bool))"]);
+        let values = [
+            AbiValue::Array(vec![
+                AbiValue::Tuple(vec![address.into(), "first".into()]),
+                AbiValue::Tuple(vec![address.into(), "second".into()]),
+            ]),
+            AbiValue::FixedArray(vec![
+                AbiValue::Tuple(vec![true.into(), (-1_i8).into()]),
+                AbiValue::Tuple(vec![false.into(), 127_i8.into()]),
+            ]),
+            AbiValue::Tuple(vec![
+                7_u8.into(),
+                AbiValue::Tuple(vec![AbiValue::Bytes(vec![1, 2, 3]), true.into()]),
+            ]),
+        ];
+
+        let bytes = encode(&types, &values).unwrap();
+
+        // the static `(bool,int8)[2]` is inline, between the offsets of the two dynamic values.
+        assert_eq!(
+            bytes[32..64],
+            hex!("0000000000000000000000000000000000000000000000000000000000000001")
+        );
+        assert_eq!(bytes[64..96], [0xff; 32]);
+
+        assert_eq!(decode(&types, &bytes).unwrap(), values);
+    }
+
+    #[test]
+    fn int_ranges() {
+        assert!(encode(&types(&["int8"]), &[(-128_i16).into()]).is_ok());
+        assert!(encode(&types(&["int8"]), &[(-129_i16).into()]).is_err());
+        assert!(encode(&types(&["int8"]), &[128_u16.into()]).is_err());
+        assert!(encode(&types(&["uint8"]), &[255_i16.into()]).is_ok());
+        assert!(encode(&types(&["uint8"]), &[256_u16.into()]).is_err());
+        assert!(encode(&types(&["uint8"]), &[(-1_i8).into()]).is_err());
+
+        let bytes = encode(&types(&["int256"]), &[(-2_i8).into()]).unwrap();
+        assert_eq!(bytes[..31], [0xff; 31]);
+        assert_eq!(bytes[31], 0xfe);
+
+        assert_eq!(decode(&types(&["int256"]), &bytes).unwrap(), [AbiValue::Int(BigInt::from(-2))]);
+
+        // `-2` sign extends to 256 bits, which doesn't fit in a `uint8`.
+        assert!(decode(&types(&["uint8"]), &bytes).is_err());
+    }
+
+    #[test]
+    fn mismatched_values() {
+        assert!(encode(&types(&["address"]), &[true.into()]).is_err());
+        assert!(encode(&types(&["bytes4"]), &[AbiValue::from([0; 3])]).is_err());
+        assert!(encode(&types(&["uint8[2]"]), &[AbiValue::Array(vec![1_u8.into()])]).is_err());
+        assert!(encode(&types(&["uint8", "uint8"]), &[1_u8.into()]).is_err());
+    }
+
+    #[test]
+    fn decode_fixed_bytes_padding() {
+        let types = types(&["bytes4"]);
+
+        let bytes = hex!("0102030400000000000000000000000000000000000000000000000000000000");
+        assert_eq!(decode(&types, &bytes).unwrap(), [AbiValue::from([1, 2, 3, 4])]);
+
+        let bytes = hex!("0102030400000000000000000000000000000000000000000000000000000001");
+        assert!(decode(&types, &bytes).is_err());
+    }
+
+    #[test]
+    fn encode_oversized_fixed_array() {
+        let ty = AbiType::FixedArray(Box::new(AbiType::Uint(256)), usize::MAX);
+
+        assert!(encode(&[ty], &[AbiValue::FixedArray(vec![1_u8.into()])]).is_err());
+    }
+
+    #[test]
+    fn decode_truncated() {
+        let types = types(&["string"]);
+        let bytes = encode(&types, &["hello".into()]).unwrap();
+
+        assert!(decode(&types, &bytes[..bytes.len() - 32]).is_err());
+        assert!(decode(&types, &bytes[..16]).is_err());
+    }
+}
@@ -0,0 +1,405 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{
+    AbiConstructor,
+    AbiError,
+    AbiEvent,
+    AbiFunction,
+    AbiValue,
+    ContractEvent,
+    ContractRevertReason,
+};
+use crate::{
+    ContractLogInfo,
+    Error,
+};
+
+/// The ABI of a Solidity contract, as produced by `solc --abi`.
+///
+/// Encodes calls to the contract's functions and constructor, and decodes what they return
+/// and the events they emit.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ContractAbi {
+    /// The functions of the contract, in the order they were declared in the ABI.
+    pub functions: Vec<AbiFunction>,
+
+    /// The constructor of the contract, if it declares one.
+    pub constructor: Option<AbiConstructor>,
+
+    /// The events of the contract, in the order they were declared in the ABI.
+    pub events: Vec<AbiEvent>,
+
+    /// The custom errors of the contract, in the order they were declared in the ABI.
+    pub errors: Vec<AbiError>,
+}
+
+impl ContractAbi {
+    /// Load an ABI from its standard JSON representation.
+    ///
+    /// Accepts either the ABI array itself, or a compiler artifact with the array in an `abi` field.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `json` isn't a valid ABI.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        let abi = hedera_contract_abi::JsonAbi::from_json(json)?;
+
+        Ok(Self {
+            functions: abi
+                .functions
+                .into_iter()
+                .map(|it| AbiFunction {
+                    name: it.name,
+                    inputs: json::params(it.inputs),
+                    outputs: json::params(it.outputs),
+                    state_mutability: it.state_mutability,
+                })
+                .collect(),
+            constructor: abi.constructor.map(|it| AbiConstructor {
+                inputs: json::params(it.inputs),
+                state_mutability: it.state_mutability,
+            }),
+            events: abi
+                .events
+                .into_iter()
+                .map(|it| AbiEvent {
+                    name: it.name,
+                    inputs: json::event_params(it.inputs),
+                    anonymous: it.anonymous,
+                })
+                .collect(),
+            errors: abi
+                .errors
+                .into_iter()
+                .map(|it| AbiError { name: it.name, inputs: json::params(it.inputs) })
+                .collect(),
+        })
+    }
+
+    /// Returns the function with the given name, or signature if it's overloaded.
+    ///
+    /// `name` can be a plain name like `transfer`, or a full signature like `transfer(address,uint256)`.
+    /// Returns `None` if there's no such function, or if a plain name matches more than one overload.
+    #[must_use]
+    pub fn function(&self, name: &str) -> Option<&AbiFunction> {
+        if name.contains('(') {
+            return self.functions.iter().find(|it| it.signature() == name);
+        }
+
+        let mut functions = self.functions.iter().filter(|it| it.name == name);
+
+        match (functions.next(), functions.next()) {
+            (Some(function), None) => Some(function),
+            _ => None,
+        }
+    }
+
+    /// Returns the event with the given name, or signature if it's overloaded.
+    ///
+    /// Like [`function`](Self::function), returns `None` if a plain name matches more than one overload.
+    #[must_use]
+    pub fn event(&self, name: &str) -> Option<&AbiEvent> {
+        if name.contains('(') {
+            return self.events.iter().find(|it| it.signature() == name);
+        }
+
+        let mut events = self.events.iter().filter(|it| it.name == name);
+
+        match (events.next(), events.next()) {
+            (Some(event), None) => Some(event),
+            _ => None,
+        }
+    }
+
+    /// Decode `log` as whichever of the contract's events emitted it.
+    ///
+    /// Returns `None` if none of the events match the log.
+    /// Anonymous events are only matched if no other event does, since they can't be identified by their first topic.
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`] if the matching event fails to decode the log's data.
+    pub fn decode_log(&self, log: &ContractLogInfo) -> Option<crate::Result<ContractEvent>> {
+        let event = self
+            .events
+            .iter()
+            .find(|it| !it.anonymous && it.matches(log))
+            .or_else(|| self.events.iter().find(|it| it.anonymous && it.matches(log)))?;
+
+        Some(event.decode_log(log))
+    }
+
+    /// Decode revert data returned by one of the contract's functions, recognizing the contract's custom errors.
+    ///
+    /// See [`ContractFunctionResult::revert_reason`](crate::ContractFunctionResult::revert_reason).
+    #[must_use]
+    pub fn decode_revert(&self, data: &[u8]) -> ContractRevertReason {
+        ContractRevertReason::decode(data, &self.errors)
+    }
+
+    /// Encode a call to the function `name` with the given arguments, selector included.
+    ///
+    /// # Errors
+    /// - [`Error::AbiEncode`] if there's no function `name`, or `args` don't match its parameters.
+    pub fn encode_call(&self, name: &str, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        self.function(name)
+            .ok_or_else(|| Error::abi_encode(self.unknown_function(name)))?
+            .encode_input(args)
+    }
+
+    /// Decode the return values of the function `name`.
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`] if there's no function `name`, or `data` doesn't match its outputs.
+    pub fn decode_output(&self, name: &str, data: &[u8]) -> crate::Result<Vec<AbiValue>> {
+        self.function(name)
+            .ok_or_else(|| Error::abi_decode(self.unknown_function(name)))?
+            .decode_output(data)
+    }
+
+    /// Encode the arguments to the contract's constructor.
+    ///
+    /// A contract without a constructor takes no arguments.
+    ///
+    /// # Errors
+    /// - [`Error::AbiEncode`] if `args` don't match the parameters of the constructor.
+    pub fn encode_constructor(&self, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        match &self.constructor {
+            Some(constructor) => constructor.encode_input(args),
+            None => AbiConstructor::default().encode_input(args),
+        }
+    }
+
+    /// Decode the arguments to the contract's constructor.
+    ///
+    /// A contract without a constructor takes no arguments.
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`] if `data` doesn't match the parameters of the constructor.
+    pub fn decode_constructor(&self, data: &[u8]) -> crate::Result<Vec<AbiValue>> {
+        match &self.constructor {
+            Some(constructor) => constructor.decode_input(data),
+            None => AbiConstructor::default().decode_input(data),
+        }
+    }
+
+    fn unknown_function(&self, name: &str) -> String {
+        match self.functions.iter().filter(|it| it.name == name).count() {
+            0 => format!("no function named `{name}`"),
+            _ => format!("function `{name}` is overloaded, use its full signature"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod json {
+    use hedera_contract_abi::JsonParam;
+
+    use super::super::{
+        AbiEventParam,
+        AbiParam,
+    };
+
+    pub(super) fn params(params: Vec<JsonParam>) -> Vec<AbiParam> {
+        params
+            .into_iter()
+            .map(|it| AbiParam {
+                name: it.name,
+                kind: it.kind,
+                components: self::params(it.components),
+            })
+            .collect()
+    }
+
+    pub(super) fn event_params(params: Vec<JsonParam>) -> Vec<AbiEventParam> {
+        params
+            .into_iter()
+            .map(|it| AbiEventParam {
+                name: it.name,
+                kind: it.kind,
+                components: self::params(it.components),
+                indexed: it.indexed,
+            })
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::ContractAbi;
+    use crate::{
+        AbiStateMutability,
+        AbiType,
+        AbiValue,
+        ContractId,
+        ContractLogInfo,
+        ContractRevertReason,
+        EvmAddress,
+    };
+
+    const ABI: &str = r#"[
+        {
+            "type": "constructor",
+            "inputs": [{ "name": "owner", "type": "address", "internalType": "address" }],
+            "stateMutability": "nonpayable"
+        },
+        {
+            "type": "function",
+            "name": "getPosition",
+            "inputs": [{ "name": "id", "type": "uint64", "internalType": "uint64" }],
+            "outputs": [
+                {
+                    "name": "",
+                    "type": "tuple",
+                    "internalType": "struct Pool.Position",
+                    "components": [
+                        { "name": "owner", "type": "address", "internalType": "address" },
+                        { "name": "amounts", "type": "uint128[2]", "internalType": "uint128[2]" },
+                        { "name": "label", "type": "string", "internalType": "string" }
+                    ]
+                }
+            ],
+            "stateMutability": "view"
+        },
+        { "type": "function", "name": "poke", "inputs": [], "outputs": [], "stateMutability": "payable" },
+        { "type": "function", "name": "poke", "inputs": [{ "name": "n", "type": "int24" }], "outputs": [] },
+        {
+            "type": "event",
+            "name": "Moved",
+            "inputs": [
+                { "name": "id", "type": "uint64", "indexed": true },
+                { "name": "label", "type": "string", "indexed": false }
+            ],
+            "anonymous": false
+        },
+        { "type": "event", "name": "Poked", "inputs": [], "anonymous": false },
+        {
+            "type": "error",
+            "name": "PositionNotFound",
+            "inputs": [{ "name": "id", "type": "uint64", "internalType": "uint64" }]
+        },
+        { "type": "receive", "stateMutability": "payable" }
+    ]"#;
+
+    #[test]
+    fn from_json() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        assert_eq!(abi.functions.len(), 3);
+        assert_eq!(abi.constructor.as_ref().unwrap().inputs[0].kind, AbiType::Address);
+
+        let function = abi.function("getPosition").unwrap();
+
+        assert_eq!(function.signature(), "getPosition(uint64)");
+        assert_eq!(function.state_mutability, AbiStateMutability::View);
+        assert_eq!(function.outputs[0].kind.to_string(), "(address,uint128[2],string)");
+        assert_eq!(function.outputs[0].components[2].name, "label");
+    }
+
+    #[test]
+    fn from_json_artifact() {
+        let abi = ContractAbi::from_json(&format!(r#"{{ "contractName": "Pool", "abi": {ABI} }}"#))
+            .unwrap();
+
+        assert_eq!(abi.functions.len(), 3);
+    }
+
+    #[test]
+    fn overloads() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        assert!(abi.function("poke").is_none());
+        assert!(abi.function("poke()").is_some());
+        assert!(abi.function("poke(int24)").is_some());
+        assert!(abi.encode_call("poke", &[]).is_err());
+        assert!(abi.encode_call("poke(int24)", &[(-5_i8).into()]).is_ok());
+    }
+
+    #[test]
+    fn decode_struct_output() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        let position = AbiValue::Tuple(vec![
+            EvmAddress::from([0x22; 20]).into(),
+            AbiValue::FixedArray(vec![10_u128.into(), 20_u128.into()]),
+            "main".into(),
+        ]);
+
+        let bytes =
+            abi.function("getPosition").unwrap().encode_output(&[position.clone()]).unwrap();
+
+        assert_eq!(abi.decode_output("getPosition", &bytes).unwrap(), [position]);
+    }
+
+    #[test]
+    fn encode_constructor() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        let bytes = abi.encode_constructor(&[EvmAddress::from([0x22; 20]).into()]).unwrap();
+
+        assert_eq!(bytes.len(), 32);
+        assert!(ContractAbi::default().encode_constructor(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn events() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        assert_eq!(abi.events.len(), 2);
+
+        let event = abi.event("Moved").unwrap();
+
+        assert_eq!(event.signature(), "Moved(uint64,string)");
+        assert!(event.inputs[0].indexed && !event.inputs[1].indexed);
+        assert!(abi.event("Moved(uint64,string)").is_some());
+    }
+
+    #[test]
+    fn decode_log() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+        let moved = abi.event("Moved").unwrap();
+
+        let mut id = [0; 32];
+        id[31] = 9;
+
+        let log = ContractLogInfo {
+            contract_id: ContractId::new(0, 0, 1001),
+            bloom: Vec::new(),
+            topics: vec![moved.topic().to_vec(), id.to_vec()],
+            data: crate::contract::abi::codec::encode(&[AbiType::String], &["north".into()])
+                .unwrap(),
+        };
+
+        let event = abi.decode_log(&log).unwrap().unwrap();
+
+        assert_eq!(event.name, "Moved");
+        assert_eq!(event.values, [AbiValue::from(9_u64), AbiValue::from("north")]);
+
+        let poked = ContractLogInfo {
+            topics: vec![abi.event("Poked").unwrap().topic().to_vec()],
+            data: Vec::new(),
+            ..log.clone()
+        };
+
+        assert_eq!(abi.decode_log(&poked).unwrap().unwrap().name, "Poked");
+        assert!(abi.decode_log(&ContractLogInfo { topics: Vec::new(), ..log }).is_none());
+    }
+
+    #[test]
+    fn decode_revert() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        assert_eq!(abi.errors[0].signature(), "PositionNotFound(uint64)");
+
+        let mut data = abi.errors[0].selector().to_vec();
+        data.extend([0; 31]);
+        data.push(4);
+
+        assert_eq!(
+            abi.decode_revert(&data),
+            ContractRevertReason::Custom {
+                name: "PositionNotFound".to_owned(),
+                values: vec![AbiValue::from(4_u64)],
+            }
+        );
+    }
+}
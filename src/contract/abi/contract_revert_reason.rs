@@ -0,0 +1,284 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+use num_bigint::BigUint;
+
+use super::{
+    codec,
+    AbiError,
+    AbiType,
+    AbiValue,
+};
+
+// `keccak256("Error(string)")[..4]`.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+// `keccak256("Panic(uint256)")[..4]`.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Why a contract call reverted, decoded from its revert data.
+///
+/// See [`ContractFunctionResult::revert_reason`](crate::ContractFunctionResult::revert_reason).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContractRevertReason {
+    /// The contract reverted without any data, as with `revert()` or `require(condition)`.
+    Empty,
+
+    /// The contract reverted with a message, as with `revert("message")` or `require(condition, "message")`.
+    Error(String),
+
+    /// The contract panicked, as with a failing `assert` or an arithmetic overflow.
+    Panic(PanicCode),
+
+    /// The contract reverted with one of the custom errors it was decoded with.
+    Custom {
+        /// The name of the error.
+        name: String,
+
+        /// The values of the error's parameters.
+        values: Vec<AbiValue>,
+    },
+
+    /// The revert data isn't a standard error, or a custom error that was decoded with.
+    Unknown(Vec<u8>),
+}
+
+impl ContractRevertReason {
+    /// Decode revert data, recognizing `Error(string)`, `Panic(uint256)` and the given `custom_errors`.
+    #[must_use]
+    pub fn decode(data: &[u8], custom_errors: &[AbiError]) -> Self {
+        if data.is_empty() {
+            return Self::Empty;
+        }
+
+        let Some((selector, args)) = data.get(..4).zip(data.get(4..)) else {
+            return Self::Unknown(data.to_vec());
+        };
+
+        let decoded = if selector == ERROR_SELECTOR {
+            codec::decode(&[AbiType::String], args)
+                .ok()
+                .and_then(|it| it.into_iter().next()?.as_str().map(|it| Self::Error(it.to_owned())))
+        } else if selector == PANIC_SELECTOR {
+            codec::decode(&[AbiType::Uint(256)], args)
+                .ok()
+                .and_then(|it| it.into_iter().next()?.as_uint())
+                .map(|it| Self::Panic(PanicCode::from(it)))
+        } else {
+            custom_errors.iter().filter(|it| it.selector() == selector).find_map(|error| {
+                let values = error.decode(data).ok()?;
+
+                Some(Self::Custom { name: error.name.clone(), values })
+            })
+        };
+
+        decoded.unwrap_or_else(|| Self::Unknown(data.to_vec()))
+    }
+
+    /// Retry decoding [`Unknown`](Self::Unknown) revert data with the given `custom_errors`.
+    ///
+    /// Useful for a reason that was decoded without knowing the contract's errors,
+    /// like the one in [`Error::ContractRevert`](crate::Error::ContractRevert).
+    #[must_use]
+    pub fn with_custom_errors(self, custom_errors: &[AbiError]) -> Self {
+        match self {
+            Self::Unknown(data) => Self::decode(&data, custom_errors),
+            reason => reason,
+        }
+    }
+}
+
+impl fmt::Display for ContractRevertReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("reverted without a reason"),
+            Self::Error(message) => write!(f, "reverted with `{message}`"),
+            Self::Panic(code) => write!(f, "panicked with code {:#04x}: {code}", code.code()),
+            Self::Custom { name, values } => write!(f, "reverted with `{name}` {values:?}"),
+            Self::Unknown(data) => {
+                write!(f, "reverted with unrecognized data `0x{}`", hex::encode(data))
+            }
+        }
+    }
+}
+
+/// The code of a Solidity `Panic(uint256)`, which says what kind of internal error occurred.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PanicCode {
+    /// `0x00`, a generic panic inserted by the compiler.
+    Generic,
+
+    /// `0x01`, an `assert` failed.
+    Assert,
+
+    /// `0x11`, an arithmetic operation overflowed or underflowed outside of an `unchecked` block.
+    ArithmeticOverflow,
+
+    /// `0x12`, division or modulo by zero.
+    DivisionByZero,
+
+    /// `0x21`, a value that's too big or negative was converted to an enum.
+    InvalidEnumValue,
+
+    /// `0x22`, a storage byte array is incorrectly encoded.
+    InvalidStorageByteArray,
+
+    /// `0x31`, `.pop()` was called on an empty array.
+    PopEmptyArray,
+
+    /// `0x32`, an array or slice was indexed out of bounds.
+    IndexOutOfBounds,
+
+    /// `0x41`, too much memory was allocated, or an array was too large.
+    OutOfMemory,
+
+    /// `0x51`, a zero-initialized variable of internal function type was called.
+    UninitializedFunction,
+
+    /// A code that isn't defined by Solidity.
+    Unknown(BigUint),
+}
+
+impl PanicCode {
+    /// Returns the numeric code of the panic.
+    #[must_use]
+    pub fn code(&self) -> BigUint {
+        let code: u8 = match self {
+            Self::Generic => 0x00,
+            Self::Assert => 0x01,
+            Self::ArithmeticOverflow => 0x11,
+            Self::DivisionByZero => 0x12,
+            Self::InvalidEnumValue => 0x21,
+            Self::InvalidStorageByteArray => 0x22,
+            Self::PopEmptyArray => 0x31,
+            Self::IndexOutOfBounds => 0x32,
+            Self::OutOfMemory => 0x41,
+            Self::UninitializedFunction => 0x51,
+            Self::Unknown(code) => return code.clone(),
+        };
+
+        code.into()
+    }
+}
+
+impl From<BigUint> for PanicCode {
+    fn from(code: BigUint) -> Self {
+        match u8::try_from(&code) {
+            Ok(0x00) => Self::Generic,
+            Ok(0x01) => Self::Assert,
+            Ok(0x11) => Self::ArithmeticOverflow,
+            Ok(0x12) => Self::DivisionByZero,
+            Ok(0x21) => Self::InvalidEnumValue,
+            Ok(0x22) => Self::InvalidStorageByteArray,
+            Ok(0x31) => Self::PopEmptyArray,
+            Ok(0x32) => Self::IndexOutOfBounds,
+            Ok(0x41) => Self::OutOfMemory,
+            Ok(0x51) => Self::UninitializedFunction,
+            _ => Self::Unknown(code),
+        }
+    }
+}
+
+impl fmt::Display for PanicCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Generic => "generic compiler panic",
+            Self::Assert => "assertion failed",
+            Self::ArithmeticOverflow => "arithmetic overflow or underflow",
+            Self::DivisionByZero => "division or modulo by zero",
+            Self::InvalidEnumValue => "invalid enum value",
+            Self::InvalidStorageByteArray => "incorrectly encoded storage byte array",
+            Self::PopEmptyArray => "pop on an empty array",
+            Self::IndexOutOfBounds => "array index out of bounds",
+            Self::OutOfMemory => "out of memory",
+            Self::UninitializedFunction => "call to an uninitialized internal function",
+            Self::Unknown(_) => "unknown panic",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+    use num_bigint::BigUint;
+
+    use super::{
+        ContractRevertReason,
+        PanicCode,
+    };
+    use crate::{
+        AbiError,
+        AbiValue,
+    };
+
+    #[test]
+    fn decode_error_string() {
+        // `revert("Not enough Ether provided.")`, from the solidity docs.
+        let data = hex!(
+            "08c379a0"
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "000000000000000000000000000000000000000000000000000000000000001a"
+            "4e6f7420656e6f7567682045746865722070726f76696465642e000000000000"
+        );
+
+        assert_eq!(
+            ContractRevertReason::decode(&data, &[]),
+            ContractRevertReason::Error("Not enough Ether provided.".to_owned())
+        );
+    }
+
+    #[test]
+    fn decode_panic() {
+        let data = hex!(
+            "4e487b71"
+            "0000000000000000000000000000000000000000000000000000000000000011"
+        );
+
+        let reason = ContractRevertReason::decode(&data, &[]);
+
+        assert_eq!(reason, ContractRevertReason::Panic(PanicCode::ArithmeticOverflow));
+        assert_eq!(reason.to_string(), "panicked with code 0x11: arithmetic overflow or underflow");
+        assert_eq!(
+            PanicCode::from(BigUint::from(0x99_u8)),
+            PanicCode::Unknown(BigUint::from(0x99_u8))
+        );
+    }
+
+    #[test]
+    fn decode_custom() {
+        let error = AbiError::parse("InsufficientBalance(uint256 available)").unwrap();
+
+        let mut data = error.selector().to_vec();
+        data.extend(hex!("0000000000000000000000000000000000000000000000000000000000000007"));
+
+        let unknown = ContractRevertReason::decode(&data, &[]);
+
+        assert_eq!(unknown, ContractRevertReason::Unknown(data.clone()));
+        assert_eq!(
+            unknown.with_custom_errors(&[error]),
+            ContractRevertReason::Custom {
+                name: "InsufficientBalance".to_owned(),
+                values: vec![AbiValue::from(7_u8)],
+            }
+        );
+    }
+
+    #[test]
+    fn decode_malformed() {
+        assert_eq!(ContractRevertReason::decode(&[], &[]), ContractRevertReason::Empty);
+
+        // an `Error(string)` selector, without a string.
+        let data = hex!("08c379a0");
+
+        assert_eq!(
+            ContractRevertReason::decode(&data, &[]),
+            ContractRevertReason::Unknown(data.to_vec())
+        );
+
+        assert_eq!(
+            ContractRevertReason::decode(&[0xab], &[]),
+            ContractRevertReason::Unknown(vec![0xab])
+        );
+    }
+}
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: Apache-2.0
+
+mod abi_error;
+mod abi_event;
+mod abi_function;
+mod abi_value;
+pub(crate) mod bindings;
+pub(crate) mod codec;
+mod contract_abi;
+mod contract_revert_reason;
+pub(crate) mod signature;
+
+pub use abi_error::AbiError;
+pub use abi_event::{
+    AbiEvent,
+    AbiEventParam,
+    ContractEvent,
+};
+pub use abi_function::{
+    AbiConstructor,
+    AbiFunction,
+    AbiParam,
+};
+pub use abi_value::AbiValue;
+pub use contract_abi::ContractAbi;
+pub use contract_revert_reason::{
+    ContractRevertReason,
+    PanicCode,
+};
+pub use hedera_contract_abi::{
+    AbiStateMutability,
+    AbiType,
+};
@@ -0,0 +1,223 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use hedera_contract_abi::parse_type;
+
+use super::AbiType;
+use crate::Error;
+
+/// A parameter of a human-readable signature like `Transfer(address indexed from, address to, uint256)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SignatureParam {
+    pub(crate) kind: AbiType,
+    pub(crate) indexed: bool,
+    pub(crate) name: String,
+}
+
+/// Parse a human-readable signature into its name and parameters.
+///
+/// Each parameter is a type, optionally followed by `indexed`, and then optionally by a name.
+/// Types don't need to be canonical, `uint` is the same as `uint256`.
+pub(crate) fn parse_signature(signature: &str) -> crate::Result<(String, Vec<SignatureParam>)> {
+    let signature = signature.trim();
+
+    let (name, params) = signature
+        .split_once('(')
+        .and_then(|(name, rest)| Some((name.trim(), rest.strip_suffix(')')?)))
+        .ok_or_else(|| Error::basic_parse(format!("expected `name(...)`, found `{signature}`")))?;
+
+    if !is_identifier(name) {
+        return Err(Error::basic_parse(format!("`{name}` is not a valid function or event name")));
+    }
+
+    let params = if params.trim().is_empty() {
+        Vec::new()
+    } else {
+        split_top_level(params)?.into_iter().map(parse_param).collect::<crate::Result<_>>()?
+    };
+
+    Ok((name.to_owned(), params))
+}
+
+fn parse_param(param: &str) -> crate::Result<SignatureParam> {
+    let param = param.trim();
+
+    let (kind, rest) = parse_param_type(param)?;
+
+    let mut words = rest.split_whitespace().peekable();
+
+    let indexed = words.next_if_eq(&"indexed").is_some();
+
+    // data locations are allowed in human-readable signatures, but don't mean anything to the ABI.
+    words.next_if(|it| matches!(*it, "memory" | "calldata" | "storage"));
+
+    let name = words.next().unwrap_or_default();
+
+    if let Some(word) = words.next() {
+        return Err(Error::basic_parse(format!("unexpected `{word}` in parameter `{param}`")));
+    }
+
+    if !name.is_empty() && !is_identifier(name) {
+        return Err(Error::basic_parse(format!("`{name}` is not a valid parameter name")));
+    }
+
+    Ok(SignatureParam { kind, indexed, name: name.to_owned() })
+}
+
+// parses the type at the start of `param`, where tuple components may have names and whitespace,
+// like `(address token, uint24 fee)[]`.
+fn parse_param_type(param: &str) -> crate::Result<(AbiType, &str)> {
+    let Some(inner) = param.strip_prefix('(') else {
+        // elementary types don't contain whitespace, so everything after the first space is modifiers and names.
+        let end = param.find(char::is_whitespace).unwrap_or(param.len());
+        let (kind, rest) = param.split_at(end);
+
+        return match parse_type(kind)? {
+            (kind, "") => Ok((kind, rest)),
+            (_, trailing) => {
+                Err(Error::basic_parse(format!("unexpected `{trailing}` in parameter `{param}`")))
+            }
+        };
+    };
+
+    let close = find_closing_paren(inner)
+        .ok_or_else(|| Error::basic_parse(format!("unbalanced `(` in parameter `{param}`")))?;
+
+    let components = match inner[..close].trim() {
+        "" => Vec::new(),
+        components => split_top_level(components)?
+            .into_iter()
+            .map(|it| match parse_param(it)? {
+                SignatureParam { indexed: true, .. } => Err(Error::basic_parse(format!(
+                    "tuple components can't be `indexed` in `{param}`"
+                ))),
+                component => Ok(component.kind),
+            })
+            .collect::<crate::Result<_>>()?,
+    };
+
+    // array suffixes like `[]` or `[2]` follow the tuple directly.
+    let rest = &inner[close + 1..];
+    let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+    let (suffix, rest) = rest.split_at(end);
+
+    let kind = format!("{}{suffix}", AbiType::Tuple(components)).parse()?;
+
+    Ok((kind, rest))
+}
+
+// `s` starts just after an opening `(`, returns the index of the `)` that closes it.
+fn find_closing_paren(s: &str) -> Option<usize> {
+    let mut depth = 0_usize;
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(i),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+// splits at commas that aren't nested in a tuple.
+fn split_top_level(params: &str) -> crate::Result<Vec<&str>> {
+    let mut parts = Vec::new();
+    let mut depth = 0_usize;
+    let mut start = 0;
+
+    for (i, c) in params.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth.checked_sub(1).ok_or_else(|| {
+                    Error::basic_parse(format!("unbalanced `)` in parameters `{params}`"))
+                })?;
+            }
+            ',' if depth == 0 => {
+                parts.push(&params[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if depth != 0 {
+        return Err(Error::basic_parse(format!("unbalanced `(` in parameters `{params}`")));
+    }
+
+    parts.push(&params[start..]);
+
+    Ok(parts)
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+
+    chars.next().is_some_and(|it| it.is_ascii_alphabetic() || it == '_' || it == '$')
+        && chars.all(|it| it.is_ascii_alphanumeric() || it == '_' || it == '$')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_signature;
+    use crate::AbiType;
+
+    #[test]
+    fn parse_event() {
+        let (name, params) =
+            parse_signature("Transfer(address indexed from, address indexed, uint value)").unwrap();
+
+        assert_eq!(name, "Transfer");
+        assert_eq!(params.len(), 3);
+        assert!(params[0].indexed && params[1].indexed && !params[2].indexed);
+        assert_eq!(params[0].name, "from");
+        assert_eq!(params[1].name, "");
+        assert_eq!(params[2].kind, AbiType::Uint(256));
+    }
+
+    #[test]
+    fn parse_nested_tuples() {
+        let (name, params) =
+            parse_signature("swap((address,uint24)[],(bool,(string)) memory)").unwrap();
+
+        assert_eq!(name, "swap");
+        assert_eq!(params[0].kind.to_string(), "(address,uint24)[]");
+        assert_eq!(params[1].kind.to_string(), "(bool,(string))");
+    }
+
+    #[test]
+    fn parse_named_tuple_components() {
+        let (_, params) =
+            parse_signature("swap((address token, uint24 fee)[] calldata routes, uint amount)")
+                .unwrap();
+
+        assert_eq!(params[0].kind.to_string(), "(address,uint24)[]");
+        assert_eq!(params[0].name, "routes");
+        assert_eq!(params[1].kind, AbiType::Uint(256));
+    }
+
+    #[test]
+    fn parse_empty() {
+        assert_eq!(parse_signature("poke()").unwrap(), ("poke".to_owned(), Vec::new()));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for signature in [
+            "poke",
+            "(uint8)",
+            "1poke()",
+            "poke(uint8",
+            "poke(uint8,)",
+            "poke((uint8)",
+            "poke(uint8 a b)",
+            "poke(uint7)",
+            "poke((uint8 indexed))",
+            "poke((uint8)x)",
+        ] {
+            assert!(parse_signature(signature).is_err(), "{signature}");
+        }
+    }
+}
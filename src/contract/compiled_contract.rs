@@ -0,0 +1,465 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::iter;
+use std::ops::Range;
+
+use crate::{
+    AbiValue,
+    Client,
+    ContractAbi,
+    ContractBytecodeQuery,
+    ContractId,
+    Error,
+};
+
+/// A contract as compiled by `solc`, to verify deployed contracts against.
+///
+/// Bytecode is compared with the metadata hash `solc` appends to it removed,
+/// since it changes with the compiler settings and source file paths without changing the code.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CompiledContract {
+    /// The ABI of the contract.
+    pub abi: ContractAbi,
+
+    /// The creation bytecode, which runs once to deploy the contract.
+    pub bytecode: Vec<u8>,
+
+    /// The runtime bytecode, which the creation bytecode deploys.
+    pub deployed_bytecode: Vec<u8>,
+
+    /// Where the values of `immutable` variables are in [`deployed_bytecode`](Self::deployed_bytecode).
+    ///
+    /// They're zero when compiled, and set by the constructor, so they're ignored when comparing bytecode.
+    pub immutable_references: Vec<Range<usize>>,
+}
+
+impl CompiledContract {
+    /// Load the contract `name` from `solc`'s output.
+    ///
+    /// Accepts the output of `solc --standard-json`, which needs `abi`, `evm.bytecode.object`
+    /// and `evm.deployedBytecode` in its output selection, or of `solc --combined-json abi,bin,bin-runtime`.
+    ///
+    /// `name` can be a plain contract name like `Pool`, or qualified with its source file like `contracts/Pool.sol:Pool`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `json` isn't `solc` output, it has no contract `name` (or more than one),
+    ///   or the contract is abstract or has unlinked libraries.
+    #[cfg(feature = "serde")]
+    pub fn from_solc_json(json: &str, name: &str) -> crate::Result<Self> {
+        let output: serde_json::Value = serde_json::from_str(json).map_err(Error::basic_parse)?;
+
+        let contracts = output["contracts"]
+            .as_object()
+            .ok_or_else(|| Error::basic_parse("expected solc output with `contracts`"))?;
+
+        // standard JSON nests contracts by source file, combined JSON qualifies their names with it.
+        let mut matches = contracts
+            .iter()
+            .flat_map(|(key, value)| match value.as_object() {
+                Some(_) if key.contains(':') => vec![(key.clone(), value)],
+                Some(source) => {
+                    source.iter().map(|(name, value)| (format!("{key}:{name}"), value)).collect()
+                }
+                None => Vec::new(),
+            })
+            .filter(|(qualified, _)| {
+                qualified == name || qualified.rsplit_once(':').is_some_and(|(_, it)| it == name)
+            });
+
+        let (qualified, contract) = match (matches.next(), matches.next()) {
+            (Some(contract), None) => contract,
+            (None, _) => return Err(Error::basic_parse(format!("no contract named `{name}`"))),
+            (Some(_), Some(_)) => {
+                return Err(Error::basic_parse(format!(
+                    "more than one contract named `{name}`, qualify it with its source file"
+                )));
+            }
+        };
+
+        let abi = match &contract["abi"] {
+            // combined JSON from before solidity 0.8.10 has the ABI as a string.
+            serde_json::Value::String(abi) => ContractAbi::from_json(abi)?,
+            abi => ContractAbi::from_json(&abi.to_string())?,
+        };
+
+        let (bytecode, deployed_bytecode) = match contract.get("evm") {
+            Some(evm) => (&evm["bytecode"]["object"], &evm["deployedBytecode"]["object"]),
+            None => (&contract["bin"], &contract["bin-runtime"]),
+        };
+
+        let bytecode = json::bytecode(&qualified, bytecode)?;
+        let deployed_bytecode = json::bytecode(&qualified, deployed_bytecode)?;
+
+        let immutable_references = json::immutable_references(
+            &contract["evm"]["deployedBytecode"]["immutableReferences"],
+        )?;
+
+        Ok(Self { abi, bytecode, deployed_bytecode, immutable_references })
+    }
+
+    /// Verify `runtime_bytecode`, as returned by a [`ContractBytecodeQuery`], against this contract.
+    ///
+    /// If `creation_input` is given, the constructor arguments are decoded from it, and the creation bytecode is verified too.
+    /// It's the data the contract was created with: the creation bytecode followed by the constructor arguments,
+    /// like the contents of the bytecode file followed by the [`constructor_parameters`](crate::ContractCreateTransaction::constructor_parameters)
+    /// of the `ContractCreateTransaction`, or the call data of the ethereum transaction that created it.
+    /// If the constructor arguments can't be found in it, [`constructor_arguments`](ContractVerification::constructor_arguments) is `None`.
+    #[must_use]
+    pub fn verify(
+        &self,
+        runtime_bytecode: &[u8],
+        creation_input: Option<&[u8]>,
+    ) -> ContractVerification {
+        let mut runtime_bytecode = runtime_bytecode.to_vec();
+
+        for range in &self.immutable_references {
+            if let Some(value) = runtime_bytecode.get_mut(range.clone()) {
+                value.fill(0);
+            }
+        }
+
+        let (code, metadata) = split_metadata(&runtime_bytecode);
+        let (expected_code, expected_metadata) = split_metadata(&self.deployed_bytecode);
+
+        let mut verification = ContractVerification {
+            matches: code == expected_code,
+            metadata_matches: metadata == expected_metadata,
+            constructor_arguments: None,
+        };
+
+        let Some(creation_input) = creation_input else {
+            return verification;
+        };
+
+        let (expected_code, expected_metadata) = split_metadata(&self.bytecode);
+
+        let Some(input) = creation_input.strip_prefix(expected_code) else {
+            verification.matches = false;
+            return verification;
+        };
+
+        verification.metadata_matches &= input.starts_with(expected_metadata);
+
+        // the input's metadata can be a different length to ours (a different compiler version, for instance),
+        // so, the arguments follow whichever metadata they can be decoded after.
+        verification.constructor_arguments = metadata_lengths(input, expected_metadata.len())
+            .find_map(|len| self.abi.decode_constructor(&input[len..]).ok());
+
+        verification
+    }
+
+    /// Verify the contract deployed at `contract_id` against this contract, see [`verify`](Self::verify).
+    ///
+    /// # Errors
+    /// - Any error from querying the contract's bytecode.
+    pub async fn verify_deployed(
+        &self,
+        client: &Client,
+        contract_id: ContractId,
+        creation_input: Option<&[u8]>,
+    ) -> crate::Result<ContractVerification> {
+        let runtime_bytecode =
+            ContractBytecodeQuery::new().contract_id(contract_id).execute(client).await?;
+
+        Ok(self.verify(&runtime_bytecode, creation_input))
+    }
+}
+
+/// The result of verifying a deployed contract against a [`CompiledContract`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractVerification {
+    /// Whether the deployed bytecode matches the compiled bytecode, ignoring their metadata hashes.
+    pub matches: bool,
+
+    /// Whether the metadata hashes match too, which means the contract was compiled from
+    /// exactly the same source files with the same settings.
+    pub metadata_matches: bool,
+
+    /// The arguments the contract was constructed with, if the creation input was given, matches, and they could be decoded from it.
+    pub constructor_arguments: Option<Vec<AbiValue>>,
+}
+
+/// Split `bytecode` into its code and the CBOR encoded metadata `solc` appends to it,
+/// which is followed by its length as two big endian bytes.
+fn split_metadata(bytecode: &[u8]) -> (&[u8], &[u8]) {
+    let Some(length_at) = bytecode.len().checked_sub(2) else {
+        return (bytecode, &[]);
+    };
+
+    let length = usize::from(u16::from_be_bytes([bytecode[length_at], bytecode[length_at + 1]]));
+
+    match length_at.checked_sub(length) {
+        // the metadata is a CBOR map.
+        Some(start) if (0xa0..=0xb7).contains(&bytecode[start]) => bytecode.split_at(start),
+        _ => (bytecode, &[]),
+    }
+}
+
+/// Returns the possible lengths of the metadata (including its length) at the start of `input`, `expected` first.
+///
+/// If `expected` is zero the contract was compiled without metadata, so the input has none either.
+fn metadata_lengths(input: &[u8], expected: usize) -> impl Iterator<Item = usize> + '_ {
+    // the metadata is a CBOR map, followed by its length as two big endian bytes.
+    let is_metadata = move |len: usize| {
+        let Some(length_at) = len.checked_sub(2) else { return false };
+
+        match input.get(length_at..len) {
+            Some(&[high, low]) => {
+                matches!(input.first(), Some(0xa0..=0xb7))
+                    && usize::from(u16::from_be_bytes([high, low])) == length_at
+            }
+            _ => false,
+        }
+    };
+
+    let max_len = match expected {
+        0 => 0,
+        _ => input.len().min(usize::from(u16::MAX) + 2),
+    };
+
+    iter::once(expected)
+        .chain((2..=max_len).filter(move |it| *it != expected))
+        .filter(move |it| *it == 0 || is_metadata(*it))
+}
+
+#[cfg(feature = "serde")]
+mod json {
+    use std::ops::Range;
+
+    use crate::Error;
+
+    pub(super) fn bytecode(name: &str, object: &serde_json::Value) -> crate::Result<Vec<u8>> {
+        let object = object.as_str().ok_or_else(|| {
+            Error::basic_parse(format!("`{name}` has no bytecode, is it in the output selection?"))
+        })?;
+
+        let object = object.strip_prefix("0x").unwrap_or(object);
+
+        if object.is_empty() {
+            return Err(Error::basic_parse(format!(
+                "`{name}` has no bytecode, is it abstract or an interface?"
+            )));
+        }
+
+        if object.contains("__") {
+            return Err(Error::basic_parse(format!("`{name}` has unlinked libraries")));
+        }
+
+        hex::decode(object).map_err(Error::basic_parse)
+    }
+
+    #[derive(serde_derive::Deserialize)]
+    struct ImmutableReference {
+        start: usize,
+        length: usize,
+    }
+
+    pub(super) fn immutable_references(
+        references: &serde_json::Value,
+    ) -> crate::Result<Vec<Range<usize>>> {
+        let Some(references) = references.as_object() else {
+            return Ok(Vec::new());
+        };
+
+        let mut ranges = Vec::new();
+
+        for references in references.values() {
+            let references: Vec<ImmutableReference> =
+                serde_json::from_value(references.clone()).map_err(Error::basic_parse)?;
+
+            ranges.extend(references.into_iter().map(|it| it.start..it.start + it.length));
+        }
+
+        Ok(ranges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        split_metadata,
+        CompiledContract,
+    };
+    use crate::{
+        AbiConstructor,
+        AbiParam,
+        AbiType,
+        ContractAbi,
+        EvmAddress,
+    };
+
+    // `{"abc": 1}`, followed by its length.
+    const METADATA: [u8; 8] = [0xa1, 0x63, b'a', b'b', b'c', 0x01, 0x00, 0x06];
+    const OTHER_METADATA: [u8; 8] = [0xa1, 0x63, b'a', b'b', b'c', 0x02, 0x00, 0x06];
+
+    // pushes an `immutable` at 7..39.
+    fn runtime(metadata: &[u8], immutable: u8) -> Vec<u8> {
+        let mut code = hex::decode("60016000525f7f").unwrap();
+        code.extend([immutable; 32]);
+        code.extend(metadata);
+        code
+    }
+
+    fn creation(metadata: &[u8]) -> Vec<u8> {
+        let mut code = hex::decode("6080604052348015600e575f80fd5b50").unwrap();
+        code.extend(runtime(metadata, 0));
+        code
+    }
+
+    fn compiled() -> CompiledContract {
+        CompiledContract {
+            abi: ContractAbi {
+                constructor: Some(AbiConstructor {
+                    inputs: vec![AbiParam::new("owner", AbiType::Address)],
+                    ..AbiConstructor::default()
+                }),
+                ..ContractAbi::default()
+            },
+            bytecode: creation(&METADATA),
+            deployed_bytecode: runtime(&METADATA, 0),
+            immutable_references: vec![7..39],
+        }
+    }
+
+    #[test]
+    fn split() {
+        let bytecode = runtime(&METADATA, 0);
+
+        assert_eq!(split_metadata(&bytecode), (&bytecode[..39], &METADATA[..]));
+        assert_eq!(split_metadata(&bytecode[..39]), (&bytecode[..39], &[][..]));
+        assert_eq!(split_metadata(&[0x00]), (&[0x00][..], &[][..]));
+    }
+
+    #[test]
+    fn verify_runtime() {
+        let compiled = compiled();
+
+        let verification = compiled.verify(&runtime(&METADATA, 0x11), None);
+
+        assert!(verification.matches);
+        assert!(verification.metadata_matches);
+        assert_eq!(verification.constructor_arguments, None);
+
+        let verification = compiled.verify(&runtime(&OTHER_METADATA, 0x11), None);
+
+        assert!(verification.matches);
+        assert!(!verification.metadata_matches);
+
+        let mut other = runtime(&METADATA, 0x11);
+        other[1] = 0x02;
+
+        assert!(!compiled.verify(&other, None).matches);
+    }
+
+    #[test]
+    fn verify_creation() {
+        let compiled = compiled();
+        let owner = EvmAddress::from([0x22; 20]);
+
+        let mut input = creation(&OTHER_METADATA);
+        input.extend(compiled.abi.encode_constructor(&[owner.into()]).unwrap());
+
+        let verification = compiled.verify(&runtime(&METADATA, 0x11), Some(&input));
+
+        assert!(verification.matches);
+        assert!(!verification.metadata_matches);
+        assert_eq!(verification.constructor_arguments, Some(vec![owner.into()]));
+
+        input[0] = 0x00;
+
+        let verification = compiled.verify(&runtime(&METADATA, 0x11), Some(&input));
+
+        assert!(!verification.matches);
+        assert_eq!(verification.constructor_arguments, None);
+    }
+
+    #[test]
+    fn verify_creation_with_other_metadata_length() {
+        let compiled = compiled();
+        let owner = EvmAddress::from([0x22; 20]);
+
+        // `{"abcd": 1}`, followed by its length.
+        let mut input = creation(&[0xa1, 0x64, b'a', b'b', b'c', b'd', 0x01, 0x00, 0x07]);
+        input.extend(compiled.abi.encode_constructor(&[owner.into()]).unwrap());
+
+        let verification = compiled.verify(&runtime(&METADATA, 0x11), Some(&input));
+
+        assert!(verification.matches);
+        assert!(!verification.metadata_matches);
+        assert_eq!(verification.constructor_arguments, Some(vec![owner.into()]));
+    }
+
+    #[test]
+    fn verify_creation_without_arguments() {
+        let compiled = compiled();
+
+        // the metadata is there, but the arguments aren't.
+        let input = creation(&METADATA);
+
+        let verification = compiled.verify(&runtime(&METADATA, 0x11), Some(&input));
+
+        assert!(verification.matches);
+        assert!(verification.metadata_matches);
+        assert_eq!(verification.constructor_arguments, None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_solc_json() {
+        let json = format!(
+            r#"{{
+                "contracts": {{
+                    "contracts/Pool.sol": {{
+                        "Pool": {{
+                            "abi": [{{ "type": "constructor", "inputs": [{{ "name": "owner", "type": "address" }}] }}],
+                            "evm": {{
+                                "bytecode": {{ "object": "{}" }},
+                                "deployedBytecode": {{
+                                    "object": "{}",
+                                    "immutableReferences": {{ "7": [{{ "start": 7, "length": 32 }}] }}
+                                }}
+                            }}
+                        }},
+                        "IPool": {{ "abi": [], "evm": {{ "bytecode": {{ "object": "" }} }} }}
+                    }},
+                    "contracts/Other.sol": {{ "IPool": {{ "abi": [] }} }}
+                }}
+            }}"#,
+            hex::encode(creation(&METADATA)),
+            hex::encode(runtime(&METADATA, 0)),
+        );
+
+        assert_eq!(CompiledContract::from_solc_json(&json, "Pool").unwrap(), compiled());
+        assert_eq!(
+            CompiledContract::from_solc_json(&json, "contracts/Pool.sol:Pool").unwrap(),
+            compiled()
+        );
+
+        assert!(CompiledContract::from_solc_json(&json, "Token").is_err());
+        assert!(CompiledContract::from_solc_json(&json, "IPool").is_err());
+        assert!(CompiledContract::from_solc_json(&json, "contracts/Pool.sol:IPool").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_combined_json() {
+        let json = format!(
+            r#"{{
+                "contracts": {{
+                    "contracts/Pool.sol:Pool": {{ "abi": "[]", "bin": "{}", "bin-runtime": "{}" }},
+                    "contracts/Lib.sol:Lib": {{ "abi": [], "bin": "73__$abc$__", "bin-runtime": "" }}
+                }},
+                "version": "0.8.28"
+            }}"#,
+            hex::encode(creation(&METADATA)),
+            hex::encode(runtime(&METADATA, 0)),
+        );
+
+        let compiled = CompiledContract::from_solc_json(&json, "Pool").unwrap();
+
+        assert_eq!(compiled.bytecode, creation(&METADATA));
+        assert!(compiled.immutable_references.is_empty());
+
+        assert!(CompiledContract::from_solc_json(&json, "Lib").is_err());
+    }
+}
@@ -8,6 +8,10 @@ use tonic::transport::Channel;
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
 use crate::staked_id::StakedId;
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -246,6 +250,15 @@ impl TransactionData for ContractCreateTransactionData {
     fn default_max_transaction_fee(&self) -> crate::Hbar {
         crate::Hbar::new(20)
     }
+
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        validation::validate_memo("contract_memo", &self.contract_memo, errors);
+        validation::validate_auto_renew_period(
+            "auto_renew_period",
+            Some(self.auto_renew_period),
+            errors,
+        );
+    }
 }
 
 impl TransactionExecute for ContractCreateTransactionData {
@@ -1,9 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use hedera_proto::services::smart_contract_service_client::SmartContractServiceClient;
 use tonic::transport::Channel;
 
+use super::gas_estimator::Call;
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
 use crate::transaction::{
@@ -16,9 +18,11 @@ use crate::transaction::{
 };
 use crate::{
     BoxGrpcFuture,
+    Client,
     ContractFunctionParameters,
     ContractId,
     Error,
+    GasEstimator,
     Hbar,
     ToProtobuf,
     Transaction,
@@ -50,6 +54,9 @@ pub struct ContractExecuteTransactionData {
 
     /// The function parameters as their raw bytes.
     function_parameters: Vec<u8>,
+
+    /// Estimates the gas when the transaction is executed, if set.
+    auto_gas: Option<GasEstimator>,
 }
 
 impl ContractExecuteTransaction {
@@ -72,8 +79,12 @@ impl ContractExecuteTransaction {
     }
 
     /// Sets the maximum amount of gas to use for the call.
+    ///
+    /// This replaces [`auto_gas`](Self::auto_gas), if set.
     pub fn gas(&mut self, gas: u64) -> &mut Self {
-        self.data_mut().gas = gas;
+        let data = self.data_mut();
+        data.gas = gas;
+        data.auto_gas = None;
         self
     }
 
@@ -101,6 +112,29 @@ impl ContractExecuteTransaction {
         self
     }
 
+    /// Returns the estimator used to set the gas when the transaction is executed.
+    ///
+    /// This is `None` once the gas has been estimated.
+    #[must_use]
+    pub fn get_auto_gas(&self) -> Option<&GasEstimator> {
+        self.data().auto_gas.as_ref()
+    }
+
+    /// Sets the gas to the estimate from `estimator` when the transaction is executed,
+    /// replacing the gas set with [`gas`](Self::gas).
+    ///
+    /// The estimate is made by simulating the call, see [`GasEstimator`].
+    /// Without a [mirror node](GasEstimator::mirror_node_url), each simulation is a paid [`ContractCallQuery`](crate::ContractCallQuery),
+    /// so estimating costs up to [`max_simulations`](GasEstimator::max_simulations) queries (12 by default)
+    /// on top of the transaction itself.
+    ///
+    /// The gas is only estimated by [`execute`](Self::execute), so until then the transaction can't be
+    /// frozen, serialized with [`to_bytes`](Self::to_bytes), or [scheduled](Self::schedule).
+    pub fn auto_gas(&mut self, estimator: GasEstimator) -> &mut Self {
+        self.data_mut().auto_gas = Some(estimator);
+        self
+    }
+
     /// Sets the function with no parameters.
     pub fn function(&mut self, name: &str) -> &mut Self {
         self.function_with_parameters(name, &ContractFunctionParameters::new())
@@ -116,7 +150,35 @@ impl ContractExecuteTransaction {
     }
 }
 
-impl TransactionData for ContractExecuteTransactionData {}
+impl TransactionData for ContractExecuteTransactionData {
+    fn prepare<'a>(&'a mut self, client: &'a Client) -> Option<BoxFuture<'a, crate::Result<()>>> {
+        let estimator = self.auto_gas.clone()?;
+
+        Some(Box::pin(async move {
+            let contract_id = self
+                .contract_id
+                .ok_or_else(|| Error::gas_estimate("the transaction has no contract ID"))?;
+
+            let call = Call {
+                contract_id: Some(contract_id),
+                data: &self.function_parameters,
+                value: self.payable_amount,
+            };
+
+            self.gas = estimator.estimate_call(client, &call).await?.gas;
+            self.auto_gas = None;
+
+            Ok(())
+        }))
+    }
+
+    fn require_prepared(&self) -> crate::Result<()> {
+        match self.auto_gas {
+            Some(_) => Err(Error::GasNotEstimated),
+            None => Ok(()),
+        }
+    }
+}
 
 impl TransactionExecute for ContractExecuteTransactionData {
     fn execute(
@@ -172,6 +234,7 @@ impl FromProtobuf<services::ContractCallTransactionBody> for ContractExecuteTran
             gas: pb.gas as u64,
             payable_amount: Hbar::from_tinybars(pb.amount),
             function_parameters: pb.function_parameters,
+            auto_gas: None,
         })
     }
 }
@@ -192,6 +255,7 @@ impl ToProtobuf for ContractExecuteTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
 
@@ -208,6 +272,8 @@ mod tests {
         AnyTransaction,
         ContractExecuteTransaction,
         ContractId,
+        Error,
+        GasEstimator,
         Hbar,
     };
 
@@ -339,6 +405,44 @@ mod tests {
         make_transaction().payable_amount(PAYABLE_AMOUNT);
     }
 
+    #[test]
+    fn get_set_auto_gas() {
+        let mut tx = ContractExecuteTransaction::new();
+        assert!(tx.get_auto_gas().is_none());
+
+        tx.auto_gas(GasEstimator::new().safety_margin(50).clone());
+
+        assert_eq!(tx.get_auto_gas().map(GasEstimator::get_safety_margin), Some(50));
+    }
+
+    #[test]
+    fn gas_replaces_auto_gas() {
+        let mut tx = ContractExecuteTransaction::new();
+        tx.auto_gas(GasEstimator::new()).gas(GAS);
+
+        assert!(tx.get_auto_gas().is_none());
+        assert_eq!(tx.get_gas(), GAS);
+    }
+
+    #[test]
+    fn auto_gas_not_estimated() {
+        let mut tx = ContractExecuteTransaction::new_for_tests();
+        tx.contract_id(CONTRACT_ID).auto_gas(GasEstimator::new());
+
+        // the gas is only estimated by `execute`, so it would be sent as 0.
+        assert_matches!(tx.to_bytes(), Err(Error::GasNotEstimated));
+        assert_matches!(tx.freeze(), Err(Error::GasNotEstimated));
+    }
+
+    #[test]
+    #[should_panic]
+    fn auto_gas_schedule_panics() {
+        let mut tx = ContractExecuteTransaction::new();
+        tx.contract_id(CONTRACT_ID).auto_gas(GasEstimator::new());
+
+        tx.schedule();
+    }
+
     #[test]
     fn get_set_function_parameters() {
         let mut tx = ContractExecuteTransaction::new();
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::cmp::max;
 use std::str::FromStr;
 
@@ -10,6 +11,7 @@ use num_bigint::{
 use self::private::Sealed;
 use crate::contract::contract_function_selector::ContractFunctionSelector;
 use crate::ethereum::SolidityAddress;
+use crate::AbiType;
 
 /// Builder for encoding parameters for a Solidity contract constructor/function call.
 #[derive(Debug, Clone, Default)]
@@ -19,11 +21,22 @@ pub struct ContractFunctionParameters {
 
 #[derive(Debug, Clone)]
 struct Argument {
-    type_name: &'static str,
+    type_name: Cow<'static, str>,
     value_bytes: Vec<u8>,
     is_dynamic: bool,
 }
 
+impl Argument {
+    /// The number of bytes this argument takes up in the head, before any dynamic arguments.
+    fn head_len(&self) -> usize {
+        if self.is_dynamic {
+            32
+        } else {
+            self.value_bytes.len()
+        }
+    }
+}
+
 mod private {
     pub trait Sealed {}
     impl Sealed for String {}
@@ -133,29 +146,14 @@ impl ContractFunctionParameters {
     // since downstream code can just...
     // Call this with `Option<&A>` anyway if they want to keep ownership of it.
     pub fn to_bytes(&self, func_name: Option<&str>) -> Vec<u8> {
-        let mut current_dynamic_offset = self.args.len() * 32;
-        let mut arg_bytes = Vec::new();
-        let mut dynamic_arg_bytes = Vec::new();
-        let mut function_selector = func_name.map(ContractFunctionSelector::new);
-        for arg in &self.args {
-            if let Some(selector) = &mut function_selector {
-                selector.add_param_type(arg.type_name);
-            }
-            if arg.is_dynamic {
-                arg_bytes.extend_from_slice(
-                    left_pad_32_bytes(current_dynamic_offset.to_be_bytes().as_slice(), false)
-                        .as_slice(),
-                );
-                dynamic_arg_bytes.extend_from_slice(arg.value_bytes.as_slice());
-                current_dynamic_offset += arg.value_bytes.len();
-            } else {
-                arg_bytes.extend_from_slice(arg.value_bytes.as_slice());
-            }
-        }
+        let mut arg_bytes = encode_arguments(&self.args);
 
-        arg_bytes.append(&mut dynamic_arg_bytes);
+        if let Some(func_name) = func_name {
+            let mut selector = ContractFunctionSelector::new(func_name);
+            for arg in &self.args {
+                selector.add_param_type(&arg.type_name);
+            }
 
-        if let Some(selector) = &mut function_selector {
             let mut out_bytes = Vec::from(selector.finish());
             out_bytes.append(&mut arg_bytes);
             out_bytes
@@ -167,7 +165,7 @@ impl ContractFunctionParameters {
     /// Add a `string` argument to the `ContractFunctionParameters`
     pub fn add_string<T: AsRef<str>>(&mut self, val: T) -> &mut Self {
         self.args.push(Argument {
-            type_name: "string",
+            type_name: Cow::Borrowed("string"),
             value_bytes: encode_dynamic_bytes(val.as_ref().as_bytes()),
             is_dynamic: true,
         });
@@ -177,7 +175,7 @@ impl ContractFunctionParameters {
     /// Add a `string[]` argument to the `ContractFunctionParameters`
     pub fn add_string_array<T: AsRef<str>>(&mut self, val: &[T]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "string[]",
+            type_name: Cow::Borrowed("string[]"),
             value_bytes: encode_array_of_dynamic_byte_arrays(
                 val.iter().map(|s| s.as_ref().as_bytes()),
                 val.len(),
@@ -190,7 +188,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes` argument to the `ContractFunctionParameters`
     pub fn add_bytes(&mut self, val: &[u8]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes",
+            type_name: Cow::Borrowed("bytes"),
             value_bytes: encode_dynamic_bytes(val),
             is_dynamic: true,
         });
@@ -200,7 +198,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes[]` argument to the `ContractFunctionParameters`
     pub fn add_bytes_array(&mut self, val: &[&[u8]]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes[]",
+            type_name: Cow::Borrowed("bytes[]"),
             value_bytes: encode_array_of_dynamic_byte_arrays(val, val.len()),
             is_dynamic: true,
         });
@@ -210,7 +208,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes32` argument to the `ContractFunctionParameters`
     pub fn add_bytes32<T: AsBytes32 + ?Sized>(&mut self, val: &T) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes32",
+            type_name: Cow::Borrowed("bytes32"),
             value_bytes: encode_array_of_32_byte(val),
             is_dynamic: false,
         });
@@ -220,7 +218,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes32[]` argument to the `ContractFunctionParameters`
     pub fn add_bytes32_array(&mut self, val: &[[u8; 32]]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes32",
+            type_name: Cow::Borrowed("bytes32[]"),
             value_bytes: encode_array_of_32_byte_elements(val.iter().copied(), val.len()),
             is_dynamic: true,
         });
@@ -230,7 +228,7 @@ impl ContractFunctionParameters {
     /// Add a `bool` argument to the `ContractFunctionParameters`
     pub fn add_bool(&mut self, val: bool) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bool",
+            type_name: Cow::Borrowed("bool"),
             value_bytes: left_pad_32_bytes(
                 // a bool in rust is guaranteed to be of value 0 or 1
                 u32::from(val).to_be_bytes().as_slice(),
@@ -247,7 +245,7 @@ impl ContractFunctionParameters {
         T: IntEncode,
     {
         self.args.push(Argument {
-            type_name,
+            type_name: Cow::Borrowed(type_name),
             value_bytes: truncate_and_left_pad_32_bytes(val, byte_count).to_vec(),
             is_dynamic: false,
         });
@@ -264,7 +262,7 @@ impl ContractFunctionParameters {
         T: IntEncode,
     {
         self.args.push(Argument {
-            type_name,
+            type_name: Cow::Borrowed(type_name),
             value_bytes: encode_array_of_32_byte_elements(
                 values.iter().map(|val| truncate_and_left_pad_32_bytes(val, byte_count)),
                 values.len(),
@@ -917,7 +915,7 @@ impl ContractFunctionParameters {
     /// Add an `address` argument to the `ContractFunctionParameters`
     pub fn add_address(&mut self, address: &str) -> &mut Self {
         self.args.push(Argument {
-            type_name: "address",
+            type_name: Cow::Borrowed("address"),
             value_bytes: encode_address(address).to_vec(),
             is_dynamic: false,
         });
@@ -927,7 +925,7 @@ impl ContractFunctionParameters {
     /// Add an `address[]` argument to the `ContractFunctionParameters`
     pub fn add_address_array(&mut self, addresses: &[&str]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "address[]",
+            type_name: Cow::Borrowed("address[]"),
             value_bytes: encode_array_of_32_byte_elements(
                 addresses.iter().map(|addr| encode_address(addr)),
                 addresses.len(),
@@ -951,12 +949,118 @@ impl ContractFunctionParameters {
         right_pad_32_bytes(&mut value_bytes);
 
         self.args.push(Argument {
-            type_name: "function",
+            type_name: Cow::Borrowed("function"),
             value_bytes: value_bytes,
             is_dynamic: false,
         });
         self
     }
+
+    /// Add a tuple (struct) argument to the `ContractFunctionParameters`, with the fields of `tuple` in order.
+    ///
+    /// For instance, a `(address,uint256)` is a tuple with an address field followed by a uint256 field.
+    pub fn add_tuple(&mut self, tuple: &ContractFunctionParameters) -> &mut Self {
+        self.args.push(tuple.to_tuple_argument());
+        self
+    }
+
+    /// Add an array of tuples (structs) argument to the `ContractFunctionParameters`.
+    ///
+    /// # Panics
+    /// - If `tuples` is empty, since then there's no element type, use [`add_empty_array`](Self::add_empty_array) instead.
+    /// - If the tuples don't all have the same field types.
+    pub fn add_tuple_array(&mut self, tuples: &[ContractFunctionParameters]) -> &mut Self {
+        let elements: Vec<_> = tuples.iter().map(Self::to_tuple_argument).collect();
+
+        let type_name = format!("{}[]", same_type_name(&elements));
+
+        let mut value_bytes =
+            left_pad_32_bytes(elements.len().to_be_bytes().as_slice(), false).to_vec();
+        value_bytes.extend(encode_arguments(&elements));
+
+        self.args.push(Argument {
+            type_name: Cow::Owned(type_name),
+            value_bytes,
+            is_dynamic: true,
+        });
+        self
+    }
+
+    /// Add a fixed-length array argument to the `ContractFunctionParameters`, where each argument of `elements` is an element.
+    ///
+    /// For instance, a `uint256[3]` is three `add_uint256` elements, and a `(address,uint256)[2]` is two `add_tuple` elements.
+    ///
+    /// # Panics
+    /// - If `elements` is empty.
+    /// - If the elements don't all have the same type.
+    pub fn add_fixed_array(&mut self, elements: &ContractFunctionParameters) -> &mut Self {
+        let type_name = format!("{}[{}]", same_type_name(&elements.args), elements.args.len());
+
+        self.args.push(Argument {
+            type_name: Cow::Owned(type_name),
+            value_bytes: encode_arguments(&elements.args),
+            is_dynamic: elements.args.iter().any(|arg| arg.is_dynamic),
+        });
+        self
+    }
+
+    /// Add an empty `T[]` argument to the `ContractFunctionParameters`, where `T` is `element_type`.
+    ///
+    /// Needed for arrays of tuples or fixed arrays, where the element type can't be inferred from an empty slice.
+    pub fn add_empty_array(&mut self, element_type: &AbiType) -> &mut Self {
+        self.args.push(Argument {
+            type_name: Cow::Owned(format!("{element_type}[]")),
+            value_bytes: [0; 32].to_vec(),
+            is_dynamic: true,
+        });
+        self
+    }
+
+    fn to_tuple_argument(&self) -> Argument {
+        let type_names: Vec<_> = self.args.iter().map(|arg| &*arg.type_name).collect();
+
+        Argument {
+            type_name: Cow::Owned(format!("({})", type_names.join(","))),
+            value_bytes: encode_arguments(&self.args),
+            is_dynamic: self.args.iter().any(|arg| arg.is_dynamic),
+        }
+    }
+}
+
+/// Encodes `args` as a sequence: static arguments in place, and dynamic arguments as offsets to the end.
+fn encode_arguments(args: &[Argument]) -> Vec<u8> {
+    let head_len: usize = args.iter().map(Argument::head_len).sum();
+
+    let mut arg_bytes = Vec::with_capacity(head_len);
+    let mut dynamic_arg_bytes = Vec::new();
+
+    for arg in args {
+        if arg.is_dynamic {
+            let offset = head_len + dynamic_arg_bytes.len();
+            arg_bytes.extend_from_slice(&left_pad_32_bytes(offset.to_be_bytes().as_slice(), false));
+            dynamic_arg_bytes.extend_from_slice(&arg.value_bytes);
+        } else {
+            arg_bytes.extend_from_slice(&arg.value_bytes);
+        }
+    }
+
+    arg_bytes.append(&mut dynamic_arg_bytes);
+    arg_bytes
+}
+
+fn same_type_name(args: &[Argument]) -> &str {
+    let Some((first, rest)) = args.split_first() else {
+        panic!("array elements must have a type, but there are no elements");
+    };
+
+    if let Some(arg) = rest.iter().find(|arg| arg.type_name != first.type_name) {
+        panic!(
+            "array elements must all have the same type, found `{}` and `{}`",
+            first.type_name, arg.type_name
+        );
+    }
+
+    &first.type_name
 }
 
 fn left_pad_32_bytes(bytes: &[u8], is_negative: bool) -> [u8; 32] {
@@ -1051,6 +1155,40 @@ mod tests {
 
     use crate::contract::contract_function_parameters::ContractFunctionParameters;
     use crate::contract::contract_function_selector::ContractFunctionSelector;
+    use crate::{
+        AbiFunction,
+        AbiParam,
+        AbiType,
+        AbiValue,
+        EvmAddress,
+    };
+
+    const ADDRESS: &str = "0x1122334455667788990011223344556677889900";
+
+    fn order(amount: u32) -> ContractFunctionParameters {
+        ContractFunctionParameters::new().add_address(ADDRESS).add_uint256(amount.into()).clone()
+    }
+
+    fn swap(types: &[&str]) -> AbiFunction {
+        let inputs = types.iter().map(|it| AbiParam::new("", it.parse().unwrap())).collect();
+        AbiFunction::new("swap", inputs, Vec::new())
+    }
+
+    #[test]
+    fn bytes32_array_selector() {
+        let param_bytes = ContractFunctionParameters::new()
+            .add_bytes32_array(&[[0x11; 32]])
+            .to_bytes(Some("foo"));
+
+        // `foo(bytes32[])`, it used to be hashed as `foo(bytes32)` (`11e814c1`).
+        assert_eq!(hex::encode(&param_bytes[..4]), "c8442119");
+        assert_eq!(
+            hex::encode(&param_bytes[4..]),
+            "0000000000000000000000000000000000000000000000000000000000000020\
+            0000000000000000000000000000000000000000000000000000000000000001\
+            1111111111111111111111111111111111111111111111111111111111111111"
+        );
+    }
 
     #[test]
     fn misc_params() {
@@ -1279,4 +1417,81 @@ mod tests {
         // should panic if input is more than 32 bytes in add_bytes32
         ContractFunctionParameters::new().add_bytes32(str_sample).to_bytes(None);
     }
+
+    #[test]
+    fn tuple_params() {
+        let param_bytes = ContractFunctionParameters::new()
+            .add_tuple_array(&[order(1), order(2)])
+            .add_fixed_array(
+                ContractFunctionParameters::new().add_uint64(1).add_uint64(2).add_uint64(3),
+            )
+            .add_tuple(
+                ContractFunctionParameters::new()
+                    .add_string("nested")
+                    .add_tuple(ContractFunctionParameters::new().add_bool(true).add_int8(-1)),
+            )
+            .add_uint8(9)
+            .to_bytes(Some("swap"));
+
+        let address = EvmAddress::from([
+            0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0x00, 0x11, 0x22, 0x33, 0x44,
+            0x55, 0x66, 0x77, 0x88, 0x99, 0x00,
+        ]);
+
+        let expected = swap(&["(address,uint256)[]", "uint64[3]", "(string,(bool,int8))", "uint8"])
+            .encode_input(&[
+                AbiValue::Array(vec![
+                    AbiValue::Tuple(vec![address.into(), 1_u8.into()]),
+                    AbiValue::Tuple(vec![address.into(), 2_u8.into()]),
+                ]),
+                AbiValue::FixedArray(vec![1_u8.into(), 2_u8.into(), 3_u8.into()]),
+                AbiValue::Tuple(vec![
+                    "nested".into(),
+                    AbiValue::Tuple(vec![true.into(), (-1_i8).into()]),
+                ]),
+                9_u8.into(),
+            ])
+            .unwrap();
+
+        assert_eq!(hex::encode(param_bytes), hex::encode(expected));
+    }
+
+    #[test]
+    fn fixed_array_of_dynamic_params() {
+        let param_bytes = ContractFunctionParameters::new()
+            .add_fixed_array(ContractFunctionParameters::new().add_string("a").add_string("b"))
+            .to_bytes(Some("swap"));
+
+        let expected = swap(&["string[2]"])
+            .encode_input(&[AbiValue::FixedArray(vec!["a".into(), "b".into()])])
+            .unwrap();
+
+        assert_eq!(hex::encode(param_bytes), hex::encode(expected));
+    }
+
+    #[test]
+    fn empty_tuple_array_params() {
+        let tuple = AbiType::Tuple(vec![AbiType::Address, AbiType::Uint(256)]);
+
+        let param_bytes =
+            ContractFunctionParameters::new().add_empty_array(&tuple).to_bytes(Some("swap"));
+
+        let expected =
+            swap(&["(address,uint256)[]"]).encode_input(&[AbiValue::Array(Vec::new())]).unwrap();
+
+        assert_eq!(hex::encode(param_bytes), hex::encode(expected));
+    }
+
+    #[test]
+    #[should_panic]
+    fn tuple_array_mismatched_types_panics() {
+        ContractFunctionParameters::new()
+            .add_tuple_array(&[order(1), ContractFunctionParameters::new().add_bool(true).clone()]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_fixed_array_panics() {
+        ContractFunctionParameters::new().add_fixed_array(&ContractFunctionParameters::new());
+    }
 }
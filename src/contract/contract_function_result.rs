@@ -9,12 +9,19 @@ use num_bigint::{
     BigUint,
 };
 
+use crate::contract::abi::codec;
 use crate::protobuf::ToProtobuf;
 use crate::{
+    AbiError,
+    AbiEvent,
+    AbiType,
+    AbiValue,
     AccountId,
+    ContractEvent,
     ContractId,
     ContractLogInfo,
     ContractNonceInfo,
+    ContractRevertReason,
     FromProtobuf,
 };
 
@@ -205,6 +212,102 @@ impl ContractFunctionResult {
     pub fn get_i256(&self, index: usize) -> Option<BigInt> {
         self.get_bytes32(index).map(|it| BigInt::from_signed_bytes_be(it))
     }
+
+    /// Get the value at `index` as a solidity tuple (struct) with fields of the given `types`.
+    ///
+    /// A tuple without any dynamic fields is stored in place, taking up one slot per field,
+    /// so it shifts the `index` of every value after it.
+    #[must_use]
+    pub fn get_tuple(&self, index: usize, types: &[AbiType]) -> Option<Vec<AbiValue>> {
+        match self.get_value(index, &AbiType::Tuple(types.to_vec()))? {
+            AbiValue::Tuple(fields) => Some(fields),
+            _ => None,
+        }
+    }
+
+    /// Get the value at `index` as a solidity array of tuples (structs) with fields of the given `types`.
+    #[must_use]
+    pub fn get_tuple_array(&self, index: usize, types: &[AbiType]) -> Option<Vec<Vec<AbiValue>>> {
+        let ty = AbiType::Array(Box::new(AbiType::Tuple(types.to_vec())));
+
+        match self.get_value(index, &ty)? {
+            AbiValue::Array(tuples) => tuples
+                .into_iter()
+                .map(|it| match it {
+                    AbiValue::Tuple(fields) => Some(fields),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+
+    /// Get the value at `index` as a solidity fixed-length array of `len` elements of `element_type`.
+    ///
+    /// An array of static elements is stored in place, taking up one slot per element,
+    /// so it shifts the `index` of every value after it.
+    #[must_use]
+    pub fn get_fixed_array(
+        &self,
+        index: usize,
+        element_type: &AbiType,
+        len: usize,
+    ) -> Option<Vec<AbiValue>> {
+        match self.get_value(index, &AbiType::FixedArray(Box::new(element_type.clone()), len))? {
+            AbiValue::FixedArray(elements) => Some(elements),
+            _ => None,
+        }
+    }
+
+    /// Decode every log in [`logs`](Self::logs) that was emitted by `event`, in the order they were emitted.
+    ///
+    /// Logs of other events are skipped, as are logs from contracts other than `contract_id`, if it's given.
+    /// This also works for the result in a [`TransactionRecord`](crate::TransactionRecord).
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`](crate::Error::AbiDecode) if a log matching `event` fails to decode.
+    pub fn decode_events(
+        &self,
+        event: &AbiEvent,
+        contract_id: Option<ContractId>,
+    ) -> crate::Result<Vec<ContractEvent>> {
+        self.logs
+            .iter()
+            .filter(|log| contract_id.map_or(true, |id| is_same_contract(log.contract_id, id)))
+            .filter(|log| event.matches(log))
+            .map(|log| event.decode_log(log))
+            .collect()
+    }
+
+    /// Decode why the call reverted, recognizing `Error(string)`, `Panic(uint256)` and the given `custom_errors`.
+    ///
+    /// Returns `None` if the call didn't fail, that is, if there's no [`error_message`](Self::error_message).
+    ///
+    /// The revert data is taken from `error_message` when it's hex encoded, which is how the network reports it,
+    /// and from [`bytes`](Self::bytes) otherwise.
+    #[must_use]
+    pub fn revert_reason(&self, custom_errors: &[AbiError]) -> Option<ContractRevertReason> {
+        let message = self.error_message.as_deref()?;
+
+        let data = match message.strip_prefix("0x").and_then(|it| hex::decode(it).ok()) {
+            Some(data) => Cow::Owned(data),
+            None => Cow::Borrowed(self.bytes.as_slice()),
+        };
+
+        Some(ContractRevertReason::decode(&data, custom_errors))
+    }
+
+    fn get_value(&self, index: usize, ty: &AbiType) -> Option<AbiValue> {
+        let offset =
+            if ty.is_dynamic() { self.get_u32(index)? as usize } else { index * Self::SLOT_SIZE };
+
+        codec::decode_value(ty, self.bytes.get(offset..)?).ok()
+    }
+}
+
+// ignores the checksum, which is only there to validate user input.
+fn is_same_contract(a: ContractId, b: ContractId) -> bool {
+    (a.shard, a.realm, a.num, a.evm_address) == (b.shard, b.realm, b.num, b.evm_address)
 }
 
 impl FromProtobuf<services::ContractFunctionResult> for ContractFunctionResult {
@@ -310,10 +413,17 @@ mod tests {
         ToProtobuf,
     };
     use crate::{
+        AbiError,
+        AbiEvent,
+        AbiType,
+        AbiValue,
         AccountId,
+        ContractFunctionParameters,
         ContractFunctionResult,
         ContractId,
+        ContractLogInfo,
         ContractNonceInfo,
+        ContractRevertReason,
     };
 
     const CALL_RESULT: [u8; 320] = hex!(
@@ -484,4 +594,114 @@ mod tests {
         assert_eq!(strings[0], "random bytes");
         assert_eq!(strings[1], "random bytes 2")
     }
+
+    #[test]
+    fn tuple_and_fixed_array_results() {
+        let order = |amount: u32| {
+            ContractFunctionParameters::new()
+                .add_address("0x1122334455667788990011223344556677889900")
+                .add_uint256(amount.into())
+                .clone()
+        };
+
+        let bytes = ContractFunctionParameters::new()
+            .add_tuple_array(&[order(1), order(2)])
+            .add_fixed_array(
+                ContractFunctionParameters::new().add_uint64(1).add_uint64(2).add_uint64(3),
+            )
+            .add_tuple(ContractFunctionParameters::new().add_string("nested").add_bool(true))
+            .add_uint8(9)
+            .to_bytes(None);
+
+        let result = services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(3).to_protobuf()),
+            contract_call_result: bytes,
+            ..Default::default()
+        };
+
+        let result = ContractFunctionResult::from_protobuf(result).unwrap();
+
+        let orders = result.get_tuple_array(0, &[AbiType::Address, AbiType::Uint(256)]).unwrap();
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[1][1].as_uint(), Some(BigUint::from(2_u8)));
+
+        // the `uint64[3]` takes up slots 1 to 3.
+        let amounts = result.get_fixed_array(1, &AbiType::Uint(64), 3).unwrap();
+        assert_eq!(amounts, [AbiValue::from(1_u64), 2_u64.into(), 3_u64.into()]);
+
+        let tuple = result.get_tuple(4, &[AbiType::String, AbiType::Bool]).unwrap();
+        assert_eq!(tuple, [AbiValue::from("nested"), true.into()]);
+
+        assert_eq!(result.get_u8(5), Some(9));
+
+        // an offset isn't a valid `bool`.
+        assert_eq!(result.get_tuple(4, &[AbiType::Bool, AbiType::Bool]), None);
+    }
+
+    #[test]
+    fn decode_events() {
+        let transfer =
+            AbiEvent::parse("Transfer(address indexed,address indexed,uint256)").unwrap();
+        let approval =
+            AbiEvent::parse("Approval(address indexed,address indexed,uint256)").unwrap();
+
+        let address = hex!("0000000000000000000000001111111111111111111111111111111111111111");
+
+        let log = |contract: u64, event: &AbiEvent, amount: u8| ContractLogInfo {
+            contract_id: ContractId::new(0, 0, contract),
+            bloom: Vec::new(),
+            topics: vec![event.topic().to_vec(), address.to_vec(), address.to_vec()],
+            data: ContractFunctionParameters::new().add_uint8(amount).to_bytes(None),
+        };
+
+        let mut result = ContractFunctionResult::from_protobuf(services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(1001).to_protobuf()),
+            ..Default::default()
+        })
+        .unwrap();
+
+        result.logs =
+            vec![log(1001, &transfer, 1), log(1001, &approval, 2), log(1002, &transfer, 3)];
+
+        let amounts = |contract_id: Option<ContractId>| -> Vec<AbiValue> {
+            let events = result.decode_events(&transfer, contract_id).unwrap();
+
+            events.into_iter().map(|it| it.values[2].clone()).collect()
+        };
+
+        assert_eq!(amounts(None), [AbiValue::from(1_u8), 3_u8.into()]);
+        assert_eq!(amounts(Some(ContractId::new(0, 0, 1002))), [AbiValue::from(3_u8)]);
+        assert_eq!(amounts(Some(ContractId::new(0, 0, 1003))), []);
+    }
+
+    #[test]
+    fn revert_reason() {
+        let error = AbiError::parse("Unauthorized(address)").unwrap();
+
+        let mut result = ContractFunctionResult::from_protobuf(services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(1001).to_protobuf()),
+            ..Default::default()
+        })
+        .unwrap();
+
+        assert_eq!(result.revert_reason(&[error.clone()]), None);
+
+        result.error_message = Some(format!(
+            "0x{}{}",
+            hex::encode(error.selector()),
+            "0000000000000000000000001111111111111111111111111111111111111111"
+        ));
+
+        assert_eq!(
+            result.revert_reason(&[error]),
+            Some(ContractRevertReason::Custom {
+                name: "Unauthorized".to_owned(),
+                values: vec![AbiValue::Address([0x11; 20].into())],
+            })
+        );
+
+        result.error_message = Some("CONTRACT_REVERT_EXECUTED".to_owned());
+
+        assert_eq!(result.revert_reason(&[]), Some(ContractRevertReason::Empty));
+    }
 }
@@ -3,187 +3,303 @@ use sha3::{
     Keccak256,
 };
 
+use crate::contract::abi::signature::parse_signature;
+use crate::{
+    AbiType,
+    Error,
+};
+
 /// Builder class for Solidity function selectors.
+///
+/// A selector is the first 4 bytes of the Keccak-256 hash of a function's canonical signature,
+/// such as `transfer(address,uint256)`, and identifies which function a contract call is for.
+///
+/// Adding parameters to a selector after it's been [finished](Self::finish) panics.
 #[derive(Debug, Clone)]
-pub struct ContractFunctionSelector(ContractFunctionSelectorState);
+pub struct ContractFunctionSelector {
+    // the function name and parameter types, `None` if the selector was created from its bytes.
+    signature: Option<(String, Vec<AbiType>)>,
 
-// this isn't intended for storage on the heap, so, boxing the hasher is... futile.
-#[allow(clippy::large_enum_variant)]
-#[derive(Debug, Clone)]
-enum ContractFunctionSelectorState {
-    Building { digest: sha3::Keccak256, needs_comma: bool },
-    Finished([u8; 4]),
+    // set once the selector is finished, after which no more parameters can be added.
+    finished: Option<[u8; 4]>,
 }
 
-use ContractFunctionSelectorState::{
-    Building,
-    Finished,
-};
-
 impl From<[u8; 4]> for ContractFunctionSelector {
     fn from(value: [u8; 4]) -> Self {
-        Self(Finished(value))
+        Self { signature: None, finished: Some(value) }
     }
 }
 
 impl ContractFunctionSelector {
+    /// Start building the selector of the function named `func_name`.
+    #[must_use]
     pub fn new(func_name: &str) -> Self {
-        let mut digest = Keccak256::new_with_prefix(func_name.as_bytes());
-        digest.update(b"(");
-        Self(Building { digest, needs_comma: false })
+        Self { signature: Some((func_name.to_owned(), Vec::new())), finished: None }
+    }
+
+    /// Parse a selector from a full Solidity function signature, such as `swap((address,uint24),uint256[])`.
+    ///
+    /// The signature doesn't need to be canonical, parameters may be named and have data locations
+    /// (`swap((address token, uint24 fee) calldata route, uint[] memory amounts)`),
+    /// and aliases like `uint` are resolved to the canonical `uint256`.
+    ///
+    /// More parameters can still be added to the parsed selector.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `signature` isn't a valid function signature.
+    pub fn parse(signature: &str) -> crate::Result<Self> {
+        let (name, params) = parse_signature(signature)?;
+
+        let param_types = params
+            .into_iter()
+            .map(|it| {
+                if it.indexed {
+                    return Err(Error::basic_parse("function parameters can't be `indexed`"));
+                }
+
+                Ok(it.kind)
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self { signature: Some((name, param_types)), finished: None })
+    }
+
+    /// Returns the name of the function, unless this selector was created from its bytes.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.signature.as_ref().map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the types of the function's parameters, unless this selector was created from its bytes.
+    #[must_use]
+    pub fn param_types(&self) -> Option<&[AbiType]> {
+        self.signature.as_ref().map(|(_, param_types)| param_types.as_slice())
+    }
+
+    /// Returns the canonical signature of the function, for instance `transfer(address,uint256)`,
+    /// unless this selector was created from its bytes.
+    #[must_use]
+    pub fn signature(&self) -> Option<String> {
+        self.signature
+            .as_ref()
+            .map(|(name, param_types)| format!("{name}{}", AbiType::Tuple(param_types.clone())))
+    }
+
+    /// Returns the 4 byte selector for the parameters added so far, without finishing the builder.
+    #[must_use]
+    pub fn selector(&self) -> [u8; 4] {
+        match (self.finished, self.signature()) {
+            (Some(selector), _) => selector,
+            (None, Some(signature)) => {
+                let mut selector = [0; 4];
+                selector.copy_from_slice(&Keccak256::digest(signature)[..4]);
+                selector
+            }
+            // `From<[u8; 4]>` is the only way to not have a signature, and it's always finished.
+            (None, None) => unreachable!(),
+        }
     }
 
     pub(crate) fn add_param_type(&mut self, param_type_name: &str) -> &mut Self {
-        let Building { digest, needs_comma } = &mut self.0 else {
+        let param_type =
+            param_type_name.parse().expect("`param_type_name` should be a valid ABI type");
+
+        self.add_abi_type(param_type)
+    }
+
+    /// Add a parameter of any type, including those without a dedicated method, like `uint24` or `bytes7`.
+    ///
+    /// # Panics
+    /// If the selector is already finished.
+    pub fn add_abi_type(&mut self, param_type: AbiType) -> &mut Self {
+        let (None, Some((_, param_types))) = (self.finished, &mut self.signature) else {
             panic!("Cannot add param type to finished ContractFunctionSelector")
         };
 
-        if *needs_comma {
-            digest.update(b",");
-        }
-
-        digest.update(param_type_name.as_bytes());
-        *needs_comma = true;
+        param_types.push(param_type);
 
         self
     }
 
+    /// Finish building the selector and return its bytes.
+    ///
+    /// Calling this again returns the same bytes, but adding more parameters panics.
     pub fn finish(&mut self) -> [u8; 4] {
-        match &mut self.0 {
-            Building { digest, .. } => {
-                digest.update(b")");
-                let finished_bytes = digest.clone().finalize()[0..4].try_into().unwrap();
-                self.0 = Finished(finished_bytes);
-                finished_bytes
-            }
-            Finished(finished_bytes) => *finished_bytes,
-        }
+        let selector = self.selector();
+        self.finished = Some(selector);
+        selector
     }
 
+    /// Add a `string` parameter.
     pub fn add_string(&mut self) -> &mut Self {
         self.add_param_type("string")
     }
 
+    /// Add a `string[]` parameter.
     pub fn add_string_array(&mut self) -> &mut Self {
         self.add_param_type("string[]")
     }
 
+    /// Add a `bytes` parameter.
     pub fn add_bytes(&mut self) -> &mut Self {
         self.add_param_type("bytes")
     }
 
+    /// Add a `bytes[]` parameter.
     pub fn add_bytes_array(&mut self) -> &mut Self {
         self.add_param_type("bytes[]")
     }
 
+    /// Add a `bytes32` parameter.
     pub fn add_bytes32(&mut self) -> &mut Self {
         self.add_param_type("bytes32")
     }
 
+    /// Add a `bytes32[]` parameter.
     pub fn add_bytes32_array(&mut self) -> &mut Self {
         self.add_param_type("bytes32[]")
     }
 
+    /// Add a `bool` parameter.
     pub fn add_bool(&mut self) -> &mut Self {
         self.add_param_type("bool")
     }
 
+    /// Add a `int8` parameter.
     pub fn add_int8(&mut self) -> &mut Self {
         self.add_param_type("int8")
     }
 
+    /// Add a `int16` parameter.
     pub fn add_int16(&mut self) -> &mut Self {
         self.add_param_type("int16")
     }
 
+    /// Add a `int32` parameter.
     pub fn add_int32(&mut self) -> &mut Self {
         self.add_param_type("int32")
     }
 
+    /// Add a `int64` parameter.
     pub fn add_int64(&mut self) -> &mut Self {
         self.add_param_type("int64")
     }
 
+    /// Add a `int256` parameter.
     pub fn add_int256(&mut self) -> &mut Self {
         self.add_param_type("int256")
     }
 
+    /// Add a `uint8` parameter.
     pub fn add_uint8(&mut self) -> &mut Self {
         self.add_param_type("uint8")
     }
 
+    /// Add a `uint16` parameter.
     pub fn add_uint16(&mut self) -> &mut Self {
         self.add_param_type("uint16")
     }
 
+    /// Add a `uint32` parameter.
     pub fn add_uint32(&mut self) -> &mut Self {
         self.add_param_type("uint32")
     }
 
+    /// Add a `uint64` parameter.
     pub fn add_uint64(&mut self) -> &mut Self {
         self.add_param_type("uint64")
     }
 
+    /// Add a `uint256` parameter.
     pub fn add_uint256(&mut self) -> &mut Self {
         self.add_param_type("uint256")
     }
 
+    /// Add a `int8[]` parameter.
     pub fn add_int8_array(&mut self) -> &mut Self {
         self.add_param_type("int8[]")
     }
 
+    /// Add a `int16[]` parameter.
     pub fn add_int16_array(&mut self) -> &mut Self {
         self.add_param_type("int16[]")
     }
 
+    /// Add a `int32[]` parameter.
     pub fn add_int32_array(&mut self) -> &mut Self {
         self.add_param_type("int32[]")
     }
 
+    /// Add a `int64[]` parameter.
     pub fn add_int64_array(&mut self) -> &mut Self {
         self.add_param_type("int64[]")
     }
 
+    /// Add a `int256[]` parameter.
     pub fn add_int256_array(&mut self) -> &mut Self {
         self.add_param_type("int256[]")
     }
 
+    /// Add a `uint8[]` parameter.
     pub fn add_uint8_array(&mut self) -> &mut Self {
         self.add_param_type("uint8[]")
     }
 
+    /// Add a `uint16[]` parameter.
     pub fn add_uint16_array(&mut self) -> &mut Self {
         self.add_param_type("uint16[]")
     }
 
+    /// Add a `uint32[]` parameter.
     pub fn add_uint32_array(&mut self) -> &mut Self {
         self.add_param_type("uint32[]")
     }
 
+    /// Add a `uint64[]` parameter.
     pub fn add_uint64_array(&mut self) -> &mut Self {
         self.add_param_type("uint64[]")
     }
 
+    /// Add a `uint256[]` parameter.
     pub fn add_uint256_array(&mut self) -> &mut Self {
         self.add_param_type("uint256[]")
     }
 
+    /// Add a `address` parameter.
     pub fn add_address(&mut self) -> &mut Self {
         self.add_param_type("address")
     }
 
+    /// Add a `address[]` parameter.
     pub fn add_address_array(&mut self) -> &mut Self {
         self.add_param_type("address[]")
     }
 
+    /// Add a `function` parameter.
     pub fn add_function(&mut self) -> &mut Self {
         self.add_param_type("function")
     }
+
+    /// Add a tuple (struct) parameter with fields of the given `types`, like `(address,uint256)`.
+    pub fn add_tuple(&mut self, types: &[AbiType]) -> &mut Self {
+        self.add_abi_type(AbiType::Tuple(types.to_vec()))
+    }
+
+    /// Add an array of tuples (structs) parameter with fields of the given `types`, like `(address,uint256)[]`.
+    pub fn add_tuple_array(&mut self, types: &[AbiType]) -> &mut Self {
+        self.add_abi_type(AbiType::Array(Box::new(AbiType::Tuple(types.to_vec()))))
+    }
+
+    /// Add a fixed-length array parameter of `len` elements of `element_type`, like `uint256[3]`.
+    pub fn add_fixed_array(&mut self, element_type: &AbiType, len: usize) -> &mut Self {
+        self.add_abi_type(AbiType::FixedArray(Box::new(element_type.clone()), len))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::ContractFunctionSelector;
+    use crate::AbiType;
 
     #[test]
     fn selector() {
@@ -219,6 +335,87 @@ mod tests {
         assert_eq!(hex::encode(signature), "4438e4ce");
     }
 
+    #[test]
+    fn tuple_and_fixed_array_selector() {
+        let signature = ContractFunctionSelector::new("swap")
+            .add_tuple_array(&[AbiType::Address, AbiType::Uint(256)])
+            .add_fixed_array(&AbiType::Uint(256), 3)
+            .add_tuple(&[AbiType::Bool, AbiType::Tuple(vec![AbiType::String])])
+            .finish();
+
+        assert_eq!(
+            signature,
+            ContractFunctionSelector::new("swap")
+                .add_param_type("(address,uint256)[]")
+                .add_param_type("uint256[3]")
+                .add_param_type("(bool,(string))")
+                .finish()
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let selector = ContractFunctionSelector::parse(
+            "swap((address token, uint24 fee) calldata, uint[] memory)",
+        )
+        .unwrap();
+
+        assert_eq!(selector.name(), Some("swap"));
+        assert_eq!(selector.signature().unwrap(), "swap((address,uint24),uint256[])");
+        assert_eq!(
+            selector.param_types().unwrap(),
+            [
+                AbiType::Tuple(vec![AbiType::Address, AbiType::Uint(24)]),
+                AbiType::Array(Box::new(AbiType::Uint(256))),
+            ]
+        );
+        assert_eq!(
+            selector.selector(),
+            ContractFunctionSelector::new("swap")
+                .add_tuple(&[AbiType::Address, AbiType::Uint(24)])
+                .add_uint256_array()
+                .finish()
+        );
+    }
+
+    #[test]
+    fn parse_matches_known_selector() {
+        let selector = ContractFunctionSelector::parse("transfer(address, uint)").unwrap();
+
+        assert_eq!(hex::encode(selector.selector()), "a9059cbb");
+    }
+
+    #[test]
+    fn parse_every_width() {
+        for bits in (8..=256).step_by(8) {
+            let selector =
+                ContractFunctionSelector::parse(&format!("f(int{bits},uint{bits})")).unwrap();
+
+            assert_eq!(selector.param_types().unwrap(), [AbiType::Int(bits), AbiType::Uint(bits)]);
+        }
+
+        for len in 1..=32 {
+            let selector = ContractFunctionSelector::parse(&format!("f(bytes{len})")).unwrap();
+
+            assert_eq!(selector.param_types().unwrap(), [AbiType::FixedBytes(len)]);
+        }
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for signature in ["f(uint7)", "f(bytes33)", "f(address indexed)", "f((uint8)", "(uint8)"] {
+            assert!(ContractFunctionSelector::parse(signature).is_err(), "{signature}");
+        }
+    }
+
+    #[test]
+    fn from_bytes() {
+        let selector = ContractFunctionSelector::from([1, 2, 3, 4]);
+
+        assert_eq!(selector.selector(), [1, 2, 3, 4]);
+        assert_eq!(selector.signature(), None);
+    }
+
     #[test]
     #[should_panic]
     fn selector_error() {
@@ -11,6 +11,10 @@ use tonic::transport::Channel;
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
 use crate::staked_id::StakedId;
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -197,7 +201,14 @@ impl ContractUpdateTransaction {
     }
 }
 
-impl TransactionData for ContractUpdateTransactionData {}
+impl TransactionData for ContractUpdateTransactionData {
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        if let Some(memo) = &self.contract_memo {
+            validation::validate_memo("contract_memo", memo, errors);
+        }
+        validation::validate_auto_renew_period("auto_renew_period", self.auto_renew_period, errors);
+    }
+}
 
 impl TransactionExecute for ContractUpdateTransactionData {
     fn execute(
@@ -0,0 +1,671 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::future::Future;
+
+use crate::{
+    Client,
+    ContractCallQuery,
+    ContractCreateFlow,
+    ContractExecuteTransaction,
+    ContractFunctionResult,
+    ContractId,
+    Error,
+    Hbar,
+    Status,
+};
+
+/// The most gas a single transaction can use on the network.
+const MAX_GAS: u64 = 15_000_000;
+
+/// The gas every call uses before running any code.
+const MIN_GAS: u64 = 21_000;
+
+/// Estimates the gas a contract call needs, by simulating it with decreasing amounts of gas.
+///
+/// Calls are simulated with a [`ContractCallQuery`], which is paid for and runs the call without changing state,
+/// or with a mirror node's `/api/v1/contracts/call` endpoint if one is configured, which is free,
+/// and can also simulate payable calls, calls that change state, and contract creation.
+///
+/// Simulating with a mirror node needs the `serde` feature (enabled by default),
+/// without it [`mirror_node_url`](Self::mirror_node_url) isn't available.
+///
+/// The minimal gas that succeeds is found with a binary search, and a safety margin is added on top,
+/// since the gas a call uses depends on the state of the network when it's executed.
+/// The search makes at most [`max_simulations`](Self::max_simulations) simulations,
+/// so without a mirror node, an estimate costs at most that many queries.
+#[derive(Debug, Clone)]
+pub struct GasEstimator {
+    safety_margin: u32,
+    max_gas: u64,
+    max_simulations: u32,
+    mirror_node_url: Option<String>,
+}
+
+impl Default for GasEstimator {
+    fn default() -> Self {
+        Self { safety_margin: 20, max_gas: MAX_GAS, max_simulations: 12, mirror_node_url: None }
+    }
+}
+
+impl GasEstimator {
+    /// Create a new `GasEstimator` ready for configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the percentage added to the minimal gas that succeeds.
+    #[must_use]
+    pub fn get_safety_margin(&self) -> u32 {
+        self.safety_margin
+    }
+
+    /// Sets the percentage added to the minimal gas that succeeds.
+    ///
+    /// Defaults to 20%.
+    pub fn safety_margin(&mut self, percent: u32) -> &mut Self {
+        self.safety_margin = percent;
+        self
+    }
+
+    /// Returns the most gas a call is simulated with, and that an estimate can be.
+    #[must_use]
+    pub fn get_max_gas(&self) -> u64 {
+        self.max_gas
+    }
+
+    /// Sets the most gas a call is simulated with, and that an estimate can be.
+    ///
+    /// Defaults to 15 million, the most a transaction can use.
+    pub fn max_gas(&mut self, max_gas: u64) -> &mut Self {
+        self.max_gas = max_gas;
+        self
+    }
+
+    /// Returns the most simulations an estimate makes.
+    #[must_use]
+    pub fn get_max_simulations(&self) -> u32 {
+        self.max_simulations
+    }
+
+    /// Sets the most simulations an estimate makes, at least 1.
+    ///
+    /// Once they're used up, the least gas that succeeded so far is the estimate,
+    /// which is within 1% of the minimum when the search finishes before that.
+    ///
+    /// Defaults to 12, which finds calls that need more than a million gas within 1%,
+    /// and others within a few percent, which the default safety margin covers.
+    pub fn max_simulations(&mut self, max_simulations: u32) -> &mut Self {
+        self.max_simulations = max_simulations;
+        self
+    }
+
+    /// Returns the URL of the mirror node calls are simulated with.
+    #[must_use]
+    pub fn get_mirror_node_url(&self) -> Option<&str> {
+        self.mirror_node_url.as_deref()
+    }
+
+    /// Sets the URL of the mirror node calls are simulated with, like `https://testnet.mirrornode.hedera.com`.
+    ///
+    /// Without one, calls are simulated with a [`ContractCallQuery`] to the consensus nodes.
+    ///
+    /// Only available with the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn mirror_node_url(&mut self, url: impl Into<String>) -> &mut Self {
+        self.mirror_node_url = Some(url.into());
+        self
+    }
+
+    /// Estimate the gas needed to execute `transaction`, sent by the client's operator.
+    ///
+    /// # Errors
+    /// - [`Error::GasEstimate`] if the call fails even with the maximum gas,
+    ///   or it's payable and there's no mirror node to simulate it with.
+    /// - Any error from simulating the call, such as a pre-check status of
+    ///   [`ContractRevertExecuted`](Status::ContractRevertExecuted) if it reverts.
+    pub async fn estimate(
+        &self,
+        client: &Client,
+        transaction: &ContractExecuteTransaction,
+    ) -> crate::Result<GasEstimate> {
+        let contract_id = transaction
+            .get_contract_id()
+            .ok_or_else(|| Error::gas_estimate("the transaction has no contract ID"))?;
+
+        let call = Call {
+            contract_id: Some(contract_id),
+            data: transaction.get_function_parameters(),
+            value: transaction.get_payable_amount(),
+        };
+
+        self.estimate_call(client, &call).await
+    }
+
+    /// Estimate the gas needed to create the contract in `flow`, sent by the client's operator.
+    ///
+    /// Creation can only be simulated by a mirror node,
+    /// so this needs the `serde` feature and a [`mirror_node_url`](Self::mirror_node_url).
+    ///
+    /// # Errors
+    /// - [`Error::GasEstimate`] if there's no mirror node, or creation fails even with the maximum gas.
+    /// - Any error from simulating the creation.
+    pub async fn estimate_create(
+        &self,
+        client: &Client,
+        flow: &ContractCreateFlow,
+    ) -> crate::Result<GasEstimate> {
+        let data = [flow.get_bytecode(), flow.get_constructor_parameters()].concat();
+
+        let call = Call { contract_id: None, data: &data, value: flow.get_initial_balance() };
+
+        self.estimate_call(client, &call).await
+    }
+
+    pub(crate) async fn estimate_call(
+        &self,
+        client: &Client,
+        call: &Call<'_>,
+    ) -> crate::Result<GasEstimate> {
+        #[cfg(feature = "serde")]
+        if let Some(url) = &self.mirror_node_url {
+            let mirror = &mirror::Mirror::new(url)?;
+
+            return self.search(move |gas| mirror.simulate(client, call, gas)).await;
+        }
+
+        let contract_id = call.contract_id.ok_or_else(|| {
+            Error::gas_estimate("contract creation can only be simulated by a mirror node")
+        })?;
+
+        if call.value != Hbar::ZERO {
+            return Err(Error::gas_estimate(
+                "payable calls can only be simulated by a mirror node",
+            ));
+        }
+
+        self.search(move |gas| simulate_query(client, contract_id, call.data, gas)).await
+    }
+
+    /// Finds the minimal gas `simulate` succeeds with.
+    async fn search<F, Fut>(&self, mut simulate: F) -> crate::Result<GasEstimate>
+    where
+        F: FnMut(u64) -> Fut + Send,
+        Fut: Future<Output = crate::Result<Simulation>> + Send,
+    {
+        // note: the network reports at least 80% of the gas limit as used,
+        // so the gas used by a simulation says nothing about the minimum.
+        let mut low = MIN_GAS.min(self.max_gas);
+        let mut high = self.max_gas;
+        let mut result = None;
+        let mut simulations = 0;
+
+        // invariant: the call succeeds with `high` gas and `result` is from that call,
+        // or `result` is `None` and `high` is the maximum gas, which hasn't been simulated yet.
+        // the search stops within 1% of the minimum, which the safety margin covers.
+        loop {
+            // one simulation is kept to try the maximum gas if nothing succeeded.
+            let remaining = self.max_simulations.saturating_sub(u32::from(result.is_none()));
+
+            if low >= high || (high - low) * 100 <= high || simulations >= remaining {
+                break;
+            }
+
+            let gas = low + (high - low) / 2;
+            simulations += 1;
+
+            match simulate(gas).await? {
+                Simulation::Success(it) => {
+                    high = gas;
+                    result = Some(it);
+                }
+                Simulation::InsufficientGas => low = gas + 1,
+            }
+        }
+
+        let result = match result {
+            Some(result) => result,
+            None => match simulate(high).await? {
+                Simulation::Success(result) => result,
+                Simulation::InsufficientGas => {
+                    return Err(Error::gas_estimate(format!(
+                        "the call runs out of gas even with the maximum of {}",
+                        self.max_gas
+                    )))
+                }
+            },
+        };
+
+        let margin = high.saturating_mul(u64::from(self.safety_margin)) / 100;
+
+        Ok(GasEstimate {
+            gas: high.saturating_add(margin).min(self.max_gas),
+            minimum_gas: high,
+            result,
+        })
+    }
+}
+
+async fn simulate_query(
+    client: &Client,
+    contract_id: ContractId,
+    data: &[u8],
+    gas: u64,
+) -> crate::Result<Simulation> {
+    let result = ContractCallQuery::new()
+        .contract_id(contract_id)
+        .gas(gas)
+        .function_parameters(data.to_vec())
+        .execute(client)
+        .await;
+
+    match result {
+        Ok(result) => Ok(Simulation::Success(result)),
+        Err(
+            Error::QueryPreCheckStatus { status: Status::InsufficientGas, .. }
+            | Error::QueryPaymentPreCheckStatus { status: Status::InsufficientGas, .. },
+        ) => Ok(Simulation::InsufficientGas),
+        Err(error) => Err(error),
+    }
+}
+
+/// The gas a contract call needs, estimated by a [`GasEstimator`].
+#[derive(Debug, Clone)]
+pub struct GasEstimate {
+    /// The gas to execute the call with, which is the minimum plus the safety margin.
+    pub gas: u64,
+
+    /// The minimal gas the call succeeded with.
+    pub minimum_gas: u64,
+
+    /// The result of simulating the call with the minimal gas.
+    ///
+    /// Results from a mirror node only have the [`contract_id`](ContractFunctionResult::contract_id)
+    /// (`0.0.0` for contract creation), the returned [`bytes`](ContractFunctionResult::bytes)
+    /// and the [`gas`](ContractFunctionResult::gas) the call was simulated with.
+    pub result: ContractFunctionResult,
+}
+
+/// A contract call to simulate, or contract creation if there's no contract.
+pub(crate) struct Call<'a> {
+    pub(crate) contract_id: Option<ContractId>,
+    pub(crate) data: &'a [u8],
+    pub(crate) value: Hbar,
+}
+
+#[cfg(any(feature = "serde", test))]
+impl Call<'_> {
+    /// The result of simulating `self` with `gas`, for simulations that only return the output.
+    fn result(&self, bytes: Vec<u8>, gas: u64) -> ContractFunctionResult {
+        ContractFunctionResult {
+            contract_id: self.contract_id.unwrap_or_else(|| ContractId::new(0, 0, 0)),
+            evm_address: None,
+            bytes,
+            error_message: None,
+            bloom: Vec::new(),
+            gas_used: 0,
+            gas,
+            hbar_amount: 0,
+            contract_function_parameters_bytes: self.data.to_vec(),
+            sender_account_id: None,
+            logs: Vec::new(),
+            contract_nonces: Vec::new(),
+            signer_nonce: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Simulation {
+    Success(ContractFunctionResult),
+    InsufficientGas,
+}
+
+#[cfg(feature = "serde")]
+mod mirror {
+    use bytes::Bytes;
+    use http_body_util::{
+        BodyExt,
+        Full,
+    };
+    use hyper::StatusCode;
+    use hyper_openssl::client::legacy::HttpsConnector;
+    use hyper_util::client::legacy::connect::HttpConnector;
+    use hyper_util::rt::TokioExecutor;
+    use openssl::ssl::{
+        SslConnector,
+        SslMethod,
+    };
+
+    use super::{
+        Call,
+        Simulation,
+    };
+    use crate::{
+        Client,
+        ContractRevertReason,
+        Error,
+    };
+
+    /// A mirror node to simulate calls with, which keeps its connections for the whole estimate.
+    pub(super) struct Mirror {
+        endpoint: String,
+        client: hyper_util::client::legacy::Client<HttpsConnector<HttpConnector>, Full<Bytes>>,
+    }
+
+    impl Mirror {
+        pub(super) fn new(url: &str) -> crate::Result<Self> {
+            let mut http = HttpConnector::new();
+            http.enforce_http(false);
+
+            let ssl = SslConnector::builder(SslMethod::tls()).map_err(Error::gas_estimate)?;
+            let https = HttpsConnector::with_connector(http, ssl).map_err(Error::gas_estimate)?;
+
+            Ok(Self {
+                endpoint: format!("{}/api/v1/contracts/call", url.trim_end_matches('/')),
+                client: hyper_util::client::legacy::Client::builder(TokioExecutor::new())
+                    .build(https),
+            })
+        }
+
+        pub(super) async fn simulate(
+            &self,
+            client: &Client,
+            call: &Call<'_>,
+            gas: u64,
+        ) -> crate::Result<Simulation> {
+            let mut body = serde_json::json!({
+                "block": "latest",
+                "data": format!("0x{}", hex::encode(call.data)),
+                "estimate": false,
+                "gas": gas,
+                "value": call.value.to_tinybars(),
+            });
+
+            if let Some(contract_id) = call.contract_id {
+                body["to"] = format!("0x{}", contract_id.to_solidity_address()?).into();
+            }
+
+            if let Some(account_id) = client.get_operator_account_id() {
+                body["from"] = format!("0x{}", account_id.to_solidity_address()?).into();
+            }
+
+            let (status, response) = self.post(body.to_string()).await?;
+
+            parse(call, gas, status, &response)
+        }
+
+        async fn post(&self, body: String) -> crate::Result<(StatusCode, Bytes)> {
+            let request = hyper::Request::post(&self.endpoint)
+                .header(hyper::header::CONTENT_TYPE, "application/json")
+                .body(Full::new(Bytes::from(body)))
+                .map_err(Error::gas_estimate)?;
+
+            let response = self.client.request(request).await.map_err(Error::gas_estimate)?;
+            let status = response.status();
+
+            let body =
+                response.into_body().collect().await.map_err(Error::gas_estimate)?.to_bytes();
+
+            Ok((status, body))
+        }
+    }
+
+    /// Reads the mirror node's `response` to simulating `call` with `gas`.
+    pub(super) fn parse(
+        call: &Call<'_>,
+        gas: u64,
+        status: StatusCode,
+        response: &[u8],
+    ) -> crate::Result<Simulation> {
+        let response: serde_json::Value =
+            serde_json::from_slice(response).map_err(Error::gas_estimate)?;
+
+        if status.is_success() {
+            let bytes = response["result"]
+                .as_str()
+                .and_then(decode_hex)
+                .ok_or_else(|| Error::gas_estimate("the mirror node returned no result"))?;
+
+            return Ok(Simulation::Success(call.result(bytes, gas)));
+        }
+
+        let message = &response["_status"]["messages"][0];
+
+        match message["message"].as_str() {
+            Some("INSUFFICIENT_GAS") => Ok(Simulation::InsufficientGas),
+            Some("CONTRACT_REVERT_EXECUTED") => {
+                let data = message["data"].as_str().and_then(decode_hex).unwrap_or_default();
+
+                Err(Error::gas_estimate(format!(
+                    "the call {}",
+                    ContractRevertReason::decode(&data, &[])
+                )))
+            }
+            Some(error) => Err(Error::gas_estimate(format!(
+                "the mirror node failed the call with `{error}`: {}",
+                message["detail"].as_str().unwrap_or_default()
+            ))),
+            None => Err(Error::gas_estimate(format!("the mirror node responded with {status}"))),
+        }
+    }
+
+    fn decode_hex(it: &str) -> Option<Vec<u8>> {
+        hex::decode(it.strip_prefix("0x").unwrap_or(it)).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::ready;
+
+    use assert_matches::assert_matches;
+
+    use super::{
+        Call,
+        GasEstimator,
+        Simulation,
+    };
+    use crate::{
+        ContractId,
+        Error,
+        Hbar,
+    };
+
+    const CALL: Call<'static> = Call {
+        contract_id: Some(ContractId::new(0, 0, 5005)),
+        data: &[1, 2, 3],
+        value: Hbar::ZERO,
+    };
+
+    /// Simulates a call that needs `needed` gas.
+    fn needs(
+        needed: u64,
+    ) -> impl FnMut(u64) -> std::future::Ready<crate::Result<Simulation>> + Send {
+        move |gas| {
+            ready(Ok(match gas >= needed {
+                true => Simulation::Success(CALL.result(Vec::new(), gas)),
+                false => Simulation::InsufficientGas,
+            }))
+        }
+    }
+
+    #[test]
+    fn defaults() {
+        let estimator = GasEstimator::new();
+
+        assert_eq!(estimator.get_safety_margin(), 20);
+        assert_eq!(estimator.get_max_gas(), 15_000_000);
+        assert_eq!(estimator.get_max_simulations(), 12);
+        assert_eq!(estimator.get_mirror_node_url(), None);
+    }
+
+    #[tokio::test]
+    async fn search() {
+        let estimate =
+            GasEstimator::new().max_simulations(20).search(needs(100_000)).await.unwrap();
+
+        // within the 1% the search stops at.
+        assert!((100_000..=101_010).contains(&estimate.minimum_gas), "{}", estimate.minimum_gas);
+        assert_eq!(estimate.result.gas, estimate.minimum_gas);
+        assert_eq!(estimate.gas, estimate.minimum_gas + estimate.minimum_gas / 5);
+    }
+
+    #[tokio::test]
+    async fn search_below_gas_used() {
+        // the network reports at least 80% of the limit as used, which must not bound the search.
+        let estimate = GasEstimator::new().max_simulations(20).search(needs(21_000)).await.unwrap();
+
+        assert!((21_000..=21_212).contains(&estimate.minimum_gas), "{}", estimate.minimum_gas);
+    }
+
+    #[tokio::test]
+    async fn search_max_simulations() {
+        let mut simulations = Vec::new();
+        let mut simulate = needs(21_000);
+
+        let estimate = GasEstimator::new()
+            .search(|gas| {
+                simulations.push(gas);
+                simulate(gas)
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(simulations.len(), 12);
+        assert!(estimate.minimum_gas >= 21_000);
+
+        // the first success bounds the search, so the maximum gas is never simulated when something succeeds.
+        assert!(!simulations.contains(&15_000_000));
+    }
+
+    #[tokio::test]
+    async fn search_single_simulation() {
+        let mut simulations = Vec::new();
+        let mut simulate = needs(100_000);
+
+        let estimate = GasEstimator::new()
+            .max_simulations(1)
+            .search(|gas| {
+                simulations.push(gas);
+                simulate(gas)
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(simulations, [15_000_000]);
+        assert_eq!(estimate.minimum_gas, 15_000_000);
+    }
+
+    #[tokio::test]
+    async fn search_safety_margin() {
+        let estimate = GasEstimator::new().safety_margin(50).search(needs(100_000)).await.unwrap();
+
+        assert_eq!(estimate.gas, estimate.minimum_gas + estimate.minimum_gas / 2);
+    }
+
+    #[tokio::test]
+    async fn search_caps_at_max_gas() {
+        let estimate = GasEstimator::new().max_gas(110_000).search(needs(100_000)).await.unwrap();
+
+        assert!((100_000..=101_010).contains(&estimate.minimum_gas), "{}", estimate.minimum_gas);
+        assert_eq!(estimate.gas, 110_000);
+    }
+
+    #[tokio::test]
+    async fn search_over_max_gas() {
+        let error = GasEstimator::new().max_gas(50_000).search(needs(100_000)).await.unwrap_err();
+
+        assert_matches!(error, Error::GasEstimate(_));
+    }
+
+    #[tokio::test]
+    async fn search_error() {
+        let error = GasEstimator::new()
+            .search(|_| ready(Err(Error::gas_estimate("nope"))))
+            .await
+            .unwrap_err();
+
+        assert_matches!(error, Error::GasEstimate(it) if it.to_string() == "nope");
+    }
+
+    #[cfg(feature = "serde")]
+    mod mirror {
+        use assert_matches::assert_matches;
+        use hyper::StatusCode;
+
+        use super::CALL;
+        use crate::contract::gas_estimator::mirror::parse;
+        use crate::contract::gas_estimator::Simulation;
+        use crate::Error;
+
+        fn error_response(message: &str, data: &str) -> String {
+            format!(
+                r#"{{"_status":{{"messages":[{{"message":"{message}","detail":"","data":"{data}"}}]}}}}"#
+            )
+        }
+
+        #[test]
+        fn success() {
+            let simulation =
+                parse(&CALL, 50_000, StatusCode::OK, br#"{"result":"0x0102"}"#).unwrap();
+
+            let result = assert_matches!(simulation, Simulation::Success(it) => it);
+
+            assert_eq!(result.bytes, [1, 2]);
+            assert_eq!(result.gas, 50_000);
+            assert_eq!(result.contract_id, CALL.contract_id.unwrap());
+        }
+
+        #[test]
+        fn missing_result() {
+            let error = parse(&CALL, 50_000, StatusCode::OK, b"{}").unwrap_err();
+
+            assert_matches!(
+                error,
+                Error::GasEstimate(it) if it.to_string() == "the mirror node returned no result"
+            );
+        }
+
+        #[test]
+        fn insufficient_gas() {
+            let response = error_response("INSUFFICIENT_GAS", "0x");
+
+            assert_matches!(
+                parse(&CALL, 50_000, StatusCode::BAD_REQUEST, response.as_bytes()),
+                Ok(Simulation::InsufficientGas)
+            );
+        }
+
+        #[test]
+        fn revert() {
+            // `Error("nope")`
+            let data = concat!(
+                "0x08c379a0",
+                "0000000000000000000000000000000000000000000000000000000000000020",
+                "0000000000000000000000000000000000000000000000000000000000000004",
+                "6e6f706500000000000000000000000000000000000000000000000000000000",
+            );
+
+            let response = error_response("CONTRACT_REVERT_EXECUTED", data);
+
+            let error =
+                parse(&CALL, 50_000, StatusCode::BAD_REQUEST, response.as_bytes()).unwrap_err();
+
+            assert_matches!(
+                error,
+                Error::GasEstimate(it) if it.to_string() == "the call reverted with `nope`"
+            );
+        }
+
+        #[test]
+        fn other_error() {
+            let error = parse(&CALL, 50_000, StatusCode::NOT_FOUND, b"{}").unwrap_err();
+
+            assert_matches!(
+                error,
+                Error::GasEstimate(it) if it.to_string() == "the mirror node responded with 404 Not Found"
+            );
+        }
+    }
+}
@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
+pub(crate) mod abi;
+mod compiled_contract;
 mod contract_bytecode_query;
 mod contract_call_query;
 mod contract_create_flow;
@@ -16,7 +18,27 @@ mod contract_log_info;
 mod contract_nonce_info;
 mod contract_update_transaction;
 mod delegate_contract_id;
+mod gas_estimator;
 
+pub use abi::{
+    AbiConstructor,
+    AbiError,
+    AbiEvent,
+    AbiEventParam,
+    AbiFunction,
+    AbiParam,
+    AbiStateMutability,
+    AbiType,
+    AbiValue,
+    ContractAbi,
+    ContractEvent,
+    ContractRevertReason,
+    PanicCode,
+};
+pub use compiled_contract::{
+    CompiledContract,
+    ContractVerification,
+};
 pub use contract_bytecode_query::ContractBytecodeQuery;
 pub(crate) use contract_bytecode_query::ContractBytecodeQueryData;
 pub use contract_call_query::ContractCallQuery;
@@ -30,6 +52,7 @@ pub use contract_execute_transaction::ContractExecuteTransaction;
 pub(crate) use contract_execute_transaction::ContractExecuteTransactionData;
 pub use contract_function_parameters::ContractFunctionParameters;
 pub use contract_function_result::ContractFunctionResult;
+pub use contract_function_selector::ContractFunctionSelector;
 pub use contract_id::ContractId;
 pub use contract_info::ContractInfo;
 pub use contract_info_query::ContractInfoQuery;
@@ -39,3 +62,7 @@ pub use contract_nonce_info::ContractNonceInfo;
 pub use contract_update_transaction::ContractUpdateTransaction;
 pub(crate) use contract_update_transaction::ContractUpdateTransactionData;
 pub use delegate_contract_id::DelegateContractId;
+pub use gas_estimator::{
+    GasEstimate,
+    GasEstimator,
+};
@@ -2,13 +2,21 @@
 
 use std::error::Error as StdError;
 use std::result::Result as StdResult;
+use std::sync::Arc;
 
 use crate::entity_id::Checksum;
 use crate::{
     AccountId,
+    ContractFunctionResult,
+    ContractRevertReason,
+    FileHash,
+    FileId,
     Hbar,
     Status,
+    TopicId,
+    TopicMessage,
     TransactionId,
+    ValidationError,
 };
 
 /// `Result<T, Error>`
@@ -185,9 +193,153 @@ pub enum Error {
         transaction_id: Option<Box<TransactionId>>,
     },
 
+    /// A contract reverted, and the [`TransactionRecord`](crate::TransactionRecord) says why.
+    ///
+    /// `error` is how the revert is reported otherwise: an [`Error::ReceiptStatus`]
+    /// with the status [`ContractRevertExecuted`](Status::ContractRevertExecuted).
+    #[error("{error} ({reason})")]
+    ContractRevert {
+        /// The error that reported the revert.
+        error: Box<Error>,
+        /// Why the contract reverted.
+        ///
+        /// Only `Error(string)` and `Panic(uint256)` are decoded, since the contract's custom errors aren't known here,
+        /// a custom error is [`Unknown`](ContractRevertReason::Unknown) until it's decoded again
+        /// with [`ContractRevertReason::with_custom_errors`].
+        reason: Box<ContractRevertReason>,
+    },
+
     /// Failed to verify a signature.
     #[error("failed to verify a signature: {0}")]
     SignatureVerify(#[source] BoxStdError),
+
+    /// A transaction failed local validation.
+    ///
+    /// See [`Transaction::validate`](crate::Transaction::validate).
+    #[error("transaction failed validation: {}", display_validation_errors(.0))]
+    TransactionValidation(Vec<ValidationError>),
+
+    /// The data of a chunked transaction doesn't fit in its chunks.
+    ///
+    /// See [`Transaction::max_chunks`](crate::Transaction::max_chunks) and [`Transaction::chunk_size`](crate::Transaction::chunk_size).
+    #[error("message of {len} bytes is larger than the maximum of {max} bytes (`max_chunks * chunk_size`)")]
+    MessageTooLarge {
+        /// The length of the data, in bytes.
+        len: usize,
+
+        /// The maximum length of the data, in bytes.
+        max: usize,
+    },
+
+    /// A [`ChunkedExecution`](crate::ChunkedExecution) was resumed with a transaction that has a different number of chunks.
+    #[error("`ChunkedExecution` is for a transaction with {expected} chunks, but this transaction has {actual}")]
+    ChunkCountMismatch {
+        /// The number of chunks the `ChunkedExecution` was started with.
+        expected: usize,
+
+        /// The number of chunks of the transaction.
+        actual: usize,
+    },
+
+    /// The contents of a file don't have the expected SHA-384 hash.
+    #[error("contents of file `{file_id}` have hash `{actual}`, but `{expected}` was expected")]
+    FileHashMismatch {
+        /// The file whose contents were checked.
+        file_id: Box<FileId>,
+
+        /// The hash the contents were expected to have.
+        expected: Box<FileHash>,
+
+        /// The hash the contents actually have.
+        actual: Box<FileHash>,
+    },
+
+    /// An I/O error occurred, for instance while writing a downloaded file.
+    #[error("i/o error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Failed to estimate a transaction fee offline.
+    #[error("failed to estimate fee: {0}")]
+    FeeEstimate(#[source] BoxStdError),
+
+    /// A topic subscription that needs a topic ID was started without one,
+    /// see [`TopicMessageQuery::subscribe_with_checkpoints`](crate::TopicMessageQuery::subscribe_with_checkpoints).
+    #[error("topic ID must be set to subscribe with checkpoints")]
+    TopicIdUnset,
+
+    /// Failed to load or save a topic checkpoint.
+    #[error("topic checkpoint store failed: {0}")]
+    TopicCheckpointStore(#[source] BoxStdError),
+
+    /// A message from a topic subscription failed verification, see [`TopicMessageQuery::verify_sequence_numbers`](crate::TopicMessageQuery::verify_sequence_numbers).
+    #[error("topic message failed verification: {0}")]
+    TopicMessageVerification(#[from] TopicMessageVerificationError),
+
+    /// A chunked topic message was given up on before all of its chunks were received,
+    /// see [`IncompleteTopicMessagePolicy::Fail`](crate::IncompleteTopicMessagePolicy::Fail).
+    #[error("{}", display_incomplete_topic_message(.0))]
+    IncompleteTopicMessage(Box<TopicMessage>),
+
+    /// Failed to encode a typed topic message, see [`TopicPublisher`](crate::TopicPublisher).
+    #[error("failed to encode topic message: {0}")]
+    TopicMessageEncode(#[source] BoxStdError),
+
+    /// Failed to decode a typed topic message, see [`TopicSubscriber`](crate::TopicSubscriber).
+    #[error("failed to decode topic message: {0}")]
+    TopicMessageDecode(#[source] BoxStdError),
+
+    /// Failed to encrypt a topic message, see [`EncryptedTopicPublisher`](crate::EncryptedTopicPublisher).
+    #[error("failed to encrypt topic message: {0}")]
+    TopicMessageEncrypt(#[source] BoxStdError),
+
+    /// Failed to decrypt a topic message, see [`EncryptedTopicSubscriber`](crate::EncryptedTopicSubscriber).
+    #[error("failed to decrypt topic message: {0}")]
+    TopicMessageDecrypt(#[source] BoxStdError),
+
+    /// A subscription shared through a [`TopicSubscriptionManager`](crate::TopicSubscriptionManager) failed.
+    ///
+    /// The manager resubscribes after most failures, so more messages may follow.
+    #[error("subscription to topic `{topic_id}` failed: {error}")]
+    TopicSubscription {
+        /// The topic that was subscribed to.
+        topic_id: Box<TopicId>,
+
+        /// Why the subscription failed, shared by every consumer of the topic.
+        #[source]
+        error: Arc<Error>,
+    },
+
+    /// A consumer of a [`TopicSubscriptionManager`](crate::TopicSubscriptionManager) subscription
+    /// fell behind the others and missed messages.
+    #[error("subscription to topic `{topic_id}` fell behind and skipped {skipped} messages")]
+    TopicSubscriptionLagged {
+        /// The topic that was subscribed to.
+        topic_id: Box<TopicId>,
+
+        /// The number of messages that were skipped.
+        skipped: u64,
+    },
+
+    /// Failed to encode values for a contract call, see [`ContractAbi`](crate::ContractAbi).
+    #[error("failed to ABI encode: {0}")]
+    AbiEncode(#[source] BoxStdError),
+
+    /// Failed to decode values returned by a contract call, see [`ContractAbi`](crate::ContractAbi).
+    #[error("failed to ABI decode: {0}")]
+    AbiDecode(#[source] BoxStdError),
+
+    /// Failed to sign an ethereum transaction, see [`EthereumDataBuilder`](crate::EthereumDataBuilder).
+    #[error("failed to sign ethereum transaction: {0}")]
+    EthereumSign(#[source] BoxStdError),
+
+    /// Failed to estimate the gas a contract call needs, see [`GasEstimator`](crate::GasEstimator).
+    #[error("failed to estimate gas: {0}")]
+    GasEstimate(#[source] BoxStdError),
+
+    /// A transaction with [`auto_gas`](crate::ContractExecuteTransaction::auto_gas) was frozen, serialized,
+    /// or scheduled before its gas was estimated, which only happens when it's executed.
+    #[error("the gas of a transaction with `auto_gas` must be estimated by executing it before it's frozen")]
+    GasNotEstimated,
 }
 
 impl Error {
@@ -210,6 +362,113 @@ impl Error {
     pub(crate) fn signature_verify(error: impl Into<BoxStdError>) -> Self {
         Self::SignatureVerify(error.into())
     }
+
+    pub(crate) fn fee_estimate(error: impl Into<BoxStdError>) -> Self {
+        Self::FeeEstimate(error.into())
+    }
+
+    pub(crate) fn abi_encode(error: impl Into<BoxStdError>) -> Self {
+        Self::AbiEncode(error.into())
+    }
+
+    pub(crate) fn abi_decode(error: impl Into<BoxStdError>) -> Self {
+        Self::AbiDecode(error.into())
+    }
+
+    pub(crate) fn ethereum_sign(error: impl Into<BoxStdError>) -> Self {
+        Self::EthereumSign(error.into())
+    }
+
+    pub(crate) fn gas_estimate(error: impl Into<BoxStdError>) -> Self {
+        Self::GasEstimate(error.into())
+    }
+
+    /// Wraps a `ContractRevertExecuted` receipt status in an [`Error::ContractRevert`], if `result` says why it reverted.
+    pub(crate) fn with_revert_reason(self, result: Option<&ContractFunctionResult>) -> Self {
+        if !matches!(self, Self::ReceiptStatus { status: Status::ContractRevertExecuted, .. }) {
+            return self;
+        }
+
+        // note: custom errors are left for the caller to decode, see `ContractRevert::reason`.
+        match result.and_then(|it| it.revert_reason(&[])) {
+            Some(reason) => {
+                Self::ContractRevert { error: Box::new(self), reason: Box::new(reason) }
+            }
+            None => self,
+        }
+    }
+}
+
+impl From<hedera_contract_abi::ParseError> for Error {
+    fn from(error: hedera_contract_abi::ParseError) -> Self {
+        Self::basic_parse(error)
+    }
+}
+
+fn display_validation_errors(errors: &[ValidationError]) -> String {
+    errors.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+}
+
+fn display_incomplete_topic_message(message: &TopicMessage) -> String {
+    let transaction_id = message.transaction.map_or_else(String::new, |it| format!(" `{it}`"));
+
+    match &message.incomplete {
+        Some(it) => format!(
+            "chunked topic message{transaction_id} is missing chunks {:?} of {} ({:?})",
+            it.missing_chunks, it.total_chunks, it.reason
+        ),
+        None => format!("chunked topic message{transaction_id} is incomplete"),
+    }
+}
+
+/// A message from a topic subscription isn't the one that should follow the previous message.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum TopicMessageVerificationError {
+    /// One or more messages are missing before this one.
+    #[error("expected sequence number `{expected}`, found `{actual}` (messages are missing)")]
+    SequenceGap {
+        /// The sequence number that was expected.
+        expected: u64,
+        /// The sequence number that was actually found.
+        actual: u64,
+    },
+
+    /// This message was already received, or arrived after a message that should follow it.
+    #[error("expected sequence number `{expected}`, found `{actual}` (messages are out of order)")]
+    SequenceOutOfOrder {
+        /// The sequence number that was expected.
+        expected: u64,
+        /// The sequence number that was actually found.
+        actual: u64,
+    },
+
+    /// The running hash of this message doesn't match the one recomputed from the previous message.
+    #[error("running hash of message `{sequence_number}` is `{}`, but `{}` was expected", hex::encode(.actual), hex::encode(.expected))]
+    RunningHashMismatch {
+        /// The sequence number of the message.
+        sequence_number: u64,
+        /// The running hash recomputed from the previous message.
+        expected: Vec<u8>,
+        /// The running hash the message actually has.
+        actual: Vec<u8>,
+    },
+
+    /// The running hash of this message uses a version other than `3`, which can't be recomputed.
+    #[error("running hash of message `{sequence_number}` has unsupported version `{version}`")]
+    UnsupportedRunningHashVersion {
+        /// The sequence number of the message.
+        sequence_number: u64,
+        /// The version of the message's running hash.
+        version: u64,
+    },
+
+    /// This message doesn't say which account paid for it, which its running hash depends on.
+    #[error("message `{sequence_number}` has no payer account ID, so its running hash can't be recomputed")]
+    MissingPayerAccountId {
+        /// The sequence number of the message.
+        sequence_number: u64,
+    },
 }
 
 /// Failed to parse a mnemonic.
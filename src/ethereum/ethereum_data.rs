@@ -6,9 +6,19 @@ use bytes::{
     BufMut,
     BytesMut,
 };
+use k256::ecdsa::{
+    RecoveryId,
+    Signature,
+    VerifyingKey,
+};
 use rlp::Rlp;
+use sha3::Digest;
 
-use crate::Error;
+use crate::{
+    Error,
+    EvmAddress,
+    PublicKey,
+};
 
 /// Data for an [`EthereumTransaction`](crate::EthereumTransaction).
 #[derive(Debug, Clone)]
@@ -17,26 +27,72 @@ pub enum EthereumData {
     /// Data for a legacy ethereum transaction.
     Legacy(LegacyEthereumData),
 
+    /// Data for an Eip 2930 ethereum transaction.
+    Eip2930(Eip2930EthereumData),
+
     /// Data for an Eip 1559 ethereum transaction.
     Eip1559(Eip1559EthereumData),
+
+    /// Data for an Eip 7702 ethereum transaction.
+    Eip7702(Eip7702EthereumData),
 }
 
 impl EthereumData {
     pub(super) fn call_data_mut(&mut self) -> &mut Vec<u8> {
         match self {
             EthereumData::Legacy(it) => &mut it.call_data,
+            EthereumData::Eip2930(it) => &mut it.call_data,
             EthereumData::Eip1559(it) => &mut it.call_data,
+            EthereumData::Eip7702(it) => &mut it.call_data,
+        }
+    }
+
+    /// The raw `to` address, empty if the transaction creates a contract.
+    pub(crate) fn to(&self) -> &[u8] {
+        match self {
+            EthereumData::Legacy(it) => &it.to,
+            EthereumData::Eip2930(it) => &it.to,
+            EthereumData::Eip1559(it) => &it.to,
+            EthereumData::Eip7702(it) => &it.to,
         }
     }
 
-    pub(crate) fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+    /// The raw big-endian value, in weibars.
+    pub(crate) fn value(&self) -> &[u8] {
+        match self {
+            EthereumData::Legacy(it) => &it.value,
+            EthereumData::Eip2930(it) => &it.value,
+            EthereumData::Eip1559(it) => &it.value,
+            EthereumData::Eip7702(it) => &it.value,
+        }
+    }
+
+    /// Deserialize this data from rlp encoded bytes, of any supported transaction type.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the transaction type is unsupported, or decoding the bytes fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
         match bytes.split_first() {
-            // note: eating the 2 here involves a bit of extra work.
+            // note: eating the type byte here involves a bit of extra work.
+            Some((1, bytes)) => Eip2930EthereumData::decode_rlp(&Rlp::new(bytes))
+                .map(Self::Eip2930)
+                .map_err(Error::basic_parse),
+
             Some((2, bytes)) => Eip1559EthereumData::decode_rlp(&Rlp::new(bytes))
                 .map(Self::Eip1559)
                 .map_err(Error::basic_parse),
 
-            Some(_) => Ok(Self::Legacy(LegacyEthereumData::from_bytes(bytes)?)),
+            Some((4, bytes)) => Eip7702EthereumData::decode_rlp(&Rlp::new(bytes))
+                .map(Self::Eip7702)
+                .map_err(Error::basic_parse),
+
+            // legacy transactions don't have a type, they're just an rlp list.
+            Some((0xc0.., _)) => Ok(Self::Legacy(LegacyEthereumData::from_bytes(bytes)?)),
+
+            Some((kind, _)) => Err(Error::basic_parse(format!(
+                "Unsupported ethereum transaction type `{kind:#04x}`"
+            ))),
+
             None => Err(Error::basic_parse("Empty ethereum transaction data")),
         }
     }
@@ -46,7 +102,22 @@ impl EthereumData {
     pub fn to_bytes(&self) -> Vec<u8> {
         match self {
             EthereumData::Legacy(it) => it.to_bytes(),
+            EthereumData::Eip2930(it) => it.to_bytes(),
             EthereumData::Eip1559(it) => it.to_bytes(),
+            EthereumData::Eip7702(it) => it.to_bytes(),
+        }
+    }
+
+    /// Recover the address of the account that signed this transaction.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature is malformed or doesn't match the transaction.
+    pub fn recover_sender(&self) -> crate::Result<EvmAddress> {
+        match self {
+            EthereumData::Legacy(it) => it.recover_sender(),
+            EthereumData::Eip2930(it) => it.recover_sender(),
+            EthereumData::Eip1559(it) => it.recover_sender(),
+            EthereumData::Eip7702(it) => it.recover_sender(),
         }
     }
 }
@@ -147,6 +218,193 @@ impl LegacyEthereumData {
 
         rlp.out().to_vec()
     }
+
+    /// The hash the sender signs, which includes `chain_id` as described by EIP-155 if there is one.
+    pub(super) fn signing_hash(&self, chain_id: Option<&[u8]>) -> [u8; 32] {
+        let mut rlp = rlp::RlpStream::new_list(if chain_id.is_some() { 9 } else { 6 });
+
+        rlp.append(&self.nonce)
+            .append(&self.gas_price)
+            .append(&self.gas_limit)
+            .append(&self.to)
+            .append(&self.value)
+            .append(&self.call_data);
+
+        if let Some(chain_id) = chain_id {
+            rlp.append(&chain_id).append_empty_data().append_empty_data();
+        }
+
+        keccak256(&rlp.out())
+    }
+
+    /// Recover the address of the account that signed this transaction.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature is malformed or doesn't match the transaction.
+    pub fn recover_sender(&self) -> crate::Result<EvmAddress> {
+        let v = uint_from_bytes(&self.v).ok_or_else(|| Error::signature_verify("invalid `v`"))?;
+
+        // `v` is `27 + recovery_id`, or `chain_id * 2 + 35 + recovery_id` with EIP-155.
+        let (chain_id, recovery_id) = match v {
+            27 | 28 => (None, v - 27),
+            35.. => (Some(uint_bytes((v - 35) / 2)), (v - 35) % 2),
+            _ => return Err(Error::signature_verify(format!("invalid `v` of {v}"))),
+        };
+
+        recover_signer(&self.signing_hash(chain_id.as_deref()), recovery_id, &self.r, &self.s)
+    }
+}
+
+/// Data for an Eip 2930 ethereum transaction.
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct Eip2930EthereumData {
+    /// ID of the chain.
+    pub chain_id: Vec<u8>,
+
+    /// Transaction's nonce.
+    pub nonce: Vec<u8>,
+
+    /// Price for 1 gas.
+    pub gas_price: Vec<u8>,
+
+    /// The amount of gas available for the transaction.
+    pub gas_limit: Vec<u8>,
+
+    /// The receiver of the transaction.
+    pub to: Vec<u8>,
+
+    /// The transaction value.
+    pub value: Vec<u8>,
+
+    /// The raw call data.
+    pub call_data: Vec<u8>,
+
+    /// The addresses and storage keys that the transaction plans to access.
+    pub access_list: Vec<AccessListItem>,
+
+    /// Recovery parameter used to ease the signature verification.
+    pub recovery_id: Vec<u8>,
+
+    /// The R value of the signature.
+    pub r: Vec<u8>,
+
+    /// The S value of the signature.
+    pub s: Vec<u8>,
+}
+
+// manual impl of debug for the hex encoding of everything.
+impl fmt::Debug for Eip2930EthereumData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            chain_id,
+            nonce,
+            gas_price,
+            gas_limit,
+            to,
+            value,
+            call_data,
+            access_list,
+            recovery_id,
+            r,
+            s,
+        } = self;
+
+        f.debug_struct("Eip2930EthereumData")
+            .field("chain_id", &hex::encode(chain_id))
+            .field("nonce", &hex::encode(nonce))
+            .field("gas_price", &hex::encode(gas_price))
+            .field("gas_limit", &hex::encode(gas_limit))
+            .field("to", &hex::encode(to))
+            .field("value", &hex::encode(value))
+            .field("call_data", &hex::encode(call_data))
+            .field("access_list", access_list)
+            .field("recovery_id", &hex::encode(recovery_id))
+            .field("r", &hex::encode(r))
+            .field("s", &hex::encode(s))
+            .finish()
+    }
+}
+
+impl Eip2930EthereumData {
+    fn decode_rlp(rlp: &Rlp) -> Result<Self, rlp::DecoderError> {
+        if rlp.item_count()? != 11 {
+            return Err(rlp::DecoderError::RlpIncorrectListLen);
+        }
+
+        Ok(Self {
+            chain_id: rlp.val_at(0)?,
+            nonce: rlp.val_at(1)?,
+            gas_price: rlp.val_at(2)?,
+            gas_limit: rlp.val_at(3)?,
+            to: rlp.val_at(4)?,
+            value: rlp.val_at(5)?,
+            call_data: rlp.val_at(6)?,
+            access_list: rlp.list_at(7)?,
+            recovery_id: rlp.val_at(8)?,
+            r: rlp.val_at(9)?,
+            s: rlp.val_at(10)?,
+        })
+    }
+
+    /// Deserialize this data from rlp encoded bytes.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if decoding the bytes fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        Self::decode_rlp(&typed_rlp(bytes, 0x01)?).map_err(Error::basic_parse)
+    }
+
+    /// Convert this data to rlp encoded bytes.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(0x01);
+        let mut rlp = rlp::RlpStream::new_list_with_buffer(buffer, 11);
+
+        rlp.append(&self.chain_id)
+            .append(&self.nonce)
+            .append(&self.gas_price)
+            .append(&self.gas_limit)
+            .append(&self.to)
+            .append(&self.value)
+            .append(&self.call_data)
+            .append_list::<AccessListItem, _>(self.access_list.as_slice())
+            .append(&self.recovery_id)
+            .append(&self.r)
+            .append(&self.s);
+
+        rlp.out().to_vec()
+    }
+
+    /// The hash the sender signs, which is the hash of the transaction without the signature.
+    pub(super) fn signing_hash(&self) -> [u8; 32] {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(0x01);
+        let mut rlp = rlp::RlpStream::new_list_with_buffer(buffer, 8);
+
+        rlp.append(&self.chain_id)
+            .append(&self.nonce)
+            .append(&self.gas_price)
+            .append(&self.gas_limit)
+            .append(&self.to)
+            .append(&self.value)
+            .append(&self.call_data)
+            .append_list::<AccessListItem, _>(self.access_list.as_slice());
+
+        keccak256(&rlp.out())
+    }
+
+    /// Recover the address of the account that signed this transaction.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature is malformed or doesn't match the transaction.
+    pub fn recover_sender(&self) -> crate::Result<EvmAddress> {
+        let recovery_id = uint_from_bytes(&self.recovery_id)
+            .ok_or_else(|| Error::signature_verify("invalid recovery ID"))?;
+
+        recover_signer(&self.signing_hash(), recovery_id, &self.r, &self.s)
+    }
 }
 
 /// Data for an Eip 1559 ethereum transaction.
@@ -180,7 +438,7 @@ pub struct Eip1559EthereumData {
     pub call_data: Vec<u8>,
 
     /// Specifies an array of addresses and storage keys that the transaction plans to access.
-    pub access_list: Vec<Vec<u8>>,
+    pub access_list: Vec<AccessListItem>,
 
     /// Recovery parameter used to ease the signature verification.
     pub recovery_id: Vec<u8>,
@@ -195,14 +453,6 @@ pub struct Eip1559EthereumData {
 // manual impl of debug for the hex encoding of everything.
 impl fmt::Debug for Eip1559EthereumData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        struct HexList<'a, T: AsRef<[u8]>>(&'a [T]);
-
-        impl<'a, T: AsRef<[u8]>> fmt::Debug for HexList<'a, T> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.debug_list().entries(self.0.iter().map(hex::encode)).finish()
-            }
-        }
-
         let Self {
             chain_id,
             nonce,
@@ -227,7 +477,7 @@ impl fmt::Debug for Eip1559EthereumData {
             .field("to", &hex::encode(to))
             .field("value", &hex::encode(value))
             .field("call_data", &hex::encode(call_data))
-            .field("access_list", &HexList(access_list))
+            .field("access_list", access_list)
             .field("recovery_id", &hex::encode(recovery_id))
             .field("r", &hex::encode(r))
             .field("s", &hex::encode(s))
@@ -262,23 +512,183 @@ impl Eip1559EthereumData {
     /// # Errors
     /// - [`Error::BasicParse`] if decoding the bytes fails.
     pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
-        let (&first, bytes) = bytes
-            .split_first()
-            .ok_or_else(|| Error::basic_parse("Empty ethereum transaction data"))?;
+        Self::decode_rlp(&typed_rlp(bytes, 0x02)?).map_err(Error::basic_parse)
+    }
+
+    /// Convert this data to rlp encoded bytes.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(0x02);
+        let mut rlp = rlp::RlpStream::new_list_with_buffer(buffer, 12);
+
+        rlp.append(&self.chain_id)
+            .append(&self.nonce)
+            .append(&self.max_priority_gas)
+            .append(&self.max_gas)
+            .append(&self.gas_limit)
+            .append(&self.to)
+            .append(&self.value)
+            .append(&self.call_data)
+            .append_list::<AccessListItem, _>(self.access_list.as_slice())
+            .append(&self.recovery_id)
+            .append(&self.r)
+            .append(&self.s);
+
+        rlp.out().to_vec()
+    }
+
+    /// The hash the sender signs, which is the hash of the transaction without the signature.
+    pub(super) fn signing_hash(&self) -> [u8; 32] {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(0x02);
+        let mut rlp = rlp::RlpStream::new_list_with_buffer(buffer, 9);
+
+        rlp.append(&self.chain_id)
+            .append(&self.nonce)
+            .append(&self.max_priority_gas)
+            .append(&self.max_gas)
+            .append(&self.gas_limit)
+            .append(&self.to)
+            .append(&self.value)
+            .append(&self.call_data)
+            .append_list::<AccessListItem, _>(self.access_list.as_slice());
+
+        keccak256(&rlp.out())
+    }
+
+    /// Recover the address of the account that signed this transaction.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature is malformed or doesn't match the transaction.
+    pub fn recover_sender(&self) -> crate::Result<EvmAddress> {
+        let recovery_id = uint_from_bytes(&self.recovery_id)
+            .ok_or_else(|| Error::signature_verify("invalid recovery ID"))?;
+
+        recover_signer(&self.signing_hash(), recovery_id, &self.r, &self.s)
+    }
+}
+
+/// Data for an Eip 7702 ethereum transaction.
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct Eip7702EthereumData {
+    /// ID of the chain.
+    pub chain_id: Vec<u8>,
+
+    /// Transaction's nonce.
+    pub nonce: Vec<u8>,
+
+    /// An 'optional' additional fee in Ethereum that is paid directly to miners in order to incentivize
+    /// them to include your transaction in a block. Not used in Hiero.
+    pub max_priority_gas: Vec<u8>,
+
+    /// The maximum amount, in tinybars, that the payer of the hedera transaction
+    /// is willing to pay to complete the transaction.
+    pub max_gas: Vec<u8>,
+
+    /// The amount of gas available for the transaction.
+    pub gas_limit: Vec<u8>,
+
+    /// The receiver of the transaction.
+    pub to: Vec<u8>,
+
+    /// The transaction value.
+    pub value: Vec<u8>,
+
+    /// The raw call data.
+    pub call_data: Vec<u8>,
+
+    /// Specifies an array of addresses and storage keys that the transaction plans to access.
+    pub access_list: Vec<AccessListItem>,
+
+    /// The authorizations for accounts to delegate their code to a contract.
+    pub authorization_list: Vec<Eip7702Authorization>,
+
+    /// Recovery parameter used to ease the signature verification.
+    pub recovery_id: Vec<u8>,
+
+    /// The R value of the signature.
+    pub r: Vec<u8>,
+
+    /// The S value of the signature.
+    pub s: Vec<u8>,
+}
 
-        if first != 2 {
-            return Err(Error::basic_parse(rlp::DecoderError::Custom("Invalid kind")));
+// manual impl of debug for the hex encoding of everything.
+impl fmt::Debug for Eip7702EthereumData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            chain_id,
+            nonce,
+            max_priority_gas,
+            max_gas,
+            gas_limit,
+            to,
+            value,
+            call_data,
+            access_list,
+            authorization_list,
+            recovery_id,
+            r,
+            s,
+        } = self;
+
+        f.debug_struct("Eip7702EthereumData")
+            .field("chain_id", &hex::encode(chain_id))
+            .field("nonce", &hex::encode(nonce))
+            .field("max_priority_gas", &hex::encode(max_priority_gas))
+            .field("max_gas", &hex::encode(max_gas))
+            .field("gas_limit", &hex::encode(gas_limit))
+            .field("to", &hex::encode(to))
+            .field("value", &hex::encode(value))
+            .field("call_data", &hex::encode(call_data))
+            .field("access_list", access_list)
+            .field("authorization_list", authorization_list)
+            .field("recovery_id", &hex::encode(recovery_id))
+            .field("r", &hex::encode(r))
+            .field("s", &hex::encode(s))
+            .finish()
+    }
+}
+
+impl Eip7702EthereumData {
+    fn decode_rlp(rlp: &Rlp) -> Result<Self, rlp::DecoderError> {
+        if rlp.item_count()? != 13 {
+            return Err(rlp::DecoderError::RlpIncorrectListLen);
         }
 
-        Self::decode_rlp(&Rlp::new(bytes)).map_err(Error::basic_parse)
+        Ok(Self {
+            chain_id: rlp.val_at(0)?,
+            nonce: rlp.val_at(1)?,
+            max_priority_gas: rlp.val_at(2)?,
+            max_gas: rlp.val_at(3)?,
+            gas_limit: rlp.val_at(4)?,
+            to: rlp.val_at(5)?,
+            value: rlp.val_at(6)?,
+            call_data: rlp.val_at(7)?,
+            access_list: rlp.list_at(8)?,
+            authorization_list: rlp.list_at(9)?,
+            recovery_id: rlp.val_at(10)?,
+            r: rlp.val_at(11)?,
+            s: rlp.val_at(12)?,
+        })
+    }
+
+    /// Deserialize this data from rlp encoded bytes.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if decoding the bytes fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        Self::decode_rlp(&typed_rlp(bytes, 0x04)?).map_err(Error::basic_parse)
     }
 
     /// Convert this data to rlp encoded bytes.
     #[must_use]
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut buffer = BytesMut::new();
-        buffer.put_u8(0x02);
-        let mut rlp = rlp::RlpStream::new_list_with_buffer(buffer, 12);
+        buffer.put_u8(0x04);
+        let mut rlp = rlp::RlpStream::new_list_with_buffer(buffer, 13);
 
         rlp.append(&self.chain_id)
             .append(&self.nonce)
@@ -288,13 +698,230 @@ impl Eip1559EthereumData {
             .append(&self.to)
             .append(&self.value)
             .append(&self.call_data)
-            .append_list::<Vec<_>, _>(self.access_list.as_slice())
+            .append_list::<AccessListItem, _>(self.access_list.as_slice())
+            .append_list::<Eip7702Authorization, _>(self.authorization_list.as_slice())
             .append(&self.recovery_id)
             .append(&self.r)
             .append(&self.s);
 
         rlp.out().to_vec()
     }
+
+    /// The hash the sender signs, which is the hash of the transaction without the signature.
+    pub(super) fn signing_hash(&self) -> [u8; 32] {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(0x04);
+        let mut rlp = rlp::RlpStream::new_list_with_buffer(buffer, 10);
+
+        rlp.append(&self.chain_id)
+            .append(&self.nonce)
+            .append(&self.max_priority_gas)
+            .append(&self.max_gas)
+            .append(&self.gas_limit)
+            .append(&self.to)
+            .append(&self.value)
+            .append(&self.call_data)
+            .append_list::<AccessListItem, _>(self.access_list.as_slice())
+            .append_list::<Eip7702Authorization, _>(self.authorization_list.as_slice());
+
+        keccak256(&rlp.out())
+    }
+
+    /// Recover the address of the account that signed this transaction.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature is malformed or doesn't match the transaction.
+    pub fn recover_sender(&self) -> crate::Result<EvmAddress> {
+        let recovery_id = uint_from_bytes(&self.recovery_id)
+            .ok_or_else(|| Error::signature_verify("invalid recovery ID"))?;
+
+        recover_signer(&self.signing_hash(), recovery_id, &self.r, &self.s)
+    }
+}
+
+/// An address and the storage keys in it that an ethereum transaction plans to access.
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AccessListItem {
+    /// The address of the account.
+    pub address: Vec<u8>,
+
+    /// The storage keys of the account that will be accessed.
+    pub storage_keys: Vec<Vec<u8>>,
+}
+
+impl fmt::Debug for AccessListItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccessListItem")
+            .field("address", &hex::encode(&self.address))
+            .field("storage_keys", &HexList(&self.storage_keys))
+            .finish()
+    }
+}
+
+impl rlp::Decodable for AccessListItem {
+    fn decode(rlp: &Rlp) -> Result<Self, rlp::DecoderError> {
+        if rlp.item_count()? != 2 {
+            return Err(rlp::DecoderError::RlpIncorrectListLen);
+        }
+
+        Ok(Self { address: rlp.val_at(0)?, storage_keys: rlp.list_at(1)? })
+    }
+}
+
+impl rlp::Encodable for AccessListItem {
+    fn rlp_append(&self, rlp: &mut rlp::RlpStream) {
+        rlp.begin_list(2)
+            .append(&self.address)
+            .append_list::<Vec<u8>, _>(self.storage_keys.as_slice());
+    }
+}
+
+/// A signed authorization, in an [`Eip7702EthereumData`], for an account to delegate its code to a contract.
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Eip7702Authorization {
+    /// ID of the chain the authorization is valid on, or zero for any chain.
+    pub chain_id: Vec<u8>,
+
+    /// The address of the contract whose code is delegated to.
+    pub address: Vec<u8>,
+
+    /// The nonce of the authorizing account.
+    pub nonce: Vec<u8>,
+
+    /// Recovery parameter used to ease the signature verification.
+    pub recovery_id: Vec<u8>,
+
+    /// The R value of the signature.
+    pub r: Vec<u8>,
+
+    /// The S value of the signature.
+    pub s: Vec<u8>,
+}
+
+impl fmt::Debug for Eip7702Authorization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { chain_id, address, nonce, recovery_id, r, s } = self;
+
+        f.debug_struct("Eip7702Authorization")
+            .field("chain_id", &hex::encode(chain_id))
+            .field("address", &hex::encode(address))
+            .field("nonce", &hex::encode(nonce))
+            .field("recovery_id", &hex::encode(recovery_id))
+            .field("r", &hex::encode(r))
+            .field("s", &hex::encode(s))
+            .finish()
+    }
+}
+
+impl rlp::Decodable for Eip7702Authorization {
+    fn decode(rlp: &Rlp) -> Result<Self, rlp::DecoderError> {
+        if rlp.item_count()? != 6 {
+            return Err(rlp::DecoderError::RlpIncorrectListLen);
+        }
+
+        Ok(Self {
+            chain_id: rlp.val_at(0)?,
+            address: rlp.val_at(1)?,
+            nonce: rlp.val_at(2)?,
+            recovery_id: rlp.val_at(3)?,
+            r: rlp.val_at(4)?,
+            s: rlp.val_at(5)?,
+        })
+    }
+}
+
+impl rlp::Encodable for Eip7702Authorization {
+    fn rlp_append(&self, rlp: &mut rlp::RlpStream) {
+        rlp.begin_list(6)
+            .append(&self.chain_id)
+            .append(&self.address)
+            .append(&self.nonce)
+            .append(&self.recovery_id)
+            .append(&self.r)
+            .append(&self.s);
+    }
+}
+
+struct HexList<'a, T: AsRef<[u8]>>(&'a [T]);
+
+impl<'a, T: AsRef<[u8]>> fmt::Debug for HexList<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter().map(hex::encode)).finish()
+    }
+}
+
+fn keccak256(bytes: &[u8]) -> [u8; 32] {
+    sha3::Keccak256::digest(bytes).into()
+}
+
+/// The minimal big-endian bytes of `value`, which is how rlp encodes integers.
+pub(super) fn uint_bytes(value: u128) -> Vec<u8> {
+    trim_uint(&value.to_be_bytes())
+}
+
+/// Strips the leading zeros from the big-endian integer `bytes`.
+pub(super) fn trim_uint(bytes: &[u8]) -> Vec<u8> {
+    let zeros = bytes.iter().take_while(|it| **it == 0).count();
+
+    bytes[zeros..].to_vec()
+}
+
+fn uint_from_bytes(bytes: &[u8]) -> Option<u128> {
+    if bytes.len() > 16 {
+        return None;
+    }
+
+    Some(bytes.iter().fold(0, |value, it| (value << 8) | u128::from(*it)))
+}
+
+/// Recovers the address of the account that signed `hash`.
+fn recover_signer(
+    hash: &[u8; 32],
+    recovery_id: u128,
+    r: &[u8],
+    s: &[u8],
+) -> crate::Result<EvmAddress> {
+    let recovery_id = match recovery_id {
+        0 => RecoveryId::new(false, false),
+        1 => RecoveryId::new(true, false),
+        _ => return Err(Error::signature_verify(format!("invalid recovery ID of {recovery_id}"))),
+    };
+
+    let scalar = |bytes: &[u8]| -> crate::Result<[u8; 32]> {
+        let mut scalar = [0; 32];
+
+        scalar
+            .get_mut(32_usize.saturating_sub(bytes.len())..)
+            .filter(|it| it.len() == bytes.len())
+            .ok_or_else(|| Error::signature_verify("signature values must be at most 32 bytes"))?
+            .copy_from_slice(bytes);
+
+        Ok(scalar)
+    };
+
+    let signature =
+        Signature::from_scalars(scalar(r)?, scalar(s)?).map_err(Error::signature_verify)?;
+
+    let key = VerifyingKey::recover_from_prehash(hash, &signature, recovery_id)
+        .map_err(Error::signature_verify)?;
+
+    PublicKey::ecdsa(key)
+        .to_evm_address()
+        .ok_or_else(|| Error::signature_verify("recovered key isn't an ECDSA key"))
+}
+
+/// Strips the type byte from a typed transaction, failing if it isn't `kind`.
+fn typed_rlp(bytes: &[u8], kind: u8) -> crate::Result<Rlp<'_>> {
+    let (&first, bytes) =
+        bytes.split_first().ok_or_else(|| Error::basic_parse("Empty ethereum transaction data"))?;
+
+    if first != kind {
+        return Err(Error::basic_parse(rlp::DecoderError::Custom("Invalid kind")));
+    }
+
+    Ok(Rlp::new(bytes))
 }
 
 #[cfg(test)]
@@ -307,6 +934,9 @@ mod test {
     const RAW_TX_TYPE_0: &[u8]  =
         &hex!("f864012f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc18180827653820277a0f9fbff985d374be4a55f296915002eec11ac96f1ce2df183adf992baa9390b2fa00c1e867cc960d9c74ec2e6a662b7908ec4c8cc9f3091e886bcefbeb2290fb792");
 
+    const RAW_TX_TYPE_1: &[u8] =
+        &hex!("01f8a882012a022f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181880de0b6b3a764000083123456f838f7947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181e1a0000000000000000000000000000000000000000000000000000000000000000101a0df48f2efd10421811de2bfb125ab75b2d3c44139c4642837fb1fccce911fd479a01aaf7ae92bee896651dfc9d99ae422a296bf5d9f1ca49b2d96d82b79eb112d66");
+
     const RAW_TX_TYPE_2: &[u8] =
         &hex!("02f87082012a022f2f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181880de0b6b3a764000083123456c001a0df48f2efd10421811de2bfb125ab75b2d3c44139c4642837fb1fccce911fd479a01aaf7ae92bee896651dfc9d99ae422a296bf5d9f1ca49b2d96d82b79eb112d66");
 
@@ -337,6 +967,41 @@ mod test {
         // assert_eq!(hex!("9ffbd69c44cf643ed8d1e756b505e545e3b5dd3a6b5ef9da1d8eca6679706594"), data.ethereum_hash);
     }
 
+    const RAW_TX_TYPE_4: &[u8] =
+        &hex!("04f8c882012a022f2f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc1818083123456c0f85ef85c82012a9411111111111111111111111111111111111111110580a0df48f2efd10421811de2bfb125ab75b2d3c44139c4642837fb1fccce911fd479a01aaf7ae92bee896651dfc9d99ae422a296bf5d9f1ca49b2d96d82b79eb112d6601a0df48f2efd10421811de2bfb125ab75b2d3c44139c4642837fb1fccce911fd479a01aaf7ae92bee896651dfc9d99ae422a296bf5d9f1ca49b2d96d82b79eb112d66");
+
+    #[test]
+    fn eip2930_to_from_bytes() {
+        let data = EthereumData::from_bytes(RAW_TX_TYPE_1).unwrap();
+        assert_eq!(hex::encode(RAW_TX_TYPE_1), hex::encode(data.to_bytes()));
+
+        expect![[r#"
+            Eip2930(
+                Eip2930EthereumData {
+                    chain_id: "012a",
+                    nonce: "02",
+                    gas_price: "2f",
+                    gas_limit: "018000",
+                    to: "7e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181",
+                    value: "0de0b6b3a7640000",
+                    call_data: "123456",
+                    access_list: [
+                        AccessListItem {
+                            address: "7e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181",
+                            storage_keys: [
+                                "0000000000000000000000000000000000000000000000000000000000000001",
+                            ],
+                        },
+                    ],
+                    recovery_id: "01",
+                    r: "df48f2efd10421811de2bfb125ab75b2d3c44139c4642837fb1fccce911fd479",
+                    s: "1aaf7ae92bee896651dfc9d99ae422a296bf5d9f1ca49b2d96d82b79eb112d66",
+                },
+            )
+        "#]]
+        .assert_debug_eq(&data);
+    }
+
     #[test]
     fn eip1559_to_from_bytes() {
         let data = EthereumData::from_bytes(RAW_TX_TYPE_2).unwrap();
@@ -362,4 +1027,74 @@ mod test {
         "#]]
         .assert_debug_eq(&data);
     }
+
+    // `RAW_TX_TYPE_2` with the access list from `RAW_TX_TYPE_1`.
+    const RAW_TX_TYPE_2_ACCESS_LIST: &[u8] =
+        &hex!("02f8a982012a022f2f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181880de0b6b3a764000083123456f838f7947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181e1a0000000000000000000000000000000000000000000000000000000000000000101a0df48f2efd10421811de2bfb125ab75b2d3c44139c4642837fb1fccce911fd479a01aaf7ae92bee896651dfc9d99ae422a296bf5d9f1ca49b2d96d82b79eb112d66");
+
+    #[test]
+    fn eip1559_access_list_to_from_bytes() {
+        let data = EthereumData::from_bytes(RAW_TX_TYPE_2_ACCESS_LIST).unwrap();
+        assert_eq!(hex::encode(RAW_TX_TYPE_2_ACCESS_LIST), hex::encode(data.to_bytes()));
+
+        let EthereumData::Eip1559(data) = data else { panic!("expected eip 1559 data") };
+
+        expect![[r#"
+            [
+                AccessListItem {
+                    address: "7e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181",
+                    storage_keys: [
+                        "0000000000000000000000000000000000000000000000000000000000000001",
+                    ],
+                },
+            ]
+        "#]]
+        .assert_debug_eq(&data.access_list);
+    }
+
+    #[test]
+    fn eip7702_to_from_bytes() {
+        let data = EthereumData::from_bytes(RAW_TX_TYPE_4).unwrap();
+        assert_eq!(hex::encode(RAW_TX_TYPE_4), hex::encode(data.to_bytes()));
+
+        expect![[r#"
+            Eip7702(
+                Eip7702EthereumData {
+                    chain_id: "012a",
+                    nonce: "02",
+                    max_priority_gas: "2f",
+                    max_gas: "2f",
+                    gas_limit: "018000",
+                    to: "7e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181",
+                    value: "",
+                    call_data: "123456",
+                    access_list: [],
+                    authorization_list: [
+                        Eip7702Authorization {
+                            chain_id: "012a",
+                            address: "1111111111111111111111111111111111111111",
+                            nonce: "05",
+                            recovery_id: "",
+                            r: "df48f2efd10421811de2bfb125ab75b2d3c44139c4642837fb1fccce911fd479",
+                            s: "1aaf7ae92bee896651dfc9d99ae422a296bf5d9f1ca49b2d96d82b79eb112d66",
+                        },
+                    ],
+                    recovery_id: "01",
+                    r: "df48f2efd10421811de2bfb125ab75b2d3c44139c4642837fb1fccce911fd479",
+                    s: "1aaf7ae92bee896651dfc9d99ae422a296bf5d9f1ca49b2d96d82b79eb112d66",
+                },
+            )
+        "#]]
+        .assert_debug_eq(&data);
+    }
+
+    #[test]
+    fn unknown_type() {
+        // a type 3 (blob) transaction, which hedera doesn't support.
+        let mut data = RAW_TX_TYPE_2.to_vec();
+        data[0] = 0x03;
+
+        assert!(EthereumData::from_bytes(&data).is_err());
+        assert!(EthereumData::from_bytes(&[]).is_err());
+    }
 }
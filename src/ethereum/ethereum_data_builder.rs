@@ -0,0 +1,319 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::ethereum_data::{
+    trim_uint,
+    uint_bytes,
+};
+use crate::{
+    Eip1559EthereumData,
+    Error,
+    EvmAddress,
+    LegacyEthereumData,
+    PrivateKey,
+};
+
+/// Builds and signs the data for an [`EthereumTransaction`](crate::EthereumTransaction),
+/// for sending EVM transactions from an ECDSA account without an external Ethereum library.
+///
+/// Values are in weibars, where 1 tinybar is 10<sup>10</sup> weibars.
+#[derive(Debug, Clone, Default)]
+pub struct EthereumDataBuilder {
+    chain_id: u64,
+    nonce: u64,
+    gas_price: u128,
+    max_priority_gas: u128,
+    max_gas: u128,
+    gas_limit: u64,
+    to: Option<EvmAddress>,
+    value: u128,
+    call_data: Vec<u8>,
+}
+
+impl EthereumDataBuilder {
+    /// Create a new `EthereumDataBuilder` ready for configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the ID of the chain the transaction is valid on.
+    #[must_use]
+    pub fn get_chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    /// Sets the ID of the chain the transaction is valid on, such as 295 for mainnet and 296 for testnet.
+    ///
+    /// Legacy transactions with a chain ID of 0 aren't protected from replays on other chains.
+    pub fn chain_id(&mut self, chain_id: u64) -> &mut Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    /// Returns the nonce of the sending account.
+    #[must_use]
+    pub fn get_nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    /// Sets the nonce of the sending account.
+    pub fn nonce(&mut self, nonce: u64) -> &mut Self {
+        self.nonce = nonce;
+        self
+    }
+
+    /// Returns the price for 1 gas of a legacy transaction.
+    #[must_use]
+    pub fn get_gas_price(&self) -> u128 {
+        self.gas_price
+    }
+
+    /// Sets the price for 1 gas of a legacy transaction.
+    pub fn gas_price(&mut self, gas_price: u128) -> &mut Self {
+        self.gas_price = gas_price;
+        self
+    }
+
+    /// Returns the priority fee per gas of an Eip 1559 transaction.
+    #[must_use]
+    pub fn get_max_priority_gas(&self) -> u128 {
+        self.max_priority_gas
+    }
+
+    /// Sets the priority fee per gas of an Eip 1559 transaction, which isn't used by Hiero.
+    pub fn max_priority_gas(&mut self, max_priority_gas: u128) -> &mut Self {
+        self.max_priority_gas = max_priority_gas;
+        self
+    }
+
+    /// Returns the maximum fee per gas of an Eip 1559 transaction.
+    #[must_use]
+    pub fn get_max_gas(&self) -> u128 {
+        self.max_gas
+    }
+
+    /// Sets the maximum fee per gas of an Eip 1559 transaction.
+    pub fn max_gas(&mut self, max_gas: u128) -> &mut Self {
+        self.max_gas = max_gas;
+        self
+    }
+
+    /// Returns the amount of gas available for the transaction.
+    #[must_use]
+    pub fn get_gas_limit(&self) -> u64 {
+        self.gas_limit
+    }
+
+    /// Sets the amount of gas available for the transaction.
+    pub fn gas_limit(&mut self, gas_limit: u64) -> &mut Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    /// Returns the receiver of the transaction.
+    #[must_use]
+    pub fn get_to(&self) -> Option<EvmAddress> {
+        self.to
+    }
+
+    /// Sets the receiver of the transaction.
+    ///
+    /// Transactions without a receiver create a contract, with the call data as its init code.
+    pub fn to(&mut self, to: EvmAddress) -> &mut Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Returns the transaction value.
+    #[must_use]
+    pub fn get_value(&self) -> u128 {
+        self.value
+    }
+
+    /// Sets the transaction value.
+    pub fn value(&mut self, value: u128) -> &mut Self {
+        self.value = value;
+        self
+    }
+
+    /// Returns the raw call data.
+    #[must_use]
+    pub fn get_call_data(&self) -> &[u8] {
+        &self.call_data
+    }
+
+    /// Sets the raw call data.
+    pub fn call_data(&mut self, call_data: Vec<u8>) -> &mut Self {
+        self.call_data = call_data;
+        self
+    }
+
+    /// Build a legacy transaction, signed with `key` as described by EIP-155.
+    ///
+    /// # Errors
+    /// - [`Error::EthereumSign`] if `key` isn't an ECDSA key.
+    pub fn sign_legacy(&self, key: &PrivateKey) -> crate::Result<LegacyEthereumData> {
+        let mut data = LegacyEthereumData {
+            nonce: uint_bytes(self.nonce.into()),
+            gas_price: uint_bytes(self.gas_price),
+            gas_limit: uint_bytes(self.gas_limit.into()),
+            to: self.to_address_bytes(),
+            value: uint_bytes(self.value),
+            v: Vec::new(),
+            call_data: self.call_data.clone(),
+            r: Vec::new(),
+            s: Vec::new(),
+        };
+
+        let chain_id = (self.chain_id != 0).then(|| uint_bytes(self.chain_id.into()));
+        let (recovery_id, r, s) = sign(key, &data.signing_hash(chain_id.as_deref()))?;
+
+        let v = match self.chain_id {
+            0 => 27 + u128::from(recovery_id),
+            chain_id => u128::from(chain_id) * 2 + 35 + u128::from(recovery_id),
+        };
+
+        data.v = uint_bytes(v);
+        data.r = r;
+        data.s = s;
+
+        Ok(data)
+    }
+
+    /// Build an Eip 1559 transaction, signed with `key`.
+    ///
+    /// # Errors
+    /// - [`Error::EthereumSign`] if `key` isn't an ECDSA key.
+    pub fn sign_eip1559(&self, key: &PrivateKey) -> crate::Result<Eip1559EthereumData> {
+        let mut data = Eip1559EthereumData {
+            chain_id: uint_bytes(self.chain_id.into()),
+            nonce: uint_bytes(self.nonce.into()),
+            max_priority_gas: uint_bytes(self.max_priority_gas),
+            max_gas: uint_bytes(self.max_gas),
+            gas_limit: uint_bytes(self.gas_limit.into()),
+            to: self.to_address_bytes(),
+            value: uint_bytes(self.value),
+            call_data: self.call_data.clone(),
+            access_list: Vec::new(),
+            recovery_id: Vec::new(),
+            r: Vec::new(),
+            s: Vec::new(),
+        };
+
+        let (recovery_id, r, s) = sign(key, &data.signing_hash())?;
+
+        data.recovery_id = uint_bytes(recovery_id.into());
+        data.r = r;
+        data.s = s;
+
+        Ok(data)
+    }
+
+    fn to_address_bytes(&self) -> Vec<u8> {
+        self.to.map(|it| it.to_bytes().to_vec()).unwrap_or_default()
+    }
+}
+
+/// Signs `hash`, returning the recovery ID and the R and S values of the signature.
+fn sign(key: &PrivateKey, hash: &[u8; 32]) -> crate::Result<(u8, Vec<u8>, Vec<u8>)> {
+    let key = key.as_ecdsa().ok_or_else(|| {
+        Error::ethereum_sign("ethereum transactions must be signed with an ECDSA key")
+    })?;
+
+    let (signature, recovery_id) =
+        key.sign_prehash_recoverable(hash).map_err(Error::ethereum_sign)?;
+
+    let (r, s) = signature.split_bytes();
+
+    Ok((recovery_id.to_byte(), trim_uint(&r), trim_uint(&s)))
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::EthereumDataBuilder;
+    use crate::{
+        EthereumData,
+        EvmAddress,
+        PrivateKey,
+    };
+
+    // the example from EIP-155.
+    const EIP155_KEY: [u8; 32] =
+        hex!("4646464646464646464646464646464646464646464646464646464646464646");
+
+    #[test]
+    fn sign_legacy() {
+        let key = PrivateKey::from_bytes_ecdsa(&EIP155_KEY).unwrap();
+
+        let data = EthereumDataBuilder::new()
+            .chain_id(1)
+            .nonce(9)
+            .gas_price(20_000_000_000)
+            .gas_limit(21000)
+            .to(EvmAddress::from([0x35; 20]))
+            .value(1_000_000_000_000_000_000)
+            .sign_legacy(&key)
+            .unwrap();
+
+        assert_eq!(
+            hex::encode(data.to_bytes()),
+            "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+        );
+
+        assert_eq!(data.recover_sender().unwrap(), key.public_key().to_evm_address().unwrap());
+    }
+
+    #[test]
+    fn sign_legacy_without_chain_id() {
+        let key = PrivateKey::from_bytes_ecdsa(&EIP155_KEY).unwrap();
+
+        let data = EthereumDataBuilder::new().nonce(1).gas_limit(21000).sign_legacy(&key).unwrap();
+
+        assert!(matches!(data.v.as_slice(), [27 | 28]));
+        assert_eq!(data.recover_sender().unwrap(), key.public_key().to_evm_address().unwrap());
+    }
+
+    #[test]
+    fn sign_eip1559() {
+        let key = PrivateKey::generate_ecdsa();
+
+        let data = EthereumDataBuilder::new()
+            .chain_id(296)
+            .nonce(2)
+            .max_gas(1_000_000_000_000)
+            .gas_limit(100_000)
+            .to(EvmAddress::from([0x11; 20]))
+            .call_data(vec![0x12, 0x34])
+            .sign_eip1559(&key)
+            .unwrap();
+
+        let data = EthereumData::from_bytes(&data.to_bytes()).unwrap();
+
+        assert!(matches!(data, EthereumData::Eip1559(_)));
+        assert_eq!(data.recover_sender().unwrap(), key.public_key().to_evm_address().unwrap());
+    }
+
+    #[test]
+    fn sign_with_ed25519() {
+        let key = PrivateKey::generate_ed25519();
+
+        assert!(EthereumDataBuilder::new().sign_eip1559(&key).is_err());
+    }
+
+    #[test]
+    fn recover_from_tampered_data() {
+        let key = PrivateKey::generate_ecdsa();
+
+        let mut data = EthereumDataBuilder::new().chain_id(296).sign_eip1559(&key).unwrap();
+        data.nonce = vec![1];
+
+        // a different, but valid, signer.
+        assert_ne!(data.recover_sender().ok(), key.public_key().to_evm_address());
+
+        data.recovery_id = vec![2];
+
+        assert!(data.recover_sender().is_err());
+    }
+}
@@ -1,15 +1,21 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod ethereum_data;
+mod ethereum_data_builder;
 mod ethereum_flow;
 mod ethereum_transaction;
 mod evm_address;
 
 pub use ethereum_data::{
+    AccessListItem,
     Eip1559EthereumData,
+    Eip2930EthereumData,
+    Eip7702Authorization,
+    Eip7702EthereumData,
     EthereumData,
     LegacyEthereumData,
 };
+pub use ethereum_data_builder::EthereumDataBuilder;
 pub use ethereum_flow::EthereumFlow;
 pub use ethereum_transaction::EthereumTransaction;
 pub(crate) use ethereum_transaction::EthereumTransactionData;
@@ -0,0 +1,862 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use hedera_proto::services;
+use prost::Message;
+use rust_decimal::Decimal;
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+
+use crate::transaction::TransactionExecute;
+use crate::{
+    Error,
+    ExchangeRate,
+    ExchangeRates,
+    FeeComponents,
+    FeeData,
+    FeeDataType,
+    FeeSchedule,
+    FeeSchedules,
+    Hbar,
+    RequestType,
+    Transaction,
+};
+
+/// Prices in a fee schedule are in thousandths of a tinycent.
+const FEE_DIVISOR_FACTOR: u128 = 1000;
+
+/// How long the network keeps a transaction receipt around.
+const RECEIPT_STORAGE_TIME_SECONDS: u64 = 180;
+
+/// Approximate size of a receipt with no created entity.
+const BASIC_RECEIPT_SIZE: u64 = 4 + 2 * 8;
+
+/// Approximate size of a serialized `SignaturePair` (prefix, signature, and framing).
+const SIGNATURE_PAIR_SIZE: u64 = 33 + 64 + 6;
+
+/// Size of the response to a transaction (the pre-check code).
+const TRANSACTION_RESPONSE_SIZE: u64 = 4;
+
+/// Approximate fixed size of an entity's state (IDs, balances, expiry, flags) before any variable length fields.
+const BASIC_ENTITY_SIZE: u64 = 8 * 8;
+
+/// Size of an entity ID (shard, realm and num) in a transfer.
+const ENTITY_ID_SIZE: u64 = 3 * 8;
+
+/// Size of an account and the amount it sends or receives in a transfer.
+const ACCOUNT_AMOUNT_SIZE: u64 = ENTITY_ID_SIZE + 8;
+
+/// Size of an NFT changing owner in a transfer (sender, receiver and serial number).
+const NFT_TRANSFER_SIZE: u64 = 2 * ENTITY_ID_SIZE + 8;
+
+/// How much more the network charges for the resources of a token transfer than an hbar transfer of the same size.
+const TOKEN_TRANSFER_MULTIPLIER: u64 = 380;
+
+/// The auto renew period used by the network when a transaction doesn't specify one.
+const DEFAULT_AUTO_RENEW_PERIOD: Duration = Duration::days(90);
+
+/// How long a schedule lives if its creation doesn't specify an expiration time.
+const DEFAULT_SCHEDULE_LIFETIME: Duration = Duration::minutes(30);
+
+/// Estimates transaction fees offline from a [`FeeSchedule`] and an [`ExchangeRate`].
+///
+/// The estimate mirrors how the network prices a transaction:
+/// the resource usage (bytes, signatures, storage and memory) is computed from the transaction body,
+/// then priced with the [`FeeData`] for the transaction's [`RequestType`] and converted to hbar.
+///
+/// Since the estimate is computed without network state,
+/// it can differ from the actual fee (for instance, token custom fees can't be detected offline).
+#[derive(Debug, Clone)]
+pub struct FeeEstimator {
+    fee_schedule: FeeSchedule,
+    exchange_rate: ExchangeRate,
+    signature_count: Option<usize>,
+    storage_duration: Duration,
+}
+
+impl FeeEstimator {
+    /// Create a new `FeeEstimator` that prices transactions with `fee_schedule` at `exchange_rate`.
+    #[must_use]
+    pub fn new(fee_schedule: FeeSchedule, exchange_rate: ExchangeRate) -> Self {
+        Self {
+            fee_schedule,
+            exchange_rate,
+            signature_count: None,
+            storage_duration: DEFAULT_AUTO_RENEW_PERIOD,
+        }
+    }
+
+    /// Create a new `FeeEstimator` from the current fee schedule and exchange rate.
+    ///
+    /// # Errors
+    /// - [`Error::FeeEstimate`] if `fee_schedules` has no current fee schedule.
+    pub fn from_current(
+        fee_schedules: &FeeSchedules,
+        exchange_rates: &ExchangeRates,
+    ) -> crate::Result<Self> {
+        let fee_schedule = fee_schedules
+            .current
+            .clone()
+            .ok_or_else(|| Error::fee_estimate("no current fee schedule"))?;
+
+        Ok(Self::new(fee_schedule, exchange_rates.current_rate.clone()))
+    }
+
+    /// Returns the fee schedule used for pricing.
+    #[must_use]
+    pub fn get_fee_schedule(&self) -> &FeeSchedule {
+        &self.fee_schedule
+    }
+
+    /// Returns the exchange rate used to convert fees to hbar.
+    #[must_use]
+    pub fn get_exchange_rate(&self) -> &ExchangeRate {
+        &self.exchange_rate
+    }
+
+    /// Returns the number of signatures transactions are expected to have when submitted.
+    #[must_use]
+    pub fn get_signature_count(&self) -> Option<usize> {
+        self.signature_count
+    }
+
+    /// Sets the number of signatures transactions are expected to have when submitted.
+    ///
+    /// Defaults to the number of signatures currently on the transaction (or 1, if it has none).
+    pub fn signature_count(&mut self, count: usize) -> &mut Self {
+        self.signature_count = Some(count);
+        self
+    }
+
+    /// Returns how long appended file contents are expected to be stored for.
+    #[must_use]
+    pub fn get_storage_duration(&self) -> Duration {
+        self.storage_duration
+    }
+
+    /// Sets how long data is expected to be stored for when the transaction itself doesn't say.
+    ///
+    /// For instance, a [`FileAppendTransaction`](crate::FileAppendTransaction) doesn't know its file's expiration time.
+    ///
+    /// Defaults to 90 days.
+    pub fn storage_duration(&mut self, duration: Duration) -> &mut Self {
+        self.storage_duration = duration;
+        self
+    }
+
+    /// Estimate the fee for `transaction`.
+    ///
+    /// Chunked transactions are estimated as the sum of all of their chunks.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`] if the transaction has no transaction ID.
+    /// - [`Error::FeeEstimate`] if the fee schedule has no fees for the transaction's type.
+    /// - [`Error::FeeEstimate`] if the exchange rate is invalid.
+    pub fn estimate<D: TransactionExecute>(
+        &self,
+        transaction: &Transaction<D>,
+    ) -> crate::Result<FeeEstimate> {
+        let signature_count =
+            self.signature_count.unwrap_or_else(|| transaction.signature_count().max(1)) as u64;
+
+        let bodies = transaction.chunk_bodies()?;
+
+        let mut estimate: Option<FeeEstimate> = None;
+
+        for body in &bodies {
+            let chunk = self.estimate_body(body, signature_count)?;
+
+            estimate = Some(match estimate {
+                Some(estimate) => estimate.merge(chunk),
+                None => chunk,
+            });
+        }
+
+        // `chunk_bodies` always returns at least one body.
+        estimate.ok_or_else(|| Error::fee_estimate("transaction has no chunks"))
+    }
+
+    fn estimate_body(
+        &self,
+        body: &services::TransactionBody,
+        signature_count: u64,
+    ) -> crate::Result<FeeEstimate> {
+        let data = body
+            .data
+            .as_ref()
+            .ok_or_else(|| Error::fee_estimate("transaction body has no data"))?;
+
+        let (request_type, kind) = classify(data)?;
+
+        let prices = self.fee_data(&request_type, &kind)?;
+
+        let usage = usage(body, data, signature_count, self.storage_duration);
+
+        let node = self.component(&prices.node, &usage.node)?;
+        let network = self.component(&prices.network, &usage.network)?;
+        let service = self.component(&prices.service, &usage.service)?;
+
+        Ok(FeeEstimate {
+            request_type,
+            kind: prices.kind.clone(),
+            chunks: 1,
+            usage,
+            node,
+            network,
+            service,
+        })
+    }
+
+    /// Find the prices for `request_type`, preferring the exact subtype and falling back to the default prices.
+    fn fee_data(&self, request_type: &RequestType, kind: &FeeDataType) -> crate::Result<&FeeData> {
+        let schedule = self
+            .fee_schedule
+            .transaction_fee_schedules
+            .iter()
+            .find(|it| &it.request_type == request_type)
+            .ok_or_else(|| {
+                Error::fee_estimate(format!("fee schedule has no entry for `{request_type:?}`"))
+            })?;
+
+        #[allow(deprecated)]
+        let fee_data = schedule
+            .fees
+            .iter()
+            .find(|it| &it.kind == kind)
+            .or_else(|| schedule.fees.iter().find(|it| it.kind == FeeDataType::Default))
+            .or_else(|| schedule.fee_data.as_deref());
+
+        fee_data.ok_or_else(|| {
+            Error::fee_estimate(format!("fee schedule has no fees for `{request_type:?}`"))
+        })
+    }
+
+    fn component(
+        &self,
+        prices: &FeeComponents,
+        usage: &FeeComponents,
+    ) -> crate::Result<FeeEstimateComponent> {
+        let tinycents = component_tinycents(prices, usage);
+
+        Ok(FeeEstimateComponent { tinycents, hbar: self.to_hbar(tinycents)? })
+    }
+
+    fn to_hbar(&self, tinycents: u64) -> crate::Result<Hbar> {
+        if self.exchange_rate.cents == 0 {
+            return Err(Error::fee_estimate("exchange rate has zero cents"));
+        }
+
+        let tinybars = u128::from(tinycents) * u128::from(self.exchange_rate.hbars)
+            / u128::from(self.exchange_rate.cents);
+
+        let tinybars = i64::try_from(tinybars)
+            .map_err(|_| Error::fee_estimate("estimated fee overflows an `Hbar`"))?;
+
+        Ok(Hbar::from_tinybars(tinybars))
+    }
+}
+
+/// A single part (node, network, or service) of a [`FeeEstimate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeEstimateComponent {
+    /// The fee in tinycents (1/100,000,000 of a USD cent).
+    pub tinycents: u64,
+
+    /// The fee in hbar, at the exchange rate used for the estimate.
+    pub hbar: Hbar,
+}
+
+impl FeeEstimateComponent {
+    /// Returns the fee in USD.
+    #[must_use]
+    pub fn usd(&self) -> Decimal {
+        tinycents_to_usd(self.tinycents)
+    }
+}
+
+/// An offline estimate of the fee for a transaction.
+///
+/// See [`FeeEstimator::estimate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeEstimate {
+    /// The request type that the transaction was priced as.
+    pub request_type: RequestType,
+
+    /// The subtype of the prices that were used.
+    pub kind: FeeDataType,
+
+    /// The number of chunks (individual transactions) the estimate covers.
+    pub chunks: usize,
+
+    /// The estimated resource usage, summed over all chunks.
+    ///
+    /// This uses the same units as the prices in the fee schedule.
+    pub usage: FeeData,
+
+    /// The fee charged by the node.
+    pub node: FeeEstimateComponent,
+
+    /// The fee charged by the network.
+    pub network: FeeEstimateComponent,
+
+    /// The fee charged for the service.
+    pub service: FeeEstimateComponent,
+}
+
+impl FeeEstimate {
+    /// Returns the estimated total fee, in tinycents.
+    #[must_use]
+    pub fn total_tinycents(&self) -> u64 {
+        self.node.tinycents + self.network.tinycents + self.service.tinycents
+    }
+
+    /// Returns the estimated total fee, in hbar.
+    #[must_use]
+    pub fn total(&self) -> Hbar {
+        self.node.hbar + self.network.hbar + self.service.hbar
+    }
+
+    /// Returns the estimated total fee, in USD.
+    #[must_use]
+    pub fn total_usd(&self) -> Decimal {
+        tinycents_to_usd(self.total_tinycents())
+    }
+
+    fn merge(self, other: Self) -> Self {
+        fn add(lhs: FeeEstimateComponent, rhs: FeeEstimateComponent) -> FeeEstimateComponent {
+            FeeEstimateComponent {
+                tinycents: lhs.tinycents + rhs.tinycents,
+                hbar: lhs.hbar + rhs.hbar,
+            }
+        }
+
+        Self {
+            request_type: self.request_type,
+            kind: self.kind,
+            chunks: self.chunks + other.chunks,
+            usage: FeeData {
+                node: add_usage(&self.usage.node, &other.usage.node),
+                network: add_usage(&self.usage.network, &other.usage.network),
+                service: add_usage(&self.usage.service, &other.usage.service),
+                kind: self.usage.kind,
+            },
+            node: add(self.node, other.node),
+            network: add(self.network, other.network),
+            service: add(self.service, other.service),
+        }
+    }
+}
+
+fn tinycents_to_usd(tinycents: u64) -> Decimal {
+    // 1 USD = 100 cents = 10^10 tinycents.
+    Decimal::from(tinycents) / Decimal::from(10_000_000_000_u64)
+}
+
+fn component_tinycents(prices: &FeeComponents, usage: &FeeComponents) -> u64 {
+    let products = [
+        (prices.constant, usage.constant),
+        (prices.bandwidth_byte, usage.bandwidth_byte),
+        (prices.verification, usage.verification),
+        (prices.storage_byte_hour, usage.storage_byte_hour),
+        (prices.ram_byte_hour, usage.ram_byte_hour),
+        (prices.contract_transaction_gas, usage.contract_transaction_gas),
+        (prices.transfer_volume_hbar, usage.transfer_volume_hbar),
+        (prices.response_memory_byte, usage.response_memory_byte),
+        (prices.response_disk_byte, usage.response_disk_byte),
+    ];
+
+    let total: u128 =
+        products.iter().map(|(price, usage)| u128::from(*price) * u128::from(*usage)).sum();
+
+    // `min`/`max` of zero mean "no bound".
+    let total = total.max(u128::from(prices.min));
+    let total = match prices.max {
+        0 => total,
+        max => total.min(u128::from(max)),
+    };
+
+    u64::try_from(total / FEE_DIVISOR_FACTOR).unwrap_or(u64::MAX)
+}
+
+fn add_usage(lhs: &FeeComponents, rhs: &FeeComponents) -> FeeComponents {
+    FeeComponents {
+        min: 0,
+        max: 0,
+        constant: lhs.constant + rhs.constant,
+        bandwidth_byte: lhs.bandwidth_byte + rhs.bandwidth_byte,
+        verification: lhs.verification + rhs.verification,
+        storage_byte_hour: lhs.storage_byte_hour + rhs.storage_byte_hour,
+        ram_byte_hour: lhs.ram_byte_hour + rhs.ram_byte_hour,
+        contract_transaction_gas: lhs.contract_transaction_gas + rhs.contract_transaction_gas,
+        transfer_volume_hbar: lhs.transfer_volume_hbar + rhs.transfer_volume_hbar,
+        response_memory_byte: lhs.response_memory_byte + rhs.response_memory_byte,
+        response_disk_byte: lhs.response_disk_byte + rhs.response_disk_byte,
+    }
+}
+
+fn empty_usage() -> FeeComponents {
+    FeeComponents {
+        min: 0,
+        max: 0,
+        constant: 1,
+        bandwidth_byte: 0,
+        verification: 0,
+        storage_byte_hour: 0,
+        ram_byte_hour: 0,
+        contract_transaction_gas: 0,
+        transfer_volume_hbar: 0,
+        response_memory_byte: 0,
+        response_disk_byte: 0,
+    }
+}
+
+fn hours(duration: Duration) -> u64 {
+    u64::try_from(duration.whole_hours()).unwrap_or(0)
+}
+
+fn duration_or(duration: Option<&services::Duration>, default: Duration) -> Duration {
+    duration.map_or(default, |it| Duration::seconds(it.seconds))
+}
+
+/// Returns how long from the start of the transaction until `expiration_time`.
+fn lifetime(
+    body: &services::TransactionBody,
+    expiration_time: Option<&services::Timestamp>,
+    default: Duration,
+) -> Duration {
+    let valid_start = body
+        .transaction_id
+        .as_ref()
+        .and_then(|it| it.transaction_valid_start.clone())
+        .map(OffsetDateTime::from);
+
+    match (valid_start, expiration_time) {
+        (Some(valid_start), Some(expiration_time)) => {
+            OffsetDateTime::from(expiration_time.clone()) - valid_start
+        }
+        _ => default,
+    }
+}
+
+/// Computes the resources used by `body`, in the units of a [`FeeComponents`].
+fn usage(
+    body: &services::TransactionBody,
+    data: &services::transaction_body::Data,
+    signature_count: u64,
+    storage_duration: Duration,
+) -> FeeData {
+    use services::transaction_body::Data;
+
+    let bytes = body.encoded_len() as u64 + signature_count * SIGNATURE_PAIR_SIZE;
+
+    let node = FeeComponents {
+        bandwidth_byte: bytes,
+        verification: signature_count,
+        response_memory_byte: TRANSACTION_RESPONSE_SIZE,
+        ..empty_usage()
+    };
+
+    let network = FeeComponents {
+        bandwidth_byte: bytes,
+        verification: signature_count,
+        ram_byte_hour: (BASIC_RECEIPT_SIZE * RECEIPT_STORAGE_TIME_SECONDS).div_ceil(3600),
+        ..empty_usage()
+    };
+
+    let mut service = empty_usage();
+
+    match data {
+        Data::CryptoCreateAccount(it) => {
+            let period = duration_or(it.auto_renew_period.as_ref(), DEFAULT_AUTO_RENEW_PERIOD);
+            service.ram_byte_hour = (BASIC_ENTITY_SIZE + it.encoded_len() as u64) * hours(period);
+        }
+
+        Data::ConsensusCreateTopic(it) => {
+            let period = duration_or(it.auto_renew_period.as_ref(), DEFAULT_AUTO_RENEW_PERIOD);
+            service.ram_byte_hour = (BASIC_ENTITY_SIZE + it.encoded_len() as u64) * hours(period);
+        }
+
+        Data::TokenCreation(it) => {
+            let period = duration_or(it.auto_renew_period.as_ref(), DEFAULT_AUTO_RENEW_PERIOD);
+            service.ram_byte_hour = (BASIC_ENTITY_SIZE + it.encoded_len() as u64) * hours(period);
+        }
+
+        Data::ScheduleCreate(it) => {
+            let period = lifetime(body, it.expiration_time.as_ref(), DEFAULT_SCHEDULE_LIFETIME);
+            service.ram_byte_hour = (BASIC_ENTITY_SIZE + it.encoded_len() as u64) * hours(period);
+        }
+
+        Data::FileCreate(it) => {
+            let period = lifetime(body, it.expiration_time.as_ref(), storage_duration);
+            let size = it.contents.len() + it.keys.as_ref().map_or(0, Message::encoded_len);
+            service.storage_byte_hour = (BASIC_ENTITY_SIZE + size as u64) * hours(period);
+        }
+
+        Data::FileUpdate(it) => {
+            let period = lifetime(body, it.expiration_time.as_ref(), storage_duration);
+            service.storage_byte_hour = it.contents.len() as u64 * hours(period);
+        }
+
+        Data::FileAppend(it) => {
+            service.storage_byte_hour = it.contents.len() as u64 * hours(storage_duration);
+        }
+
+        Data::ConsensusSubmitMessage(it) => {
+            service.bandwidth_byte = it.message.len() as u64;
+        }
+
+        Data::TokenMint(it) => {
+            let size: usize = it.metadata.iter().map(Vec::len).sum();
+            service.storage_byte_hour = size as u64 * hours(storage_duration);
+        }
+
+        Data::CryptoTransfer(it) => {
+            let hbar_transfers = it.transfers.as_ref().map_or(0, |it| it.account_amounts.len());
+            transfer_usage(&mut service, hbar_transfers, &it.token_transfers);
+        }
+
+        Data::TokenAirdrop(it) => {
+            transfer_usage(&mut service, 0, &it.token_transfers);
+        }
+
+        Data::ContractCall(it) => {
+            service.contract_transaction_gas = u64::try_from(it.gas).unwrap_or(0);
+        }
+
+        Data::ContractCreateInstance(it) => {
+            service.contract_transaction_gas = u64::try_from(it.gas).unwrap_or(0);
+        }
+
+        _ => {}
+    }
+
+    FeeData { node, network, service, kind: FeeDataType::Default }
+}
+
+/// Adds the bandwidth of a transfer's account amounts and NFT transfers to `service`,
+/// and the memory to keep them in the record.
+fn transfer_usage(
+    service: &mut FeeComponents,
+    hbar_transfers: usize,
+    token_transfers: &[services::TokenTransferList],
+) {
+    let tokens = token_transfers.len() as u64;
+    let fungible_transfers: u64 = token_transfers.iter().map(|it| it.transfers.len() as u64).sum();
+    let nft_transfers: u64 = token_transfers.iter().map(|it| it.nft_transfers.len() as u64).sum();
+
+    let token_bytes = TOKEN_TRANSFER_MULTIPLIER
+        * (tokens * ENTITY_ID_SIZE
+            + fungible_transfers * ACCOUNT_AMOUNT_SIZE
+            + nft_transfers * NFT_TRANSFER_SIZE);
+
+    let bytes = hbar_transfers as u64 * ACCOUNT_AMOUNT_SIZE + token_bytes;
+
+    service.bandwidth_byte += bytes;
+    service.ram_byte_hour += (bytes * RECEIPT_STORAGE_TIME_SECONDS).div_ceil(3600);
+}
+
+/// Figure out which prices apply to `data`.
+fn classify(data: &services::transaction_body::Data) -> crate::Result<(RequestType, FeeDataType)> {
+    use services::transaction_body::Data;
+
+    let kind = FeeDataType::Default;
+
+    let token_kind = |is_nft: bool| {
+        if is_nft {
+            FeeDataType::TokenNonFungibleUnique
+        } else {
+            FeeDataType::TokenFungibleCommon
+        }
+    };
+
+    let value = match data {
+        Data::ContractCall(_) => (RequestType::ContractCall, kind),
+        Data::ContractCreateInstance(_) => (RequestType::ContractCreate, kind),
+        Data::ContractUpdateInstance(_) => (RequestType::ContractUpdate, kind),
+        Data::ContractDeleteInstance(_) => (RequestType::ContractDelete, kind),
+        Data::EthereumTransaction(_) => (RequestType::EthereumTransaction, kind),
+        Data::CryptoApproveAllowance(_) => (RequestType::CryptoApproveAllowance, kind),
+        Data::CryptoDeleteAllowance(_) => (RequestType::CryptoDeleteAllowance, kind),
+        Data::CryptoCreateAccount(_) => (RequestType::CryptoCreate, kind),
+        Data::CryptoDelete(_) => (RequestType::CryptoDelete, kind),
+        Data::CryptoTransfer(it) => {
+            let kind = if it.token_transfers.iter().any(|it| !it.nft_transfers.is_empty()) {
+                FeeDataType::TokenNonFungibleUnique
+            } else if it.token_transfers.is_empty() {
+                FeeDataType::Default
+            } else {
+                FeeDataType::TokenFungibleCommon
+            };
+
+            (RequestType::CryptoTransfer, kind)
+        }
+        Data::CryptoUpdateAccount(_) => (RequestType::CryptoUpdate, kind),
+        Data::FileAppend(_) => (RequestType::FileAppend, kind),
+        Data::FileCreate(_) => (RequestType::FileCreate, kind),
+        Data::FileDelete(_) => (RequestType::FileDelete, kind),
+        Data::FileUpdate(_) => (RequestType::FileUpdate, kind),
+        Data::UtilPrng(_) => (RequestType::UtilPrng, kind),
+        Data::SystemDelete(_) => (RequestType::SystemDelete, kind),
+        Data::SystemUndelete(_) => (RequestType::SystemUndelete, kind),
+        Data::Freeze(_) => (RequestType::Freeze, kind),
+        Data::ConsensusCreateTopic(it) => {
+            let kind = if it.custom_fees.is_empty() {
+                FeeDataType::Default
+            } else {
+                FeeDataType::TopicCreateWithCustomFees
+            };
+
+            (RequestType::ConsensusCreateTopic, kind)
+        }
+        Data::ConsensusUpdateTopic(_) => (RequestType::ConsensusUpdateTopic, kind),
+        Data::ConsensusDeleteTopic(_) => (RequestType::ConsensusDeleteTopic, kind),
+        Data::ConsensusSubmitMessage(_) => (RequestType::ConsensusSubmitMessage, kind),
+        Data::TokenCreation(it) => {
+            let is_nft = it.token_type == services::TokenType::NonFungibleUnique as i32;
+
+            let kind = match (is_nft, it.custom_fees.is_empty()) {
+                (false, true) => FeeDataType::TokenFungibleCommon,
+                (true, true) => FeeDataType::TokenNonFungibleUnique,
+                (false, false) => FeeDataType::TokenFungibleCommonWithCustomFees,
+                (true, false) => FeeDataType::TokenNonFungibleUniqueWithCustomFees,
+            };
+
+            (RequestType::TokenCreate, kind)
+        }
+        Data::TokenFreeze(_) => (RequestType::TokenFreezeAccount, kind),
+        Data::TokenUnfreeze(_) => (RequestType::TokenUnfreezeAccount, kind),
+        Data::TokenGrantKyc(_) => (RequestType::TokenGrantKycToAccount, kind),
+        Data::TokenRevokeKyc(_) => (RequestType::TokenRevokeKycFromAccount, kind),
+        Data::TokenDeletion(_) => (RequestType::TokenDelete, kind),
+        Data::TokenUpdate(_) => (RequestType::TokenUpdate, kind),
+        Data::TokenMint(it) => (RequestType::TokenMint, token_kind(!it.metadata.is_empty())),
+        Data::TokenBurn(it) => (RequestType::TokenBurn, token_kind(!it.serial_numbers.is_empty())),
+        Data::TokenWipe(it) => {
+            (RequestType::TokenAccountWipe, token_kind(!it.serial_numbers.is_empty()))
+        }
+        Data::TokenAssociate(_) => (RequestType::TokenAssociateToAccount, kind),
+        Data::TokenDissociate(_) => (RequestType::TokenDissociateFromAccount, kind),
+        Data::TokenFeeScheduleUpdate(_) => (RequestType::TokenFeeScheduleUpdate, kind),
+        Data::TokenPause(_) => (RequestType::TokenPause, kind),
+        Data::TokenUnpause(_) => (RequestType::TokenUnpause, kind),
+        Data::TokenReject(_) => (RequestType::TokenReject, kind),
+        Data::ScheduleCreate(it) => {
+            let is_contract_call = matches!(
+                it.scheduled_transaction_body.as_ref().and_then(|it| it.data.as_ref()),
+                Some(services::schedulable_transaction_body::Data::ContractCall(_))
+            );
+
+            let kind = if is_contract_call {
+                FeeDataType::ScheduleCreateContractCall
+            } else {
+                FeeDataType::Default
+            };
+
+            (RequestType::ScheduleCreate, kind)
+        }
+        Data::ScheduleDelete(_) => (RequestType::ScheduleDelete, kind),
+        Data::ScheduleSign(_) => (RequestType::ScheduleSign, kind),
+        Data::TokenUpdateNfts(_) => (RequestType::TokenUpdateNfts, kind),
+        Data::NodeCreate(_) => (RequestType::NodeCreate, kind),
+        Data::NodeUpdate(_) => (RequestType::NodeUpdate, kind),
+        Data::NodeDelete(_) => (RequestType::NodeDelete, kind),
+        Data::TokenAirdrop(_) => (RequestType::TokenAirdrop, kind),
+        Data::TokenClaimAirdrop(_) => (RequestType::TokenClaimAirdrop, kind),
+        Data::TokenCancelAirdrop(_) => (RequestType::TokenCancelAirdrop, kind),
+        Data::CryptoAddLiveHash(_) => (RequestType::CryptoAddLiveHash, kind),
+        Data::CryptoDeleteLiveHash(_) => (RequestType::CryptoDeleteLiveHash, kind),
+        Data::UncheckedSubmit(_) => (RequestType::UncheckedSubmit, kind),
+        Data::NodeStakeUpdate(_) => (RequestType::NodeStakeUpdate, kind),
+        _ => return Err(Error::fee_estimate("unsupported transaction type")),
+    };
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use super::FeeEstimator;
+    use crate::transaction::test_helpers::TEST_ACCOUNT_ID;
+    use crate::{
+        AccountId,
+        ExchangeRate,
+        FeeComponents,
+        FeeData,
+        FeeDataType,
+        FeeSchedule,
+        Hbar,
+        RequestType,
+        TokenId,
+        TopicId,
+        TopicMessageSubmitTransaction,
+        TransactionFeeSchedule,
+        TransferTransaction,
+    };
+
+    fn components(constant: u64, bandwidth_byte: u64, verification: u64) -> FeeComponents {
+        FeeComponents {
+            min: 0,
+            max: 0,
+            constant,
+            bandwidth_byte,
+            verification,
+            storage_byte_hour: 0,
+            ram_byte_hour: 0,
+            contract_transaction_gas: 0,
+            transfer_volume_hbar: 0,
+            response_memory_byte: 0,
+            response_disk_byte: 0,
+        }
+    }
+
+    fn fee_data(kind: FeeDataType) -> FeeData {
+        FeeData {
+            node: components(1_000_000_000, 1_000_000, 10_000_000),
+            network: components(2_000_000_000, 2_000_000, 20_000_000),
+            service: components(3_000_000_000, 0, 0),
+            kind,
+        }
+    }
+
+    fn estimator() -> FeeEstimator {
+        estimator_with(fee_data(FeeDataType::Default))
+    }
+
+    #[allow(deprecated)]
+    fn estimator_with(fees: FeeData) -> FeeEstimator {
+        let schedule = FeeSchedule {
+            transaction_fee_schedules: vec![
+                TransactionFeeSchedule {
+                    request_type: RequestType::CryptoTransfer,
+                    fee_data: None,
+                    fees: vec![fees.clone()],
+                },
+                TransactionFeeSchedule {
+                    request_type: RequestType::ConsensusSubmitMessage,
+                    fee_data: None,
+                    fees: vec![fees],
+                },
+            ],
+            expiration_time: OffsetDateTime::UNIX_EPOCH,
+        };
+
+        // 1 hbar = 10 cents
+        let exchange_rate = ExchangeRate {
+            hbars: 1,
+            cents: 10,
+            expiration_time: OffsetDateTime::UNIX_EPOCH,
+            exchange_rate_in_cents: 10.0,
+        };
+
+        FeeEstimator::new(schedule, exchange_rate)
+    }
+
+    #[test]
+    fn transfer() {
+        let mut tx = TransferTransaction::new_for_tests();
+        tx.hbar_transfer(TEST_ACCOUNT_ID, Hbar::new(-1))
+            .hbar_transfer(crate::AccountId::new(0, 0, 3), Hbar::new(1));
+
+        let estimate = estimator().estimate(&tx).unwrap();
+
+        assert_eq!(estimate.request_type, RequestType::CryptoTransfer);
+        assert_eq!(estimate.chunks, 1);
+        assert_eq!(estimate.usage.node.verification, 1);
+        assert_eq!(estimate.usage.node.bandwidth_byte, estimate.usage.network.bandwidth_byte);
+
+        let bytes = estimate.usage.node.bandwidth_byte;
+
+        assert_eq!(
+            estimate.node.tinycents,
+            (1_000_000_000 + bytes * 1_000_000 + 10_000_000) / 1000
+        );
+        assert_eq!(estimate.service.tinycents, 3_000_000);
+
+        // 10 cents per hbar means 1 tinycent = 1/10 tinybar
+        assert_eq!(estimate.node.hbar.to_tinybars() as u64, estimate.node.tinycents / 10);
+
+        assert_eq!(
+            estimate.total_tinycents(),
+            estimate.node.tinycents + estimate.network.tinycents + 3_000_000
+        );
+    }
+
+    #[test]
+    fn transfer_count() {
+        // bytes are free, so the difference is only what the transfers use.
+        let fees = FeeData {
+            node: components(1_000_000_000, 0, 10_000_000),
+            network: components(2_000_000_000, 0, 20_000_000),
+            service: FeeComponents { ram_byte_hour: 1_000_000, ..components(3_000_000_000, 0, 0) },
+            kind: FeeDataType::Default,
+        };
+
+        let estimate = |count: u64| {
+            let mut tx = TransferTransaction::new_for_tests();
+
+            for num in 0..count {
+                tx.hbar_transfer(AccountId::new(0, 0, 1000 + num), Hbar::new(1));
+            }
+
+            tx.hbar_transfer(TEST_ACCOUNT_ID, Hbar::new(-(count as i64)));
+
+            estimator_with(fees.clone()).estimate(&tx).unwrap()
+        };
+
+        let one = estimate(1);
+        let ten = estimate(10);
+
+        assert_eq!(one.usage.service.bandwidth_byte, 2 * 32);
+        assert_eq!(ten.usage.service.bandwidth_byte, 11 * 32);
+        assert!(ten.service.tinycents > one.service.tinycents);
+        assert!(ten.total() > one.total());
+    }
+
+    #[test]
+    fn token_transfer() {
+        let mut tx = TransferTransaction::new_for_tests();
+        tx.token_transfer(TokenId::new(0, 0, 5), TEST_ACCOUNT_ID, -1).token_transfer(
+            TokenId::new(0, 0, 5),
+            AccountId::new(0, 0, 1000),
+            1,
+        );
+
+        let estimate = estimator().estimate(&tx).unwrap();
+
+        // token transfers are charged as if they were 380 times the size.
+        assert_eq!(estimate.usage.service.bandwidth_byte, 380 * (24 + 2 * 32));
+    }
+
+    #[test]
+    fn signature_count() {
+        let tx = TransferTransaction::new_for_tests();
+
+        let one = estimator().estimate(&tx).unwrap();
+        let three = estimator().signature_count(3).estimate(&tx).unwrap();
+
+        assert_eq!(three.usage.node.verification, 3);
+        assert!(three.total() > one.total());
+    }
+
+    #[test]
+    fn chunked() {
+        let mut tx = TopicMessageSubmitTransaction::new_for_tests();
+        tx.topic_id(TopicId::new(0, 0, 10)).message(vec![0; 2500]);
+
+        let estimate = estimator().estimate(&tx).unwrap();
+
+        assert_eq!(estimate.chunks, 3);
+        assert_eq!(estimate.usage.service.bandwidth_byte, 2500);
+        assert_eq!(estimate.service.tinycents, 3 * 3_000_000);
+    }
+
+    #[test]
+    fn missing_request_type() {
+        let tx = crate::PrngTransaction::new_for_tests();
+
+        assert!(matches!(estimator().estimate(&tx), Err(crate::Error::FeeEstimate(_))));
+    }
+}
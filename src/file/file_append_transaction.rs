@@ -88,6 +88,10 @@ impl TransactionData for FileAppendTransactionData {
         Some(self.chunk_data())
     }
 
+    fn maybe_chunk_data_mut(&mut self) -> Option<&mut ChunkData> {
+        Some(self.chunk_data_mut())
+    }
+
     fn wait_for_receipt(&self) -> bool {
         true
     }
@@ -186,6 +190,7 @@ impl FromProtobuf<Vec<services::FileAppendTransactionBody>> for FileAppendTransa
                 chunk_size: NonZeroUsize::new(largest_chunk_size)
                     .unwrap_or_else(|| NonZeroUsize::new(1).unwrap()),
                 data: contents,
+                ..Default::default()
             },
         })
     }
@@ -14,6 +14,10 @@ use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -164,6 +168,11 @@ impl TransactionData for FileCreateTransactionData {
     fn default_max_transaction_fee(&self) -> crate::Hbar {
         crate::Hbar::new(5)
     }
+
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        validation::validate_memo("file_memo", &self.file_memo, errors);
+        validation::validate_auto_renew_period("auto_renew_period", self.auto_renew_period, errors);
+    }
 }
 
 impl TransactionExecute for FileCreateTransactionData {
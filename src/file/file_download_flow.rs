@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use tokio::io::{
+    AsyncWrite,
+    AsyncWriteExt,
+};
+
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    FileContentsQuery,
+    FileHash,
+    FileId,
+};
+
+/// Download the contents of a file into an [`AsyncWrite`], optionally checking them against an expected hash.
+///
+/// When an expected hash is set, the contents are only written once they've been checked,
+/// so a mismatched download never reaches the writer.
+#[derive(Default, Debug, Clone)]
+pub struct FileDownloadFlow {
+    file_id: Option<FileId>,
+    expected_hash: Option<FileHash>,
+    node_account_ids: Option<Vec<AccountId>>,
+}
+
+impl FileDownloadFlow {
+    /// Create a new `FileDownloadFlow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the ID of the file to download.
+    #[must_use]
+    pub fn get_file_id(&self) -> Option<FileId> {
+        self.file_id
+    }
+
+    /// Sets the ID of the file to download.
+    pub fn file_id(&mut self, id: impl Into<FileId>) -> &mut Self {
+        self.file_id = Some(id.into());
+
+        self
+    }
+
+    /// Returns the SHA-384 hash the file's contents must have.
+    #[must_use]
+    pub fn get_expected_hash(&self) -> Option<FileHash> {
+        self.expected_hash
+    }
+
+    /// Sets the SHA-384 hash the file's contents must have.
+    pub fn expected_hash(&mut self, hash: FileHash) -> &mut Self {
+        self.expected_hash = Some(hash);
+
+        self
+    }
+
+    /// Returns the account IDs of the nodes the query may be submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the account IDs of the nodes the query may be submitted to.
+    ///
+    /// Defaults to the full list of nodes configured on the client.
+    pub fn node_account_ids(
+        &mut self,
+        node_account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> &mut Self {
+        self.node_account_ids = Some(node_account_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Downloads the file into `writer` and returns the hash of its contents.
+    ///
+    /// # Errors
+    /// - [`Error::FileHashMismatch`] if an expected hash is set and the contents don't match it.
+    /// - [`Error::Io`] if writing to `writer` fails.
+    /// - Any error from executing the query.
+    pub async fn execute<W>(&self, client: &Client, writer: &mut W) -> crate::Result<FileHash>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        self.execute_with_optional_timeout(client, writer, None).await
+    }
+
+    /// Downloads the file into `writer` and returns the hash of its contents.
+    ///
+    /// # Errors
+    /// - [`Error::FileHashMismatch`] if an expected hash is set and the contents don't match it.
+    /// - [`Error::Io`] if writing to `writer` fails.
+    /// - Any error from executing the query.
+    pub async fn execute_with_timeout<W>(
+        &self,
+        client: &Client,
+        writer: &mut W,
+        timeout: std::time::Duration,
+    ) -> crate::Result<FileHash>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        self.execute_with_optional_timeout(client, writer, Some(timeout)).await
+    }
+
+    async fn execute_with_optional_timeout<W>(
+        &self,
+        client: &Client,
+        writer: &mut W,
+        timeout: Option<std::time::Duration>,
+    ) -> crate::Result<FileHash>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let mut query = FileContentsQuery::new();
+
+        if let Some(file_id) = self.file_id {
+            query.file_id(file_id);
+        }
+
+        if let Some(node_account_ids) = self.node_account_ids.clone() {
+            query.node_account_ids(node_account_ids);
+        }
+
+        let response = query.execute_with_optional_timeout(client, timeout).await?;
+
+        let actual = FileHash::new(&response.contents);
+
+        if let Some(expected) = self.expected_hash {
+            if expected != actual {
+                return Err(Error::FileHashMismatch {
+                    file_id: Box::new(response.file_id),
+                    expected: Box::new(expected),
+                    actual: Box::new(actual),
+                });
+            }
+        }
+
+        writer.write_all(&response.contents).await?;
+        writer.flush().await?;
+
+        Ok(actual)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        FileDownloadFlow,
+        FileHash,
+        FileId,
+    };
+
+    #[test]
+    fn get_set_file_id() {
+        let mut flow = FileDownloadFlow::new();
+        flow.file_id(FileId::new(0, 0, 150));
+
+        assert_eq!(flow.get_file_id(), Some(FileId::new(0, 0, 150)));
+    }
+
+    #[test]
+    fn get_set_expected_hash() {
+        let hash = FileHash::new(b"hello");
+
+        let mut flow = FileDownloadFlow::new();
+        flow.expected_hash(hash);
+
+        assert_eq!(flow.get_expected_hash(), Some(hash));
+    }
+}
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::{
+    self,
+    Debug,
+    Display,
+    Formatter,
+};
+
+use sha2::{
+    Digest,
+    Sha384,
+};
+
+/// The SHA-384 hash of a file's contents.
+///
+/// Used by [`FileUploadFlow`](crate::FileUploadFlow) and [`FileDownloadFlow`](crate::FileDownloadFlow)
+/// to check that the contents stored on the network are the expected ones.
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FileHash(pub [u8; 48]);
+
+impl FileHash {
+    /// Returns the hash of `contents`.
+    #[must_use]
+    pub fn new(contents: &[u8]) -> Self {
+        Self(Sha384::digest(contents).into())
+    }
+}
+
+impl Debug for FileHash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{self}\"")
+    }
+}
+
+impl Display for FileHash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad(&hex::encode(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FileHash;
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            FileHash::new(b"").to_string(),
+            "38b060a751ac96384cd9327eb1b1e36a21fdb71114be07434c0cc7bf63f6e1da274edebfe76f65fbd51ad2f14898b95b"
+        );
+    }
+}
@@ -13,6 +13,10 @@ use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -167,7 +171,14 @@ impl FileUpdateTransaction {
     }
 }
 
-impl TransactionData for FileUpdateTransactionData {}
+impl TransactionData for FileUpdateTransactionData {
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        if let Some(memo) = &self.file_memo {
+            validation::validate_memo("file_memo", memo, errors);
+        }
+        validation::validate_auto_renew_period("auto_renew_period", self.auto_renew_period, errors);
+    }
+}
 
 impl TransactionExecute for FileUpdateTransactionData {
     fn execute(
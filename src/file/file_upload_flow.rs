@@ -0,0 +1,346 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::num::NonZeroUsize;
+
+use time::OffsetDateTime;
+
+use crate::signer::AnySigner;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    FileAppendTransaction,
+    FileContentsQuery,
+    FileCreateTransaction,
+    FileHash,
+    FileId,
+    Key,
+    PrivateKey,
+    PublicKey,
+};
+
+/// Upload a file of any size, and check that the network stored it correctly.
+///
+/// The operation of this flow is as follows:
+/// 1. Create the file with the first chunk of its contents (via a [`FileCreateTransaction`]).
+/// 2. Append the remaining contents to the file (via a chunked [`FileAppendTransaction`]).
+/// 3. Read the file back (via a [`FileContentsQuery`]) and check that the SHA-384 hash of its contents
+///    matches the hash of the uploaded contents.
+#[derive(Default, Debug)]
+pub struct FileUploadFlow {
+    contents: Vec<u8>,
+    keys: Option<Vec<Key>>,
+    file_memo: String,
+    expiration_time: Option<OffsetDateTime>,
+    chunk_size: Option<NonZeroUsize>,
+    node_account_ids: Option<Vec<AccountId>>,
+    signers: Vec<AnySigner>,
+}
+
+impl FileUploadFlow {
+    /// The default size of each chunk, matches the default of [`FileAppendTransaction`].
+    const DEFAULT_CHUNK_SIZE: NonZeroUsize = match NonZeroUsize::new(4096) {
+        Some(it) => it,
+        None => unreachable!(),
+    };
+
+    /// Create a new `FileUploadFlow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the contents to upload.
+    #[must_use]
+    pub fn get_contents(&self) -> &[u8] {
+        &self.contents
+    }
+
+    /// Sets the contents to upload.
+    pub fn contents(&mut self, contents: impl Into<Vec<u8>>) -> &mut Self {
+        self.contents = contents.into();
+
+        self
+    }
+
+    /// Returns the keys that must sign to modify or delete the file.
+    #[must_use]
+    pub fn get_keys(&self) -> Option<&[Key]> {
+        self.keys.as_deref()
+    }
+
+    /// Sets the keys that must sign to modify or delete the file.
+    ///
+    /// Defaults to the client's operator key.
+    pub fn keys<K: Into<Key>>(&mut self, keys: impl IntoIterator<Item = K>) -> &mut Self {
+        self.keys = Some(keys.into_iter().map(Into::into).collect());
+
+        self
+    }
+
+    /// Returns the memo associated with the file.
+    #[must_use]
+    pub fn get_file_memo(&self) -> &str {
+        &self.file_memo
+    }
+
+    /// Sets the memo associated with the file.
+    pub fn file_memo(&mut self, memo: impl Into<String>) -> &mut Self {
+        self.file_memo = memo.into();
+
+        self
+    }
+
+    /// Returns the time at which the file will expire.
+    #[must_use]
+    pub fn get_expiration_time(&self) -> Option<OffsetDateTime> {
+        self.expiration_time
+    }
+
+    /// Sets the time at which the file will expire.
+    pub fn expiration_time(&mut self, at: OffsetDateTime) -> &mut Self {
+        self.expiration_time = Some(at);
+
+        self
+    }
+
+    /// Returns the size of each chunk the contents are split into.
+    #[must_use]
+    pub fn get_chunk_size(&self) -> usize {
+        self.chunk_size.unwrap_or(Self::DEFAULT_CHUNK_SIZE).get()
+    }
+
+    /// Sets the size of each chunk the contents are split into.
+    ///
+    /// # Panics
+    /// - If `size` is 0.
+    pub fn chunk_size(&mut self, size: usize) -> &mut Self {
+        self.chunk_size = Some(NonZeroUsize::new(size).expect("chunk size must be non-zero"));
+
+        self
+    }
+
+    /// Returns the account IDs of the nodes the transactions and query may be submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the account IDs of the nodes the transactions and query may be submitted to.
+    ///
+    /// Defaults to the full list of nodes configured on the client.
+    pub fn node_account_ids(
+        &mut self,
+        node_account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> &mut Self {
+        self.node_account_ids = Some(node_account_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Adds a signer to the file create and append transactions, for keys other than the operator's.
+    pub fn sign(&mut self, key: PrivateKey) -> &mut Self {
+        self.signers.push(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Adds a signer to the file create and append transactions, for keys other than the operator's.
+    pub fn sign_with<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.signers.push(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Uploads the file and returns its ID.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`] if no keys are set and the client has no operator.
+    /// - [`Error::FileHashMismatch`] if the file's contents don't match the uploaded contents.
+    /// - Any error from executing the transactions or the query.
+    pub async fn execute(&self, client: &Client) -> crate::Result<FileId> {
+        self.execute_with_optional_timeout(client, None).await
+    }
+
+    /// Uploads the file and returns its ID.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`] if no keys are set and the client has no operator.
+    /// - [`Error::FileHashMismatch`] if the file's contents don't match the uploaded contents.
+    /// - Any error from executing the transactions or the query.
+    pub async fn execute_with_timeout(
+        &self,
+        client: &Client,
+        timeout_per_request: std::time::Duration,
+    ) -> crate::Result<FileId> {
+        self.execute_with_optional_timeout(client, Some(timeout_per_request)).await
+    }
+
+    async fn execute_with_optional_timeout(
+        &self,
+        client: &Client,
+        timeout_per_request: Option<std::time::Duration>,
+    ) -> crate::Result<FileId> {
+        let keys = match &self.keys {
+            Some(keys) => keys.clone(),
+            None => {
+                let operator_public_key = client
+                    .load_operator()
+                    .as_deref()
+                    .map(|it| it.signer.public_key())
+                    .ok_or(Error::NoPayerAccountOrTransactionId)?;
+
+                Vec::from([Key::Single(operator_public_key)])
+            }
+        };
+
+        let chunk_size = self.get_chunk_size();
+        let (create_contents, append_contents) =
+            self.contents.split_at(self.contents.len().min(chunk_size));
+
+        let file_id = self
+            .make_file_create_transaction(create_contents, keys)
+            .execute_with_optional_timeout(client, timeout_per_request)
+            .await?
+            .get_receipt_query()
+            .execute_with_optional_timeout(client, timeout_per_request)
+            .await?
+            .file_id
+            .expect("Creating a file means there's a file ID");
+
+        if !append_contents.is_empty() {
+            // note: FileAppendTransaction already waits for receipts, so the file is complete once this returns.
+            self.make_file_append_transaction(file_id, append_contents, chunk_size)
+                .execute_all_with_optional_timeout(client, timeout_per_request)
+                .await?;
+        }
+
+        let mut query = FileContentsQuery::new();
+
+        query.file_id(file_id);
+
+        if let Some(node_account_ids) = self.node_account_ids.clone() {
+            query.node_account_ids(node_account_ids);
+        }
+
+        let contents =
+            query.execute_with_optional_timeout(client, timeout_per_request).await?.contents;
+
+        let expected = FileHash::new(&self.contents);
+        let actual = FileHash::new(&contents);
+
+        if expected != actual {
+            return Err(Error::FileHashMismatch {
+                file_id: Box::new(file_id),
+                expected: Box::new(expected),
+                actual: Box::new(actual),
+            });
+        }
+
+        Ok(file_id)
+    }
+
+    fn make_file_create_transaction(
+        &self,
+        contents: &[u8],
+        keys: Vec<Key>,
+    ) -> FileCreateTransaction {
+        let mut tmp = FileCreateTransaction::new();
+
+        tmp.contents(contents).keys(keys).file_memo(self.file_memo.clone());
+
+        if let Some(expiration_time) = self.expiration_time {
+            tmp.expiration_time(expiration_time);
+        }
+
+        if let Some(node_account_ids) = self.node_account_ids.clone() {
+            tmp.node_account_ids(node_account_ids);
+        }
+
+        for signer in &self.signers {
+            tmp.sign_signer(signer.clone());
+        }
+
+        tmp
+    }
+
+    fn make_file_append_transaction(
+        &self,
+        file_id: FileId,
+        contents: &[u8],
+        chunk_size: usize,
+    ) -> FileAppendTransaction {
+        let mut tmp = FileAppendTransaction::new();
+
+        tmp.file_id(file_id)
+            .contents(contents)
+            .chunk_size(chunk_size)
+            .max_chunks(contents.len().div_ceil(chunk_size));
+
+        if let Some(node_account_ids) = self.node_account_ids.clone() {
+            tmp.node_account_ids(node_account_ids);
+        }
+
+        for signer in &self.signers {
+            tmp.sign_signer(signer.clone());
+        }
+
+        tmp
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AccountId,
+        FileUploadFlow,
+        PrivateKey,
+    };
+
+    #[test]
+    fn get_set_contents() {
+        let mut flow = FileUploadFlow::new();
+        flow.contents(b"hello".to_vec());
+
+        assert_eq!(flow.get_contents(), b"hello");
+    }
+
+    #[test]
+    fn get_set_keys() {
+        let key = PrivateKey::generate_ed25519().public_key();
+
+        let mut flow = FileUploadFlow::new();
+        flow.keys([key]);
+
+        assert_eq!(flow.get_keys(), Some(&[key.into()][..]));
+    }
+
+    #[test]
+    fn get_set_chunk_size() {
+        let mut flow = FileUploadFlow::new();
+        assert_eq!(flow.get_chunk_size(), 4096);
+
+        flow.chunk_size(1024);
+        assert_eq!(flow.get_chunk_size(), 1024);
+    }
+
+    #[test]
+    #[should_panic]
+    fn chunk_size_zero_panics() {
+        FileUploadFlow::new().chunk_size(0);
+    }
+
+    #[test]
+    fn get_set_node_account_ids() {
+        let node_account_ids = [AccountId::new(0, 0, 3), AccountId::new(0, 0, 4)];
+
+        let mut flow = FileUploadFlow::new();
+        flow.node_account_ids(node_account_ids);
+
+        assert_eq!(flow.get_node_account_ids(), Some(&node_account_ids[..]));
+    }
+}
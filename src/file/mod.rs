@@ -5,10 +5,13 @@ mod file_contents_query;
 mod file_contents_response;
 mod file_create_transaction;
 mod file_delete_transaction;
+mod file_download_flow;
+mod file_hash;
 mod file_id;
 mod file_info;
 mod file_info_query;
 mod file_update_transaction;
+mod file_upload_flow;
 
 pub use file_append_transaction::FileAppendTransaction;
 pub(crate) use file_append_transaction::FileAppendTransactionData;
@@ -19,9 +22,12 @@ pub use file_create_transaction::FileCreateTransaction;
 pub(crate) use file_create_transaction::FileCreateTransactionData;
 pub use file_delete_transaction::FileDeleteTransaction;
 pub(crate) use file_delete_transaction::FileDeleteTransactionData;
+pub use file_download_flow::FileDownloadFlow;
+pub use file_hash::FileHash;
 pub use file_id::FileId;
 pub use file_info::FileInfo;
 pub use file_info_query::FileInfoQuery;
 pub(crate) use file_info_query::FileInfoQueryData;
 pub use file_update_transaction::FileUpdateTransaction;
 pub(crate) use file_update_transaction::FileUpdateTransactionData;
+pub use file_upload_flow::FileUploadFlow;
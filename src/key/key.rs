@@ -1,8 +1,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+
 use hedera_proto::services;
 
+use super::key_verification::{
+    self,
+    KeyVerification,
+};
 use crate::contract::DelegateContractId;
+use crate::transaction::TransactionExecute;
 use crate::{
     ContractId,
     Error,
@@ -10,6 +17,7 @@ use crate::{
     KeyList,
     PublicKey,
     ToProtobuf,
+    Transaction,
 };
 
 /// Any method that can be used to authorize an operation on Hiero.
@@ -36,6 +44,78 @@ impl Key {
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Verify `message` against this key using the given `signatures`.
+    ///
+    /// Every [`KeyList`] in the key is evaluated according to its `threshold`
+    /// (or requires all of its keys if there is no threshold).
+    ///
+    /// Contract ID leaves can't be proven by signatures and are reported as
+    /// [`Unverifiable`](crate::KeyLeafStatus::Unverifiable).
+    #[must_use]
+    pub fn verify(
+        &self,
+        message: &[u8],
+        signatures: &HashMap<PublicKey, Vec<u8>>,
+    ) -> KeyVerification {
+        key_verification::verify_key(self, message, |key| {
+            key_verification::find_signature_in_hash_map(signatures, key)
+        })
+    }
+
+    /// Verify the signatures on the given transaction against this key.
+    ///
+    /// A leaf is only considered verified if it has a valid signature for *every* node and chunk of the transaction.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the transaction isn't frozen (or parsed from bytes),
+    ///   since its body bytes, and therefore its signatures, can still change.
+    pub fn verify_transaction<D: TransactionExecute>(
+        &self,
+        transaction: &Transaction<D>,
+    ) -> crate::Result<KeyVerification> {
+        if !transaction.is_frozen() && transaction.sources().is_none() {
+            return Err(Error::signature_verify(
+                "transaction must be frozen before its signatures can be verified",
+            ));
+        }
+
+        let sources = transaction.make_sources()?;
+
+        // transactions built locally always have a `SignedTransaction`,
+        // but transactions parsed from bytes may only have the (deprecated) unwrapped body and signature map.
+        #[allow(deprecated)]
+        let parts: Vec<(&[u8], Option<&services::SignatureMap>)> =
+            if sources.signed_transactions().is_empty() {
+                sources
+                    .transactions()
+                    .iter()
+                    .map(|it| (it.body_bytes.as_slice(), it.sig_map.as_ref()))
+                    .collect()
+            } else {
+                sources
+                    .signed_transactions()
+                    .iter()
+                    .map(|it| (it.body_bytes.as_slice(), it.sig_map.as_ref()))
+                    .collect()
+            };
+
+        let default_sig_map = services::SignatureMap::default();
+
+        let verification = parts
+            .into_iter()
+            .map(|(body_bytes, sig_map)| {
+                let sig_map = sig_map.unwrap_or(&default_sig_map);
+
+                key_verification::verify_key(self, body_bytes, |key| {
+                    key_verification::find_signature_in_map(sig_map, key)
+                })
+            })
+            .reduce(KeyVerification::merge);
+
+        verification
+            .ok_or_else(|| Error::signature_verify("transaction has no signed transactions"))
+    }
 }
 
 impl ToProtobuf for Key {
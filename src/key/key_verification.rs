@@ -0,0 +1,447 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use hedera_proto::services;
+
+use crate::{
+    Key,
+    KeyList,
+    PublicKey,
+};
+
+/// The outcome of checking a single leaf of a [`Key`] against a set of signatures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyLeafStatus {
+    /// A valid signature from this key was found.
+    Verified,
+
+    /// No signature from this key was found.
+    Missing,
+
+    /// A signature from this key was found, but it doesn't verify.
+    Invalid,
+
+    /// This leaf can't be checked against signatures (IE, a contract ID).
+    ///
+    /// Unverifiable leaves never count towards satisfying a key.
+    Unverifiable,
+}
+
+/// A single leaf of a [`Key`] along with its verification status.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyLeafVerification {
+    /// The indices of the [`KeyList`]s that lead from the root key to this leaf.
+    ///
+    /// This is empty if the root key is itself a leaf.
+    pub path: Vec<usize>,
+
+    /// The leaf key.
+    pub key: Key,
+
+    /// Whether or not the leaf was verified.
+    pub status: KeyLeafStatus,
+}
+
+/// The result of verifying a [`Key`] against a set of signatures.
+///
+/// See [`Key::verify`] and [`Key::verify_transaction`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyVerification {
+    is_satisfied: bool,
+    leaves: Vec<KeyLeafVerification>,
+}
+
+impl KeyVerification {
+    /// Returns `true` if the signatures satisfy the key as a whole, including all thresholds.
+    #[must_use]
+    pub fn is_satisfied(&self) -> bool {
+        self.is_satisfied
+    }
+
+    /// Returns every leaf of the key, in depth-first order, with its status.
+    #[must_use]
+    pub fn leaves(&self) -> &[KeyLeafVerification] {
+        &self.leaves
+    }
+
+    /// Returns the public keys that had a valid signature.
+    pub fn verified_keys(&self) -> impl Iterator<Item = &PublicKey> {
+        self.leaves.iter().filter_map(|leaf| match (&leaf.key, leaf.status) {
+            (Key::Single(key), KeyLeafStatus::Verified) => Some(key),
+            _ => None,
+        })
+    }
+
+    /// Combine the verification of the same key over multiple messages.
+    ///
+    /// A leaf only stays verified if it was verified for every message.
+    pub(super) fn merge(self, other: Self) -> Self {
+        debug_assert_eq!(self.leaves.len(), other.leaves.len());
+
+        let leaves = self
+            .leaves
+            .into_iter()
+            .zip(other.leaves)
+            .map(|(lhs, rhs)| {
+                let status = match (lhs.status, rhs.status) {
+                    (KeyLeafStatus::Unverifiable, _) | (_, KeyLeafStatus::Unverifiable) => {
+                        KeyLeafStatus::Unverifiable
+                    }
+                    (KeyLeafStatus::Invalid, _) | (_, KeyLeafStatus::Invalid) => {
+                        KeyLeafStatus::Invalid
+                    }
+                    (KeyLeafStatus::Missing, _) | (_, KeyLeafStatus::Missing) => {
+                        KeyLeafStatus::Missing
+                    }
+                    (KeyLeafStatus::Verified, KeyLeafStatus::Verified) => KeyLeafStatus::Verified,
+                };
+
+                KeyLeafVerification { status, ..lhs }
+            })
+            .collect();
+
+        Self { is_satisfied: self.is_satisfied && other.is_satisfied, leaves }
+    }
+}
+
+/// Walks a [`Key`] tree, looking up signatures with `find_signature`.
+pub(super) fn verify_key<'a>(
+    key: &Key,
+    message: &[u8],
+    find_signature: impl Fn(&PublicKey) -> Option<Option<&'a [u8]>>,
+) -> KeyVerification {
+    let mut leaves = Vec::new();
+    let mut path = Vec::new();
+
+    let is_satisfied = visit(key, message, &find_signature, &mut path, &mut leaves);
+
+    KeyVerification { is_satisfied, leaves }
+}
+
+fn visit<'a>(
+    key: &Key,
+    message: &[u8],
+    find_signature: &impl Fn(&PublicKey) -> Option<Option<&'a [u8]>>,
+    path: &mut Vec<usize>,
+    leaves: &mut Vec<KeyLeafVerification>,
+) -> bool {
+    let status = match key {
+        Key::KeyList(list) => return visit_list(list, message, find_signature, path, leaves),
+        Key::Single(public_key) => match find_signature(public_key) {
+            None => KeyLeafStatus::Missing,
+            // a signature was present, but not of a supported type.
+            Some(None) => KeyLeafStatus::Invalid,
+            Some(Some(signature)) => match public_key.verify(message, signature) {
+                Ok(()) => KeyLeafStatus::Verified,
+                Err(_) => KeyLeafStatus::Invalid,
+            },
+        },
+        Key::ContractId(_) | Key::DelegateContractId(_) => KeyLeafStatus::Unverifiable,
+    };
+
+    leaves.push(KeyLeafVerification { path: path.clone(), key: key.clone(), status });
+
+    status == KeyLeafStatus::Verified
+}
+
+fn visit_list<'a>(
+    list: &KeyList,
+    message: &[u8],
+    find_signature: &impl Fn(&PublicKey) -> Option<Option<&'a [u8]>>,
+    path: &mut Vec<usize>,
+    leaves: &mut Vec<KeyLeafVerification>,
+) -> bool {
+    let mut satisfied = 0;
+
+    // note: every child is visited (rather than short circuiting) so that all leaves get reported.
+    for (index, key) in list.keys.iter().enumerate() {
+        path.push(index);
+
+        if visit(key, message, find_signature, path, leaves) {
+            satisfied += 1;
+        }
+
+        path.pop();
+    }
+
+    // a key list without a threshold requires *all* keys,
+    // an empty list (or a threshold of zero) can never be satisfied.
+    let required = list.threshold.map_or(list.keys.len(), |it| it as usize);
+
+    required > 0 && satisfied >= required
+}
+
+/// Looks up the signature for `key` in a protobuf signature map.
+///
+/// If more than one prefix matches, the longest one wins, since shorter prefixes may belong to other keys.
+///
+/// Returns `Some(None)` if there is a signature, but it's of an unsupported type.
+pub(super) fn find_signature_in_map<'a>(
+    sig_map: &'a services::SignatureMap,
+    key: &PublicKey,
+) -> Option<Option<&'a [u8]>> {
+    use services::signature_pair::Signature;
+
+    let key_bytes = key.to_bytes_raw();
+
+    let sig_pair = sig_map
+        .sig_pair
+        .iter()
+        .filter(|it| key_bytes.starts_with(&it.pub_key_prefix))
+        .max_by_key(|it| it.pub_key_prefix.len())?;
+
+    match &sig_pair.signature {
+        Some(Signature::Ed25519(sig)) if key.is_ed25519() => Some(Some(sig.as_slice())),
+        Some(Signature::EcdsaSecp256k1(sig)) if key.is_ecdsa() => Some(Some(sig.as_slice())),
+        _ => Some(None),
+    }
+}
+
+/// Looks up the signature for `key` in a map of signatures.
+pub(super) fn find_signature_in_hash_map<'a>(
+    signatures: &'a HashMap<PublicKey, Vec<u8>>,
+    key: &PublicKey,
+) -> Option<Option<&'a [u8]>> {
+    signatures.get(key).map(|it| Some(it.as_slice()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use assert_matches::assert_matches;
+    use hedera_proto::services;
+    use prost::Message;
+
+    use super::{
+        find_signature_in_map,
+        KeyLeafStatus,
+    };
+    use crate::{
+        AnyTransaction,
+        ContractId,
+        Error,
+        Key,
+        KeyList,
+        PrivateKey,
+        PublicKey,
+        TransferTransaction,
+    };
+
+    const MESSAGE: &[u8] = b"hello, world";
+
+    fn private_keys() -> [PrivateKey; 3] {
+        [
+            PrivateKey::from_str_ed25519(
+                "302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e10",
+            )
+            .unwrap(),
+            PrivateKey::from_str_ed25519(
+                "302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e11",
+            )
+            .unwrap(),
+            PrivateKey::from_str_ecdsa(
+                "8776c6b831a1b61ac10dac0304a2843de4716f54b1919bb91a2685d0fe3f3048",
+            )
+            .unwrap(),
+        ]
+    }
+
+    fn sign(keys: &[&PrivateKey]) -> HashMap<PublicKey, Vec<u8>> {
+        keys.iter().map(|it| (it.public_key(), it.sign(MESSAGE))).collect()
+    }
+
+    #[test]
+    fn single_key() {
+        let [a, b, _] = private_keys();
+
+        let key = Key::Single(a.public_key());
+
+        let verification = key.verify(MESSAGE, &sign(&[&a]));
+        assert!(verification.is_satisfied());
+        assert_eq!(verification.leaves().len(), 1);
+        assert_eq!(verification.leaves()[0].status, KeyLeafStatus::Verified);
+        assert!(verification.leaves()[0].path.is_empty());
+
+        let verification = key.verify(MESSAGE, &sign(&[&b]));
+        assert!(!verification.is_satisfied());
+        assert_eq!(verification.leaves()[0].status, KeyLeafStatus::Missing);
+    }
+
+    #[test]
+    fn invalid_signature() {
+        let [a, b, _] = private_keys();
+
+        let key = Key::Single(a.public_key());
+
+        let signatures = HashMap::from([(a.public_key(), b.sign(MESSAGE))]);
+
+        let verification = key.verify(MESSAGE, &signatures);
+        assert!(!verification.is_satisfied());
+        assert_eq!(verification.leaves()[0].status, KeyLeafStatus::Invalid);
+    }
+
+    #[test]
+    fn longest_prefix_wins() {
+        let [a, b, _] = private_keys();
+
+        let pair = |prefix: Vec<u8>, key: &PrivateKey| services::SignaturePair {
+            pub_key_prefix: prefix,
+            signature: Some(services::signature_pair::Signature::Ed25519(key.sign(MESSAGE))),
+        };
+
+        let key_bytes = a.public_key().to_bytes_raw();
+
+        // an empty prefix matches every key, but the full key is a better match.
+        let sig_map = services::SignatureMap {
+            sig_pair: vec![
+                pair(Vec::new(), &b),
+                pair(key_bytes.clone(), &a),
+                pair(key_bytes[..4].to_vec(), &b),
+            ],
+        };
+
+        assert_eq!(
+            find_signature_in_map(&sig_map, &a.public_key()),
+            Some(Some(a.sign(MESSAGE).as_slice()))
+        );
+    }
+
+    #[test]
+    fn key_list_requires_all() {
+        let [a, b, c] = private_keys();
+
+        let key = Key::from(KeyList::from([a.public_key(), b.public_key(), c.public_key()]));
+
+        assert!(!key.verify(MESSAGE, &sign(&[&a, &b])).is_satisfied());
+
+        let verification = key.verify(MESSAGE, &sign(&[&a, &b, &c]));
+        assert!(verification.is_satisfied());
+        assert_eq!(verification.verified_keys().count(), 3);
+    }
+
+    #[test]
+    fn nested_threshold() {
+        let [a, b, c] = private_keys();
+
+        // 1 of (a, 2 of (b, c))
+        let inner = KeyList {
+            keys: vec![b.public_key().into(), c.public_key().into()],
+            threshold: Some(2),
+        };
+        let key = Key::from(KeyList {
+            keys: vec![a.public_key().into(), inner.into()],
+            threshold: Some(1),
+        });
+
+        assert!(key.verify(MESSAGE, &sign(&[&a])).is_satisfied());
+        assert!(!key.verify(MESSAGE, &sign(&[&b])).is_satisfied());
+
+        let verification = key.verify(MESSAGE, &sign(&[&b, &c]));
+        assert!(verification.is_satisfied());
+
+        let paths: Vec<_> = verification.leaves().iter().map(|it| it.path.clone()).collect();
+        assert_eq!(paths, [vec![0], vec![1, 0], vec![1, 1]]);
+
+        let statuses: Vec<_> = verification.leaves().iter().map(|it| it.status).collect();
+        assert_eq!(
+            statuses,
+            [KeyLeafStatus::Missing, KeyLeafStatus::Verified, KeyLeafStatus::Verified]
+        );
+    }
+
+    #[test]
+    fn contract_id_is_unverifiable() {
+        let [a, _, _] = private_keys();
+
+        let key = Key::from(KeyList {
+            keys: vec![a.public_key().into(), ContractId::new(0, 0, 1001).into()],
+            threshold: Some(1),
+        });
+
+        let verification = key.verify(MESSAGE, &sign(&[&a]));
+
+        assert!(verification.is_satisfied());
+        assert_matches!(verification.leaves()[1].status, KeyLeafStatus::Unverifiable);
+
+        let key = Key::from(ContractId::new(0, 0, 1001));
+        assert!(!key.verify(MESSAGE, &sign(&[&a])).is_satisfied());
+    }
+
+    #[test]
+    fn empty_key_list_is_never_satisfied() {
+        let [a, _, _] = private_keys();
+
+        assert!(!Key::from(KeyList::new()).verify(MESSAGE, &sign(&[&a])).is_satisfied());
+    }
+
+    #[test]
+    fn transaction() {
+        let [a, b, c] = private_keys();
+
+        // `new_for_tests` signs with `a`.
+        let mut tx = TransferTransaction::new_for_tests();
+        tx.freeze().unwrap();
+
+        let key = Key::from(KeyList {
+            keys: vec![a.public_key().into(), b.public_key().into()],
+            threshold: Some(1),
+        });
+
+        let verification = key.verify_transaction(&tx).unwrap();
+        assert!(verification.is_satisfied());
+        assert_eq!(verification.verified_keys().collect::<Vec<_>>(), [&a.public_key()]);
+
+        let key = Key::from(KeyList::from([a.public_key(), c.public_key()]));
+        assert!(!key.verify_transaction(&tx).unwrap().is_satisfied());
+
+        tx.sign(c);
+        assert!(key.verify_transaction(&tx).unwrap().is_satisfied());
+    }
+
+    #[test]
+    fn transaction_not_frozen() {
+        let [a, _, _] = private_keys();
+
+        let tx = TransferTransaction::new_for_tests();
+
+        assert_matches!(
+            Key::from(a.public_key()).verify_transaction(&tx),
+            Err(Error::SignatureVerify(_))
+        );
+        assert!(!tx.is_frozen());
+    }
+
+    #[test]
+    fn transaction_deprecated_fields() {
+        let [a, b, _] = private_keys();
+
+        let mut tx = TransferTransaction::new_for_tests();
+        tx.freeze().unwrap();
+
+        // older SDKs put the body and signatures directly on the `Transaction` instead of a `SignedTransaction`.
+        #[allow(deprecated)]
+        let transaction_list = tx
+            .make_sources()
+            .unwrap()
+            .signed_transactions()
+            .iter()
+            .map(|it| services::Transaction {
+                body_bytes: it.body_bytes.clone(),
+                sig_map: it.sig_map.clone(),
+                ..Default::default()
+            })
+            .collect();
+
+        let bytes =
+            hedera_proto::sdk::TransactionList { transaction_list, draft: None }.encode_to_vec();
+        let tx = AnyTransaction::from_bytes(&bytes).unwrap();
+
+        assert!(tx.sources().unwrap().signed_transactions().is_empty());
+
+        assert!(Key::from(a.public_key()).verify_transaction(&tx).unwrap().is_satisfied());
+        assert!(!Key::from(b.public_key()).verify_transaction(&tx).unwrap().is_satisfied());
+    }
+}
@@ -3,11 +3,17 @@
 #[allow(clippy::module_inception)]
 mod key;
 mod key_list;
+mod key_verification;
 mod private_key;
 mod public_key;
 
 pub use key::Key;
 pub use key_list::KeyList;
+pub use key_verification::{
+    KeyLeafStatus,
+    KeyLeafVerification,
+    KeyVerification,
+};
 pub use private_key::PrivateKey;
 pub use public_key::PublicKey;
 
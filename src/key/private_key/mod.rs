@@ -151,6 +151,13 @@ impl PrivateKey {
         Self::new(key.into())
     }
 
+    pub(crate) fn as_ecdsa(&self) -> Option<&k256::ecdsa::SigningKey> {
+        match &self.0.data {
+            PrivateKeyData::Ed25519(_) => None,
+            PrivateKeyData::Ecdsa(key) => Some(key),
+        }
+    }
+
     /// Generates a new Ed25519 `PrivateKey`.
     #[must_use]
     pub fn generate_ed25519() -> Self {
@@ -588,6 +595,33 @@ impl PrivateKey {
         }
     }
 
+    /// Computes the secret this key shares with `public_key` with ECDH,
+    /// over X25519 (converted from the Edwards form) for Ed25519 keys, and over secp256k1 for ECDSA keys.
+    ///
+    /// Returns `None` if the keys use different algorithms, or `public_key` has a small order.
+    pub(crate) fn diffie_hellman(&self, public_key: &PublicKey) -> Option<[u8; 32]> {
+        match &self.0.data {
+            PrivateKeyData::Ed25519(key) => {
+                let shared_secret = public_key
+                    .as_ed25519()?
+                    .to_montgomery()
+                    .mul_clamped(key.to_scalar_bytes())
+                    .to_bytes();
+
+                // an all zero secret is what every key agrees on with a small order point.
+                (shared_secret != [0; 32]).then_some(shared_secret)
+            }
+            PrivateKeyData::Ecdsa(key) => {
+                let shared_secret = k256::ecdh::diffie_hellman(
+                    key.as_nonzero_scalar(),
+                    public_key.as_ecdsa()?.as_affine(),
+                );
+
+                shared_secret.raw_secret_bytes().as_slice().try_into().ok()
+            }
+        }
+    }
+
     // I question the reason for this function existing.
     /// Signs the given transaction.
     ///
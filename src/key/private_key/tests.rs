@@ -408,3 +408,24 @@ fn ecdsa_ec_private_key_no_public_key_der() {
         "03b69a75a5ddb1c0747e995d47555019e5d8a28003ab5202bd92f534361fb4ec8a"
     );
 }
+
+#[test]
+fn diffie_hellman_agrees() {
+    for (alice, bob) in [
+        (PrivateKey::generate_ed25519(), PrivateKey::generate_ed25519()),
+        (PrivateKey::generate_ecdsa(), PrivateKey::generate_ecdsa()),
+    ] {
+        let shared_secret = alice.diffie_hellman(&bob.public_key()).unwrap();
+
+        assert_eq!(bob.diffie_hellman(&alice.public_key()), Some(shared_secret));
+    }
+}
+
+#[test]
+fn diffie_hellman_mixed_algorithms() {
+    let ed25519 = PrivateKey::generate_ed25519();
+    let ecdsa = PrivateKey::generate_ecdsa();
+
+    assert_eq!(ed25519.diffie_hellman(&ecdsa.public_key()), None);
+    assert_eq!(ecdsa.diffie_hellman(&ed25519.public_key()), None);
+}
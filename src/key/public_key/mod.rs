@@ -85,10 +85,24 @@ impl PublicKey {
         Self(PublicKeyData::Ed25519(key))
     }
 
-    pub(super) fn ecdsa(key: k256::ecdsa::VerifyingKey) -> Self {
+    pub(crate) fn ecdsa(key: k256::ecdsa::VerifyingKey) -> Self {
         Self(PublicKeyData::Ecdsa(key))
     }
 
+    pub(super) fn as_ed25519(&self) -> Option<&ed25519_dalek::VerifyingKey> {
+        match &self.0 {
+            PublicKeyData::Ed25519(key) => Some(key),
+            PublicKeyData::Ecdsa(_) => None,
+        }
+    }
+
+    pub(super) fn as_ecdsa(&self) -> Option<&k256::ecdsa::VerifyingKey> {
+        match &self.0 {
+            PublicKeyData::Ed25519(_) => None,
+            PublicKeyData::Ecdsa(key) => Some(key),
+        }
+    }
+
     /// Returns `true` if the public key is `Ed25519`.
     #[must_use]
     pub fn is_ed25519(&self) -> bool {
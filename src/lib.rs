@@ -111,6 +111,7 @@ mod error;
 mod ethereum;
 mod exchange_rates;
 mod execute;
+mod fee_estimate;
 mod fee_schedules;
 mod file;
 mod hbar;
@@ -164,6 +165,10 @@ pub use account::{
     AccountRecordsQuery,
     AccountUpdateTransaction,
     AllProxyStakers,
+    LiveHash,
+    LiveHashAddTransaction,
+    LiveHashDeleteTransaction,
+    LiveHashQuery,
     ProxyStaker,
 };
 pub use address_book::{
@@ -174,21 +179,39 @@ pub use address_book::{
 pub use client::Client;
 pub(crate) use client::Operator;
 pub use contract::{
+    AbiConstructor,
+    AbiError,
+    AbiEvent,
+    AbiEventParam,
+    AbiFunction,
+    AbiParam,
+    AbiStateMutability,
+    AbiType,
+    AbiValue,
+    CompiledContract,
+    ContractAbi,
     ContractBytecodeQuery,
     ContractCallQuery,
     ContractCreateFlow,
     ContractCreateTransaction,
     ContractDeleteTransaction,
+    ContractEvent,
     ContractExecuteTransaction,
     ContractFunctionParameters,
     ContractFunctionResult,
+    ContractFunctionSelector,
     ContractId,
     ContractInfo,
     ContractInfoQuery,
     ContractLogInfo,
     ContractNonceInfo,
+    ContractRevertReason,
     ContractUpdateTransaction,
+    ContractVerification,
     DelegateContractId,
+    GasEstimate,
+    GasEstimator,
+    PanicCode,
 };
 pub use custom_fixed_fee::CustomFixedFee;
 pub use entity_id::EntityId;
@@ -196,6 +219,7 @@ pub(crate) use entity_id::ValidateChecksums;
 pub use error::{
     Error,
     Result,
+    TopicMessageVerificationError,
 };
 #[cfg(feature = "mnemonic")]
 pub use error::{
@@ -203,8 +227,13 @@ pub use error::{
     MnemonicParseError,
 };
 pub use ethereum::{
+    AccessListItem,
     Eip1559EthereumData,
+    Eip2930EthereumData,
+    Eip7702Authorization,
+    Eip7702EthereumData,
     EthereumData,
+    EthereumDataBuilder,
     EthereumFlow,
     EthereumTransaction,
     EvmAddress,
@@ -214,6 +243,11 @@ pub use exchange_rates::{
     ExchangeRate,
     ExchangeRates,
 };
+pub use fee_estimate::{
+    FeeEstimate,
+    FeeEstimateComponent,
+    FeeEstimator,
+};
 pub use fee_schedules::{
     FeeComponents,
     FeeData,
@@ -229,10 +263,13 @@ pub use file::{
     FileContentsResponse,
     FileCreateTransaction,
     FileDeleteTransaction,
+    FileDownloadFlow,
+    FileHash,
     FileId,
     FileInfo,
     FileInfoQuery,
     FileUpdateTransaction,
+    FileUploadFlow,
 };
 pub use hbar::{
     Hbar,
@@ -242,7 +279,10 @@ pub use hbar::{
 pub use hedera_proto::services::ResponseCodeEnum as Status;
 pub use key::{
     Key,
+    KeyLeafStatus,
+    KeyLeafVerification,
     KeyList,
+    KeyVerification,
     PrivateKey,
     PublicKey,
 };
@@ -335,20 +375,56 @@ pub use token::{
     TokenUpdateTransaction,
     TokenWipeTransaction,
 };
+#[cfg(feature = "serde")]
+pub use topic::JsonCodec;
 pub use topic::{
+    DecryptedTopicMessage,
+    EncryptedTopicPublisher,
+    EncryptedTopicSubscriber,
+    FileCheckpointStore,
+    IncompleteTopicMessage,
+    IncompleteTopicMessagePolicy,
+    IncompleteTopicMessageReason,
+    ProtobufCodec,
+    TopicCheckpoint,
+    TopicCheckpointStore,
+    TopicCheckpointStoreError,
     TopicCreateTransaction,
     TopicDeleteTransaction,
     TopicId,
     TopicInfo,
     TopicInfoQuery,
     TopicMessage,
+    TopicMessageCodec,
+    TopicMessageCodecError,
+    TopicMessageEnvelope,
     TopicMessageQuery,
     TopicMessageSubmitTransaction,
+    TopicPublisher,
+    TopicSubscriber,
+    TopicSubscriptionManager,
     TopicUpdateTransaction,
+    TypedTopicMessage,
 };
 pub use transaction::{
+    AccountChange,
+    AllowanceAmount,
+    AllowanceDescription,
     AnyTransaction,
+    ChunkedExecution,
+    EthereumTransactionDescription,
+    ExecutedChunk,
+    HbarMovementDescription,
+    HbarMovementParty,
+    HbarTransferDescription,
+    KeyChange,
+    SignatureDescription,
+    TokenSupplyChange,
+    TokenTransferDescription,
     Transaction,
+    TransactionDescription,
+    ValidationError,
+    ValidationErrorKind,
 };
 pub use transaction_hash::TransactionHash;
 pub use transaction_id::TransactionId;
@@ -361,6 +437,28 @@ pub use transaction_response::TransactionResponse;
 pub use transfer::Transfer;
 pub use transfer_transaction::TransferTransaction;
 
+/// Support for the contract bindings generated by `hedera-contract-macros`, not public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use num_bigint;
+
+    pub use crate::contract::abi::bindings::*;
+    pub use crate::{
+        AbiEvent,
+        AbiEventParam,
+        AbiType,
+        AbiValue,
+        ContractCallQuery,
+        ContractCreateFlow,
+        ContractExecuteTransaction,
+        ContractFunctionResult,
+        ContractId,
+        ContractLogInfo,
+        EvmAddress,
+        Result,
+    };
+}
+
 /// Like [`arc_swap::ArcSwapOption`] but with a [`triomphe::Arc`].
 pub(crate) type ArcSwapOption<T> = arc_swap::ArcSwapAny<Option<triomphe::Arc<T>>>;
 
@@ -125,14 +125,14 @@ impl MirrorRequest for NodeAddressBookQueryData {
         })
     }
 
-    fn make_item_stream<'a, S>(stream: S) -> Self::ItemStream<'a>
+    fn make_item_stream<'a, S>(&self, stream: S) -> Self::ItemStream<'a>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a,
     {
         Box::pin(Self::map_stream(stream))
     }
 
-    fn try_collect<'a, S>(stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
+    fn try_collect<'a, S>(&self, stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a,
     {
@@ -169,12 +169,9 @@ impl NodeAddressBookQuery {
             std::time::Duration::from_millis(backoff::default::MAX_ELAPSED_TIME_MILLIS)
         });
 
-        NodeAddressBookQueryData::try_collect(crate::mirror_query::subscribe(
-            channel,
-            timeout,
-            self.data.clone(),
-        ))
-        .await
+        self.data
+            .try_collect(crate::mirror_query::subscribe(channel, timeout, self.data.clone()))
+            .await
     }
 }
 
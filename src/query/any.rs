@@ -8,6 +8,7 @@ use crate::account::{
     AccountBalanceQueryData,
     AccountInfoQueryData,
     AccountRecordsQueryData,
+    LiveHashQueryData,
 };
 use crate::contract::{
     ContractBytecodeQueryData,
@@ -40,6 +41,7 @@ use crate::{
     FileInfo,
     FromProtobuf,
     Hbar,
+    LiveHash,
     NetworkVersionInfo,
     NetworkVersionInfoQueryData,
     Query,
@@ -60,6 +62,7 @@ pub enum AnyQueryData {
     AccountBalance(AccountBalanceQueryData),
     AccountInfo(AccountInfoQueryData),
     AccountRecords(AccountRecordsQueryData),
+    LiveHash(LiveHashQueryData),
     TransactionReceipt(TransactionReceiptQueryData),
     TransactionRecord(TransactionRecordQueryData),
     FileContents(FileContentsQueryData),
@@ -90,6 +93,9 @@ pub enum AnyQueryResponse {
     /// Response from [`AccountRecordsQuery`](crate::AccountRecordsQuery).
     AccountRecords(Vec<TransactionRecord>),
 
+    /// Response from [`LiveHashQuery`](crate::LiveHashQuery).
+    LiveHash(LiveHash),
+
     /// Response from [`TransactionReceiptQuery`](crate::TransactionReceiptQuery).
     TransactionReceipt(TransactionReceipt),
 
@@ -133,6 +139,7 @@ impl ToQueryProtobuf for AnyQueryData {
             Self::AccountBalance(data) => data.to_query_protobuf(header),
             Self::AccountInfo(data) => data.to_query_protobuf(header),
             Self::AccountRecords(data) => data.to_query_protobuf(header),
+            Self::LiveHash(data) => data.to_query_protobuf(header),
             Self::TransactionReceipt(data) => data.to_query_protobuf(header),
             Self::TransactionRecord(data) => data.to_query_protobuf(header),
             Self::FileContents(data) => data.to_query_protobuf(header),
@@ -157,6 +164,7 @@ impl QueryExecute for AnyQueryData {
             Self::AccountInfo(query) => query.is_payment_required(),
             Self::AccountBalance(query) => query.is_payment_required(),
             Self::AccountRecords(query) => query.is_payment_required(),
+            Self::LiveHash(query) => query.is_payment_required(),
             Self::TransactionReceipt(query) => query.is_payment_required(),
             Self::TransactionRecord(query) => query.is_payment_required(),
             Self::FileContents(query) => query.is_payment_required(),
@@ -177,6 +185,7 @@ impl QueryExecute for AnyQueryData {
             Self::AccountInfo(query) => query.map_cost(cost),
             Self::AccountBalance(query) => query.map_cost(cost),
             Self::AccountRecords(query) => query.map_cost(cost),
+            Self::LiveHash(query) => query.map_cost(cost),
             Self::TransactionReceipt(query) => query.map_cost(cost),
             Self::TransactionRecord(query) => query.map_cost(cost),
             Self::FileContents(query) => query.map_cost(cost),
@@ -201,6 +210,7 @@ impl QueryExecute for AnyQueryData {
             Self::AccountInfo(query) => query.execute(channel, request),
             Self::AccountBalance(query) => query.execute(channel, request),
             Self::AccountRecords(query) => query.execute(channel, request),
+            Self::LiveHash(query) => query.execute(channel, request),
             Self::TransactionReceipt(query) => query.execute(channel, request),
             Self::TransactionRecord(query) => query.execute(channel, request),
             Self::FileContents(query) => query.execute(channel, request),
@@ -221,6 +231,7 @@ impl QueryExecute for AnyQueryData {
             Self::AccountInfo(query) => query.should_retry_pre_check(status),
             Self::AccountBalance(query) => query.should_retry_pre_check(status),
             Self::AccountRecords(query) => query.should_retry_pre_check(status),
+            Self::LiveHash(query) => query.should_retry_pre_check(status),
             Self::TransactionReceipt(query) => query.should_retry_pre_check(status),
             Self::TransactionRecord(query) => query.should_retry_pre_check(status),
             Self::FileContents(query) => query.should_retry_pre_check(status),
@@ -241,6 +252,7 @@ impl QueryExecute for AnyQueryData {
             Self::AccountInfo(query) => query.should_retry(response),
             Self::AccountBalance(query) => query.should_retry(response),
             Self::AccountRecords(query) => query.should_retry(response),
+            Self::LiveHash(query) => query.should_retry(response),
             Self::TransactionReceipt(query) => query.should_retry(response),
             Self::TransactionRecord(query) => query.should_retry(response),
             Self::FileContents(query) => query.should_retry(response),
@@ -261,6 +273,7 @@ impl QueryExecute for AnyQueryData {
             Self::AccountBalance(query) => query.transaction_id(),
             Self::AccountInfo(query) => query.transaction_id(),
             Self::AccountRecords(query) => query.transaction_id(),
+            Self::LiveHash(query) => query.transaction_id(),
             Self::TransactionReceipt(query) => query.transaction_id(),
             Self::TransactionRecord(query) => query.transaction_id(),
             Self::FileContents(query) => query.transaction_id(),
@@ -290,6 +303,7 @@ impl QueryExecute for AnyQueryData {
             Self::AccountRecords(query) => {
                 query.make_response(response).map(AnyQueryResponse::AccountRecords)
             }
+            Self::LiveHash(query) => query.make_response(response).map(AnyQueryResponse::LiveHash),
             Self::TransactionReceipt(query) => {
                 query.make_response(response).map(AnyQueryResponse::TransactionReceipt)
             }
@@ -334,6 +348,7 @@ impl ValidateChecksums for AnyQueryData {
             Self::AccountBalance(query) => query.validate_checksums(ledger_id),
             Self::AccountInfo(query) => query.validate_checksums(ledger_id),
             Self::AccountRecords(query) => query.validate_checksums(ledger_id),
+            Self::LiveHash(query) => query.validate_checksums(ledger_id),
             Self::TransactionReceipt(query) => query.validate_checksums(ledger_id),
             Self::TransactionRecord(query) => query.validate_checksums(ledger_id),
             Self::FileContents(query) => query.validate_checksums(ledger_id),
@@ -383,6 +398,7 @@ impl FromProtobuf<services::response::Response> for AnyQueryResponse {
             CryptoGetAccountRecords(_) => {
                 Self::AccountRecords(Vec::<TransactionRecord>::from_protobuf(response)?)
             }
+            CryptoGetLiveHash(_) => Self::LiveHash(LiveHash::from_protobuf(response)?),
             TransactionGetRecord(_) => {
                 Self::TransactionRecord(Box::new(TransactionRecord::from_protobuf(response)?))
             }
@@ -396,7 +412,6 @@ impl FromProtobuf<services::response::Response> for AnyQueryResponse {
             }
             // Unimplemented on hedera services
             TransactionGetFastRecord(_)
-            | CryptoGetLiveHash(_)
             | GetBySolidityId(_)
             | TokenGetAccountNftInfos(_)
             | NetworkGetExecutionTime(_)
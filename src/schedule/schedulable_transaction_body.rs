@@ -1,12 +1,22 @@
 use hedera_proto::services;
 
 use crate::protobuf::FromProtobuf;
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+};
 use crate::transaction::{
     AnyTransactionData,
     ToSchedulableTransactionDataProtobuf,
+    TransactionBody,
     TransactionData,
 };
-use crate::Hbar;
+use crate::{
+    AnyTransaction,
+    Hbar,
+    Transaction,
+    TransactionId,
+};
 
 mod data {
     pub(super) use crate::account::{
@@ -93,6 +103,13 @@ impl FromProtobuf<services::SchedulableTransactionBody> for SchedulableTransacti
 }
 
 impl SchedulableTransactionBody {
+    /// Checks the scheduled transaction like [`Transaction::validate`](crate::Transaction::validate),
+    /// except for the fields a scheduled transaction doesn't have.
+    pub(super) fn validate(&self, errors: &mut Vec<ValidationError>) {
+        validation::validate_memo("transaction_memo", &self.transaction_memo, errors);
+        self.data.validate_data(errors);
+    }
+
     pub(super) fn to_scheduled_body_protobuf(&self) -> services::SchedulableTransactionBody {
         services::SchedulableTransactionBody {
             data: Some(self.data.to_schedulable_transaction_data_protobuf()),
@@ -106,6 +123,30 @@ impl SchedulableTransactionBody {
     }
 }
 
+/// Returns the transaction scheduled by `pb`, as a frozen transaction with `transaction_id` as its ID.
+pub(crate) fn scheduled_transaction_from_protobuf(
+    pb: services::SchedulableTransactionBody,
+    transaction_id: Option<TransactionId>,
+) -> crate::Result<AnyTransaction> {
+    let body = SchedulableTransactionBody::from_protobuf(pb)?;
+
+    Ok(Transaction::from_parts(
+        TransactionBody {
+            data: (*body.data).into(),
+            node_account_ids: None,
+            transaction_valid_duration: None,
+            max_transaction_fee: body.max_transaction_fee,
+            transaction_memo: body.transaction_memo,
+            transaction_id,
+            operator: None,
+            is_frozen: true,
+            regenerate_transaction_id: Some(false),
+            custom_fee_limits: Vec::new(),
+        },
+        Vec::new(),
+    ))
+}
+
 #[derive(Debug, Clone)]
 pub(super) enum AnySchedulableTransactionData {
     AccountCreate(data::AccountCreate),
@@ -157,6 +198,57 @@ pub(super) enum AnySchedulableTransactionData {
 }
 
 impl AnySchedulableTransactionData {
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        match self {
+            Self::AccountCreate(it) => it.validate_data(errors),
+            Self::AccountUpdate(it) => it.validate_data(errors),
+            Self::AccountDelete(it) => it.validate_data(errors),
+            Self::AccountAllowanceApprove(it) => it.validate_data(errors),
+            Self::AccountAllowanceDelete(it) => it.validate_data(errors),
+            Self::ContractCreate(it) => it.validate_data(errors),
+            Self::ContractUpdate(it) => it.validate_data(errors),
+            Self::ContractDelete(it) => it.validate_data(errors),
+            Self::ContractExecute(it) => it.validate_data(errors),
+            Self::Transfer(it) => it.validate_data(errors),
+            Self::TopicCreate(it) => it.validate_data(errors),
+            Self::TopicUpdate(it) => it.validate_data(errors),
+            Self::TopicDelete(it) => it.validate_data(errors),
+            Self::TopicMessageSubmit(it) => it.validate_data(errors),
+            Self::FileAppend(it) => it.validate_data(errors),
+            Self::FileCreate(it) => it.validate_data(errors),
+            Self::FileUpdate(it) => it.validate_data(errors),
+            Self::FileDelete(it) => it.validate_data(errors),
+            Self::Prng(it) => it.validate_data(errors),
+            Self::TokenAssociate(it) => it.validate_data(errors),
+            Self::TokenBurn(it) => it.validate_data(errors),
+            Self::TokenCreate(it) => it.validate_data(errors),
+            Self::TokenDelete(it) => it.validate_data(errors),
+            Self::TokenDissociate(it) => it.validate_data(errors),
+            Self::TokenFeeScheduleUpdate(it) => it.validate_data(errors),
+            Self::TokenFreeze(it) => it.validate_data(errors),
+            Self::TokenGrantKyc(it) => it.validate_data(errors),
+            Self::TokenMint(it) => it.validate_data(errors),
+            Self::TokenPause(it) => it.validate_data(errors),
+            Self::TokenRevokeKyc(it) => it.validate_data(errors),
+            Self::TokenUnfreeze(it) => it.validate_data(errors),
+            Self::TokenUnpause(it) => it.validate_data(errors),
+            Self::TokenUpdate(it) => it.validate_data(errors),
+            Self::TokenWipe(it) => it.validate_data(errors),
+            Self::TokenUpdateNfts(it) => it.validate_data(errors),
+            Self::TokenReject(it) => it.validate_data(errors),
+            Self::SystemDelete(it) => it.validate_data(errors),
+            Self::SystemUndelete(it) => it.validate_data(errors),
+            Self::Freeze(it) => it.validate_data(errors),
+            Self::ScheduleDelete(it) => it.validate_data(errors),
+            Self::NodeCreate(it) => it.validate_data(errors),
+            Self::NodeUpdate(it) => it.validate_data(errors),
+            Self::NodeDelete(it) => it.validate_data(errors),
+            Self::TokenAirdrop(it) => it.validate_data(errors),
+            Self::TokenClaimAirdrop(it) => it.validate_data(errors),
+            Self::TokenCancelAirdrop(it) => it.validate_data(errors),
+        }
+    }
+
     pub(super) fn default_max_transaction_fee(&self) -> Hbar {
         match self {
             AnySchedulableTransactionData::AccountCreate(it) => it.default_max_transaction_fee(),
@@ -543,6 +635,12 @@ impl TryFrom<AnyTransactionData> for AnySchedulableTransactionData {
             AnyTransactionData::Ethereum(_) => {
                 Err(crate::Error::basic_parse("Cannot schedule `EthereumTransaction`"))
             }
+            AnyTransactionData::LiveHashAdd(_) => {
+                Err(crate::Error::basic_parse("Cannot schedule `LiveHashAddTransaction`"))
+            }
+            AnyTransactionData::LiveHashDelete(_) => {
+                Err(crate::Error::basic_parse("Cannot schedule `LiveHashDeleteTransaction`"))
+            }
         }
     }
 }
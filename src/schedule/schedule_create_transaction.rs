@@ -10,6 +10,10 @@ use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -147,7 +151,17 @@ impl ScheduleCreateTransaction {
     }
 }
 
-impl TransactionData for ScheduleCreateTransactionData {}
+impl TransactionData for ScheduleCreateTransactionData {
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        if let Some(memo) = &self.schedule_memo {
+            validation::validate_memo("schedule_memo", memo, errors);
+        }
+
+        if let Some(scheduled) = &self.scheduled_transaction {
+            scheduled.validate(errors);
+        }
+    }
+}
 
 impl TransactionExecute for ScheduleCreateTransactionData {
     fn execute(
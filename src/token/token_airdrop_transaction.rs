@@ -23,8 +23,10 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    ValidationError,
 };
 use crate::transfer_transaction::{
+    validate_token_transfers,
     TokenTransfer,
     Transfer,
 };
@@ -286,7 +288,11 @@ impl TokenAirdropTransaction {
     }
 }
 
-impl TransactionData for TokenAirdropTransactionData {}
+impl TransactionData for TokenAirdropTransactionData {
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        validate_token_transfers(&self.token_transfers, errors);
+    }
+}
 
 impl TransactionExecute for TokenAirdropTransactionData {
     fn execute(
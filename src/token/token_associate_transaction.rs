@@ -6,6 +6,10 @@ use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -74,7 +78,11 @@ impl TokenAssociateTransaction {
     }
 }
 
-impl TransactionData for TokenAssociateTransactionData {}
+impl TransactionData for TokenAssociateTransactionData {
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        validation::validate_unique_token_ids("token_ids", self.token_ids.iter().copied(), errors);
+    }
+}
 
 impl TransactionExecute for TokenAssociateTransactionData {
     fn execute(
@@ -8,6 +8,10 @@ use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -99,7 +103,11 @@ impl TokenBurnTransaction {
     }
 }
 
-impl TransactionData for TokenBurnTransactionData {}
+impl TransactionData for TokenBurnTransactionData {
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        validation::validate_serials("serials", &self.serials, errors);
+    }
+}
 
 impl TransactionExecute for TokenBurnTransactionData {
     fn execute(
@@ -16,6 +16,10 @@ use crate::protobuf::{
 use crate::token::custom_fees::AnyCustomFee;
 use crate::token::token_supply_type::TokenSupplyType;
 use crate::token::token_type::TokenType;
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -460,6 +464,13 @@ impl TransactionData for TokenCreateTransactionData {
     fn default_max_transaction_fee(&self) -> crate::Hbar {
         crate::Hbar::from_unit(40, crate::HbarUnit::Hbar)
     }
+
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        validation::validate_string("name", &self.name, validation::MAX_TOKEN_NAME_LEN, errors);
+        validation::validate_string("symbol", &self.symbol, validation::MAX_TOKEN_NAME_LEN, errors);
+        validation::validate_memo("token_memo", &self.token_memo, errors);
+        validation::validate_auto_renew_period("auto_renew_period", self.auto_renew_period, errors);
+    }
 }
 
 impl TransactionExecute for TokenCreateTransactionData {
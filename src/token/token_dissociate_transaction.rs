@@ -9,6 +9,10 @@ use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -80,7 +84,11 @@ impl TokenDissociateTransaction {
     }
 }
 
-impl TransactionData for TokenDissociateTransactionData {}
+impl TransactionData for TokenDissociateTransactionData {
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        validation::validate_unique_token_ids("token_ids", self.token_ids.iter().copied(), errors);
+    }
+}
 
 impl TransactionExecute for TokenDissociateTransactionData {
     fn execute(
@@ -9,6 +9,11 @@ use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+    ValidationErrorKind,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -100,7 +105,28 @@ impl TokenMintTransaction {
     }
 }
 
-impl TransactionData for TokenMintTransactionData {}
+impl TransactionData for TokenMintTransactionData {
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        validation::validate_count(
+            "metadata",
+            self.metadata.len(),
+            validation::MAX_NFT_BATCH_SIZE,
+            errors,
+        );
+
+        for metadata in &self.metadata {
+            if metadata.len() > validation::MAX_NFT_METADATA_LEN {
+                errors.push(ValidationError::new(
+                    "metadata",
+                    ValidationErrorKind::TooLong {
+                        len: metadata.len(),
+                        max: validation::MAX_NFT_METADATA_LEN,
+                    },
+                ));
+            }
+        }
+    }
+}
 
 impl TransactionExecute for TokenMintTransactionData {
     fn execute(
@@ -9,6 +9,11 @@ use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+    ValidationErrorKind,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -85,7 +90,21 @@ impl TokenUpdateNftsTransaction {
     }
 }
 
-impl TransactionData for TokenUpdateNftsTransactionData {}
+impl TransactionData for TokenUpdateNftsTransactionData {
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        validation::validate_serials("serials", &self.serials, errors);
+
+        if self.metadata.len() > validation::MAX_NFT_METADATA_LEN {
+            errors.push(ValidationError::new(
+                "metadata",
+                ValidationErrorKind::TooLong {
+                    len: self.metadata.len(),
+                    max: validation::MAX_NFT_METADATA_LEN,
+                },
+            ));
+        }
+    }
+}
 
 impl TransactionExecute for TokenUpdateNftsTransactionData {
     fn execute(
@@ -14,6 +14,10 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::token::token_key_validation_type::TokenKeyValidation;
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -369,7 +373,26 @@ impl TokenUpdateTransaction {
     }
 }
 
-impl TransactionData for TokenUpdateTransactionData {}
+impl TransactionData for TokenUpdateTransactionData {
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        validation::validate_string(
+            "token_name",
+            &self.token_name,
+            validation::MAX_TOKEN_NAME_LEN,
+            errors,
+        );
+        validation::validate_string(
+            "token_symbol",
+            &self.token_symbol,
+            validation::MAX_TOKEN_NAME_LEN,
+            errors,
+        );
+        if let Some(memo) = &self.token_memo {
+            validation::validate_memo("token_memo", memo, errors);
+        }
+        validation::validate_auto_renew_period("auto_renew_period", self.auto_renew_period, errors);
+    }
+}
 
 impl TransactionExecute for TokenUpdateTransactionData {
     fn execute(
@@ -9,6 +9,10 @@ use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -123,7 +127,13 @@ impl TokenWipeTransaction {
     }
 }
 
-impl TransactionData for TokenWipeTransactionData {}
+impl TransactionData for TokenWipeTransactionData {
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        let serials: Vec<_> = self.serials.iter().map(|it| *it as i64).collect();
+
+        validation::validate_serials("serials", &serials, errors);
+    }
+}
 
 impl TransactionExecute for TokenWipeTransactionData {
     fn execute(
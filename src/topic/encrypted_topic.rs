@@ -0,0 +1,477 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use aes_gcm::aead::{
+    Aead,
+    Payload,
+};
+use aes_gcm::{
+    Aes256Gcm,
+    KeyInit,
+    Nonce,
+};
+use futures_core::stream::BoxStream;
+use futures_util::StreamExt;
+use hedera_proto::sdk;
+use hkdf::Hkdf;
+use prost::Message;
+use rand::{
+    thread_rng,
+    RngCore,
+};
+use sha2::Sha256;
+
+use crate::{
+    Client,
+    Error,
+    PrivateKey,
+    PublicKey,
+    TopicId,
+    TopicMessage,
+    TopicMessageQuery,
+    TopicMessageSubmitTransaction,
+    TransactionResponse,
+};
+
+/// Binds the key encryption keys to this scheme, so they can't be confused with keys derived for anything else.
+const KEY_WRAP_INFO: &[u8] = b"hedera-sdk encrypted topic message v1";
+
+/// Every key encryption key is derived from a fresh ephemeral key, and only used once, so a fixed nonce is safe.
+const KEY_WRAP_NONCE: [u8; 12] = [0; 12];
+
+/// Derives the cipher that wraps the content key for `recipient` from the secret it shares with `ephemeral`.
+fn key_wrap_cipher(
+    shared_secret: &[u8; 32],
+    ephemeral: &PublicKey,
+    recipient: &PublicKey,
+) -> Aes256Gcm {
+    let mut info = KEY_WRAP_INFO.to_vec();
+    info.extend_from_slice(&ephemeral.to_bytes_raw());
+    info.extend_from_slice(&recipient.to_bytes_raw());
+
+    let mut key = [0; 32];
+
+    Hkdf::<Sha256>::new(None, shared_secret)
+        .expand(&info, &mut key)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+
+    Aes256Gcm::new_from_slice(&key).expect("AES-256 keys are 32 bytes")
+}
+
+/// Encrypts the content key for `recipient`, with a key agreed on with a fresh ephemeral key.
+fn wrap_content_key(
+    content_key: &[u8; 32],
+    sender_public_key: &[u8],
+    recipient: &PublicKey,
+) -> crate::Result<sdk::EncryptedTopicMessageRecipient> {
+    let ephemeral = if recipient.is_ed25519() {
+        PrivateKey::generate_ed25519()
+    } else {
+        PrivateKey::generate_ecdsa()
+    };
+
+    let ephemeral_public_key = ephemeral.public_key();
+
+    let shared_secret = ephemeral.diffie_hellman(recipient).ok_or_else(|| {
+        Error::TopicMessageEncrypt(
+            format!("cannot agree on a key with recipient `{recipient}`").into(),
+        )
+    })?;
+
+    let wrapped_key = key_wrap_cipher(&shared_secret, &ephemeral_public_key, recipient)
+        .encrypt(
+            Nonce::from_slice(&KEY_WRAP_NONCE),
+            Payload { msg: content_key, aad: sender_public_key },
+        )
+        .expect("encrypting a 32 byte key can't fail");
+
+    Ok(sdk::EncryptedTopicMessageRecipient {
+        public_key: recipient.to_bytes_der(),
+        ephemeral_public_key: ephemeral_public_key.to_bytes_raw(),
+        wrapped_key,
+    })
+}
+
+/// Publishes messages to a topic that only a chosen set of recipients can read.
+///
+/// Each message is encrypted with AES-256-GCM under a fresh content key,
+/// which is then encrypted for each recipient with a key agreed on with ECDH:
+/// over secp256k1 for ECDSA recipients, and over X25519 (converted from the Ed25519 key) for Ed25519 recipients.
+/// The result is signed with the sender's key.
+///
+/// Everyone can still see who the recipients are, when the message was sent and roughly how large it is.
+///
+/// Read them back with an [`EncryptedTopicSubscriber`].
+#[derive(Clone, Debug)]
+pub struct EncryptedTopicPublisher {
+    topic_id: TopicId,
+    sender: PrivateKey,
+    recipients: Vec<PublicKey>,
+}
+
+impl EncryptedTopicPublisher {
+    /// Create a new `EncryptedTopicPublisher` that signs the messages it publishes to `topic_id` with `sender`.
+    #[must_use]
+    pub fn new(topic_id: impl Into<TopicId>, sender: PrivateKey) -> Self {
+        Self { topic_id: topic_id.into(), sender, recipients: Vec::new() }
+    }
+
+    /// Returns the ID of the topic messages are published to.
+    #[must_use]
+    pub fn get_topic_id(&self) -> TopicId {
+        self.topic_id
+    }
+
+    /// Returns the public key of the sender, which recipients check the signature of each message with.
+    #[must_use]
+    pub fn get_sender_public_key(&self) -> PublicKey {
+        self.sender.public_key()
+    }
+
+    /// Returns the keys of the recipients messages are encrypted for.
+    #[must_use]
+    pub fn get_recipients(&self) -> &[PublicKey] {
+        &self.recipients
+    }
+
+    /// Sets the keys of the recipients messages are encrypted for.
+    ///
+    /// The sender can only read its own messages if its public key is one of the recipients.
+    pub fn recipients(&mut self, recipients: impl IntoIterator<Item = PublicKey>) -> &mut Self {
+        self.recipients = recipients.into_iter().collect();
+        self
+    }
+
+    /// Adds a recipient to encrypt messages for.
+    pub fn add_recipient(&mut self, recipient: PublicKey) -> &mut Self {
+        self.recipients.push(recipient);
+        self
+    }
+
+    /// Encrypts and signs `payload`, returning the contents of the topic message to submit.
+    ///
+    /// # Errors
+    /// - [`Error::TopicMessageEncrypt`] if there are no recipients, or a key can't be agreed on with one of them.
+    pub fn encrypt(&self, payload: &[u8]) -> crate::Result<Vec<u8>> {
+        if self.recipients.is_empty() {
+            return Err(Error::TopicMessageEncrypt("no recipients".into()));
+        }
+
+        let sender_public_key = self.sender.public_key().to_bytes_der();
+
+        let mut content_key = [0; 32];
+        let mut nonce = [0; 12];
+
+        thread_rng().fill_bytes(&mut content_key);
+        thread_rng().fill_bytes(&mut nonce);
+
+        let ciphertext = Aes256Gcm::new_from_slice(&content_key)
+            .expect("AES-256 keys are 32 bytes")
+            .encrypt(Nonce::from_slice(&nonce), Payload { msg: payload, aad: &sender_public_key })
+            .map_err(|_| Error::TopicMessageEncrypt("payload is too large".into()))?;
+
+        let recipients = self
+            .recipients
+            .iter()
+            .map(|recipient| wrap_content_key(&content_key, &sender_public_key, recipient))
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        let body = sdk::EncryptedTopicMessageBody {
+            sender_public_key,
+            recipients,
+            nonce: nonce.to_vec(),
+            ciphertext,
+        }
+        .encode_to_vec();
+
+        let signature = self.sender.sign(&body);
+
+        Ok(sdk::EncryptedTopicMessage { body, signature }.encode_to_vec())
+    }
+
+    /// Returns a transaction that submits `payload`, to be configured further before executing it.
+    ///
+    /// # Errors
+    /// - [`Error::TopicMessageEncrypt`] if `payload` can't be encrypted, see [`encrypt`](Self::encrypt).
+    pub fn transaction(&self, payload: &[u8]) -> crate::Result<TopicMessageSubmitTransaction> {
+        let contents = self.encrypt(payload)?;
+
+        let mut transaction = TopicMessageSubmitTransaction::new();
+
+        transaction.topic_id(self.topic_id).message(contents);
+
+        Ok(transaction)
+    }
+
+    /// Publishes `payload`, returning the response of each chunk it's submitted in.
+    ///
+    /// # Errors
+    /// - [`Error::TopicMessageEncrypt`] if `payload` can't be encrypted, see [`encrypt`](Self::encrypt).
+    /// - Any error from executing the transaction.
+    pub async fn publish(
+        &self,
+        client: &Client,
+        payload: &[u8],
+    ) -> crate::Result<Vec<TransactionResponse>> {
+        self.transaction(payload)?.execute_all(client).await
+    }
+}
+
+/// A topic message received by an [`EncryptedTopicSubscriber`].
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct DecryptedTopicMessage {
+    /// The message as it was received, its contents are still encrypted.
+    pub message: TopicMessage,
+
+    /// The public key the message claims to be from.
+    ///
+    /// Only trust this if [`decrypted`](Self::decrypted) is `Ok`, which means the signature matched it.
+    pub sender: PublicKey,
+
+    /// The decrypted payload, or why it couldn't be decrypted.
+    pub decrypted: crate::Result<Vec<u8>>,
+}
+
+/// Subscribes to the messages an [`EncryptedTopicPublisher`] encrypted for a recipient.
+#[derive(Clone, Debug)]
+pub struct EncryptedTopicSubscriber {
+    query: TopicMessageQuery,
+    recipient: PrivateKey,
+    trusted_senders: Option<Vec<PublicKey>>,
+}
+
+impl EncryptedTopicSubscriber {
+    /// Create a new `EncryptedTopicSubscriber` that decrypts the messages `query` receives with `recipient`.
+    #[must_use]
+    pub fn new(query: TopicMessageQuery, recipient: PrivateKey) -> Self {
+        Self { query, recipient, trusted_senders: None }
+    }
+
+    /// Returns the query messages are received with.
+    #[must_use]
+    pub fn get_query(&self) -> &TopicMessageQuery {
+        &self.query
+    }
+
+    /// Returns the public key of the recipient messages are decrypted for.
+    #[must_use]
+    pub fn get_recipient_public_key(&self) -> PublicKey {
+        self.recipient.public_key()
+    }
+
+    /// Returns the keys of the senders messages are accepted from, `None` if they're accepted from anyone.
+    #[must_use]
+    pub fn get_trusted_senders(&self) -> Option<&[PublicKey]> {
+        self.trusted_senders.as_deref()
+    }
+
+    /// Sets the keys of the senders messages are accepted from.
+    ///
+    /// By default messages are accepted from anyone, the signature only proves that
+    /// the message came from whoever holds the key it names.
+    pub fn trusted_senders(
+        &mut self,
+        trusted_senders: impl IntoIterator<Item = PublicKey>,
+    ) -> &mut Self {
+        self.trusted_senders = Some(trusted_senders.into_iter().collect());
+        self
+    }
+
+    /// Decrypts a message received from the topic.
+    ///
+    /// Returns `None` if the message isn't an encrypted message, or wasn't encrypted for this recipient.
+    ///
+    /// Use this to decrypt messages from any subscription, such as [`TopicMessageQuery::subscribe_with_checkpoints`].
+    pub fn decrypt(&self, message: TopicMessage) -> Option<DecryptedTopicMessage> {
+        let envelope = sdk::EncryptedTopicMessage::decode(message.contents.as_slice()).ok()?;
+        let body = sdk::EncryptedTopicMessageBody::decode(envelope.body.as_slice()).ok()?;
+        let sender = PublicKey::from_bytes_der(&body.sender_public_key).ok()?;
+
+        let recipient_public_key = self.recipient.public_key().to_bytes_der();
+        let entry = body.recipients.iter().find(|it| it.public_key == recipient_public_key)?;
+
+        let decrypted = self.open(&sender, &envelope, &body, entry);
+
+        Some(DecryptedTopicMessage { message, sender, decrypted })
+    }
+
+    fn open(
+        &self,
+        sender: &PublicKey,
+        envelope: &sdk::EncryptedTopicMessage,
+        body: &sdk::EncryptedTopicMessageBody,
+        entry: &sdk::EncryptedTopicMessageRecipient,
+    ) -> crate::Result<Vec<u8>> {
+        sender.verify(&envelope.body, &envelope.signature)?;
+
+        if let Some(trusted_senders) = &self.trusted_senders {
+            if !trusted_senders.contains(sender) {
+                return Err(Error::TopicMessageDecrypt(
+                    format!("sender `{sender}` isn't trusted").into(),
+                ));
+            }
+        }
+
+        let recipient = self.recipient.public_key();
+
+        let ephemeral = if recipient.is_ed25519() {
+            PublicKey::from_bytes_ed25519(&entry.ephemeral_public_key)
+        } else {
+            PublicKey::from_bytes_ecdsa(&entry.ephemeral_public_key)
+        }
+        .map_err(|e| Error::TopicMessageDecrypt(e.into()))?;
+
+        let shared_secret = self.recipient.diffie_hellman(&ephemeral).ok_or_else(|| {
+            Error::TopicMessageDecrypt("cannot agree on a key with the ephemeral key".into())
+        })?;
+
+        let content_key = key_wrap_cipher(&shared_secret, &ephemeral, &recipient)
+            .decrypt(
+                Nonce::from_slice(&KEY_WRAP_NONCE),
+                Payload { msg: &entry.wrapped_key, aad: &body.sender_public_key },
+            )
+            .map_err(|_| Error::TopicMessageDecrypt("failed to unwrap the content key".into()))?;
+
+        let cipher = Aes256Gcm::new_from_slice(&content_key)
+            .map_err(|_| Error::TopicMessageDecrypt("content key isn't 32 bytes".into()))?;
+
+        if body.nonce.len() != KEY_WRAP_NONCE.len() {
+            return Err(Error::TopicMessageDecrypt("nonce isn't 12 bytes".into()));
+        }
+
+        cipher
+            .decrypt(
+                Nonce::from_slice(&body.nonce),
+                Payload { msg: &body.ciphertext, aad: &body.sender_public_key },
+            )
+            .map_err(|_| Error::TopicMessageDecrypt("failed to decrypt the payload".into()))
+    }
+
+    /// Subscribe to the topic, decrypting each message that was encrypted for this recipient as it arrives.
+    ///
+    /// Messages that weren't encrypted for this recipient are skipped.
+    /// A message that fails to decrypt is still delivered, with [`DecryptedTopicMessage::decrypted`] set to the error,
+    /// only errors from the subscription itself are returned as `Err`.
+    pub fn subscribe<'a>(
+        &'a self,
+        client: &'a Client,
+    ) -> BoxStream<'a, crate::Result<DecryptedTopicMessage>> {
+        Box::pin(self.query.subscribe(client).filter_map(move |message| {
+            std::future::ready(message.map(|message| self.decrypt(message)).transpose())
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use time::OffsetDateTime;
+
+    use crate::{
+        EncryptedTopicPublisher,
+        EncryptedTopicSubscriber,
+        Error,
+        PrivateKey,
+        TopicId,
+        TopicMessage,
+        TopicMessageQuery,
+    };
+
+    fn received(contents: Vec<u8>) -> TopicMessage {
+        TopicMessage {
+            consensus_timestamp: OffsetDateTime::UNIX_EPOCH,
+            contents,
+            running_hash: Vec::new(),
+            running_hash_version: 3,
+            sequence_number: 1,
+            chunks: None,
+            transaction: None,
+            incomplete: None,
+        }
+    }
+
+    fn publisher(sender: &PrivateKey, recipients: &[&PrivateKey]) -> EncryptedTopicPublisher {
+        let mut publisher = EncryptedTopicPublisher::new(TopicId::new(0, 0, 1001), sender.clone());
+        publisher.recipients(recipients.iter().map(|it| it.public_key()));
+
+        publisher
+    }
+
+    #[test]
+    fn encrypt_then_decrypt() {
+        let sender = PrivateKey::generate_ed25519();
+        let ed25519_recipient = PrivateKey::generate_ed25519();
+        let ecdsa_recipient = PrivateKey::generate_ecdsa();
+
+        let contents =
+            publisher(&sender, &[&ed25519_recipient, &ecdsa_recipient]).encrypt(b"hello").unwrap();
+
+        for recipient in [ed25519_recipient, ecdsa_recipient] {
+            let subscriber = EncryptedTopicSubscriber::new(TopicMessageQuery::new(), recipient);
+
+            let message = subscriber.decrypt(received(contents.clone())).unwrap();
+
+            assert_eq!(message.sender, sender.public_key());
+            assert_eq!(message.decrypted.unwrap(), b"hello");
+        }
+    }
+
+    #[test]
+    fn decrypt_not_a_recipient() {
+        let sender = PrivateKey::generate_ecdsa();
+        let recipient = PrivateKey::generate_ecdsa();
+
+        let contents = publisher(&sender, &[&recipient]).encrypt(b"hello").unwrap();
+
+        let subscriber =
+            EncryptedTopicSubscriber::new(TopicMessageQuery::new(), PrivateKey::generate_ecdsa());
+
+        assert!(subscriber.decrypt(received(contents)).is_none());
+        assert!(subscriber.decrypt(received(b"hello".to_vec())).is_none());
+    }
+
+    #[test]
+    fn decrypt_tampered() {
+        let sender = PrivateKey::generate_ed25519();
+        let recipient = PrivateKey::generate_ed25519();
+
+        let mut contents = publisher(&sender, &[&recipient]).encrypt(b"hello").unwrap();
+
+        // flip the last byte of the body, which is followed by the signature and its tag and length.
+        let index = contents.len() - sender.sign(b"").len() - 3;
+        contents[index] ^= 1;
+
+        let subscriber = EncryptedTopicSubscriber::new(TopicMessageQuery::new(), recipient);
+
+        assert_matches!(
+            subscriber.decrypt(received(contents)).unwrap().decrypted,
+            Err(Error::SignatureVerify(_))
+        );
+    }
+
+    #[test]
+    fn decrypt_untrusted_sender() {
+        let sender = PrivateKey::generate_ed25519();
+        let recipient = PrivateKey::generate_ecdsa();
+
+        let contents = publisher(&sender, &[&recipient]).encrypt(b"hello").unwrap();
+
+        let mut subscriber = EncryptedTopicSubscriber::new(TopicMessageQuery::new(), recipient);
+        subscriber.trusted_senders([PrivateKey::generate_ed25519().public_key()]);
+
+        assert_matches!(
+            subscriber.decrypt(received(contents)).unwrap().decrypted,
+            Err(Error::TopicMessageDecrypt(_))
+        );
+    }
+
+    #[test]
+    fn encrypt_without_recipients() {
+        let sender = PrivateKey::generate_ed25519();
+
+        assert_matches!(
+            publisher(&sender, &[]).encrypt(b"hello"),
+            Err(Error::TopicMessageEncrypt(_))
+        );
+    }
+}
@@ -1,15 +1,32 @@
 // SPDX-License-Identifier: Apache-2.0
 
+mod encrypted_topic;
+mod topic_checkpoint;
 mod topic_create_transaction;
 mod topic_delete_transaction;
 mod topic_id;
 mod topic_info;
 mod topic_info_query;
 mod topic_message;
+mod topic_message_codec;
 mod topic_message_query;
 mod topic_message_submit_transaction;
+mod topic_message_verifier;
+mod topic_subscription_manager;
 mod topic_update_transaction;
+mod typed_topic;
 
+pub use encrypted_topic::{
+    DecryptedTopicMessage,
+    EncryptedTopicPublisher,
+    EncryptedTopicSubscriber,
+};
+pub use topic_checkpoint::{
+    FileCheckpointStore,
+    TopicCheckpoint,
+    TopicCheckpointStore,
+    TopicCheckpointStoreError,
+};
 pub use topic_create_transaction::TopicCreateTransaction;
 pub(crate) use topic_create_transaction::TopicCreateTransactionData;
 pub use topic_delete_transaction::TopicDeleteTransaction;
@@ -18,10 +35,31 @@ pub use topic_id::TopicId;
 pub use topic_info::TopicInfo;
 pub use topic_info_query::TopicInfoQuery;
 pub(crate) use topic_info_query::TopicInfoQueryData;
-pub use topic_message::TopicMessage;
-pub use topic_message_query::TopicMessageQuery;
+pub use topic_message::{
+    IncompleteTopicMessage,
+    IncompleteTopicMessageReason,
+    TopicMessage,
+};
+#[cfg(feature = "serde")]
+pub use topic_message_codec::JsonCodec;
+pub use topic_message_codec::{
+    ProtobufCodec,
+    TopicMessageCodec,
+    TopicMessageCodecError,
+    TopicMessageEnvelope,
+};
 pub(crate) use topic_message_query::TopicMessageQueryData;
+pub use topic_message_query::{
+    IncompleteTopicMessagePolicy,
+    TopicMessageQuery,
+};
 pub use topic_message_submit_transaction::TopicMessageSubmitTransaction;
 pub(crate) use topic_message_submit_transaction::TopicMessageSubmitTransactionData;
+pub use topic_subscription_manager::TopicSubscriptionManager;
 pub use topic_update_transaction::TopicUpdateTransaction;
 pub(crate) use topic_update_transaction::TopicUpdateTransactionData;
+pub use typed_topic::{
+    TopicPublisher,
+    TopicSubscriber,
+    TypedTopicMessage,
+};
@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::error::Error as StdError;
+use std::path::{
+    Path,
+    PathBuf,
+};
+use std::sync::Arc;
+
+use futures_core::future::BoxFuture;
+use time::OffsetDateTime;
+
+use crate::TopicId;
+
+/// The error type returned by a [`TopicCheckpointStore`].
+pub type TopicCheckpointStoreError = Box<dyn StdError + Send + Sync + 'static>;
+
+/// How far a subscription to a topic got, see [`TopicMessageQuery::subscribe_with_checkpoints`](crate::TopicMessageQuery::subscribe_with_checkpoints).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TopicCheckpoint {
+    /// The subscription resumes with the messages that reached consensus after this time.
+    ///
+    /// This is the consensus timestamp of the last delivered message,
+    /// or earlier if a chunked message was still incomplete when it was delivered.
+    pub consensus_timestamp: OffsetDateTime,
+
+    /// The sequence number of the last delivered message.
+    pub sequence_number: u64,
+}
+
+impl TopicCheckpoint {
+    /// Create a new checkpoint.
+    #[must_use]
+    pub fn new(consensus_timestamp: OffsetDateTime, sequence_number: u64) -> Self {
+        Self { consensus_timestamp, sequence_number }
+    }
+
+    fn to_file_contents(self) -> String {
+        format!("{} {}\n", self.consensus_timestamp.unix_timestamp_nanos(), self.sequence_number)
+    }
+
+    fn from_file_contents(contents: &str) -> Option<Self> {
+        let (timestamp, sequence_number) = contents.trim().split_once(' ')?;
+
+        let consensus_timestamp =
+            OffsetDateTime::from_unix_timestamp_nanos(timestamp.parse().ok()?).ok()?;
+
+        Some(Self { consensus_timestamp, sequence_number: sequence_number.parse().ok()? })
+    }
+}
+
+/// Persists [`TopicCheckpoint`]s, so that a subscription can resume after a restart.
+///
+/// Implement this to keep checkpoints somewhere other than the file system, such as the database the messages end up in.
+pub trait TopicCheckpointStore: Send + Sync {
+    /// Returns the last checkpoint saved for `topic_id`, or `None` if there isn't one.
+    fn load(
+        &self,
+        topic_id: TopicId,
+    ) -> BoxFuture<'_, Result<Option<TopicCheckpoint>, TopicCheckpointStoreError>>;
+
+    /// Saves `checkpoint` for `topic_id`, replacing the previous one.
+    fn save(
+        &self,
+        topic_id: TopicId,
+        checkpoint: TopicCheckpoint,
+    ) -> BoxFuture<'_, Result<(), TopicCheckpointStoreError>>;
+}
+
+impl<T: TopicCheckpointStore + ?Sized> TopicCheckpointStore for Arc<T> {
+    fn load(
+        &self,
+        topic_id: TopicId,
+    ) -> BoxFuture<'_, Result<Option<TopicCheckpoint>, TopicCheckpointStoreError>> {
+        T::load(self, topic_id)
+    }
+
+    fn save(
+        &self,
+        topic_id: TopicId,
+        checkpoint: TopicCheckpoint,
+    ) -> BoxFuture<'_, Result<(), TopicCheckpointStoreError>> {
+        T::save(self, topic_id, checkpoint)
+    }
+}
+
+/// A [`TopicCheckpointStore`] that keeps one file per topic in a directory.
+///
+/// Checkpoints are written to a temporary file which then replaces the previous checkpoint,
+/// so a crash while saving never leaves a corrupt checkpoint behind.
+#[derive(Clone, Debug)]
+pub struct FileCheckpointStore {
+    directory: PathBuf,
+}
+
+impl FileCheckpointStore {
+    /// Create a new `FileCheckpointStore` that keeps its checkpoints in `directory`.
+    ///
+    /// The directory is created when the first checkpoint is saved.
+    #[must_use]
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self { directory: directory.into() }
+    }
+
+    /// Returns the directory checkpoints are kept in.
+    #[must_use]
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn path(&self, topic_id: TopicId) -> PathBuf {
+        self.directory.join(format!("{topic_id}.checkpoint"))
+    }
+}
+
+impl TopicCheckpointStore for FileCheckpointStore {
+    fn load(
+        &self,
+        topic_id: TopicId,
+    ) -> BoxFuture<'_, Result<Option<TopicCheckpoint>, TopicCheckpointStoreError>> {
+        Box::pin(async move {
+            let path = self.path(topic_id);
+
+            let contents = match tokio::fs::read_to_string(&path).await {
+                Ok(contents) => contents,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                Err(e) => return Err(e.into()),
+            };
+
+            TopicCheckpoint::from_file_contents(&contents)
+                .map(Some)
+                .ok_or_else(|| format!("malformed checkpoint file `{}`", path.display()).into())
+        })
+    }
+
+    fn save(
+        &self,
+        topic_id: TopicId,
+        checkpoint: TopicCheckpoint,
+    ) -> BoxFuture<'_, Result<(), TopicCheckpointStoreError>> {
+        Box::pin(async move {
+            let path = self.path(topic_id);
+            let tmp_path = path.with_extension("checkpoint.tmp");
+
+            tokio::fs::create_dir_all(&self.directory).await?;
+            tokio::fs::write(&tmp_path, checkpoint.to_file_contents()).await?;
+            tokio::fs::rename(&tmp_path, &path).await?;
+
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use super::TopicCheckpoint;
+    use crate::{
+        FileCheckpointStore,
+        TopicCheckpointStore,
+        TopicId,
+    };
+
+    #[test]
+    fn file_contents_round_trip() {
+        let checkpoint = TopicCheckpoint::new(
+            OffsetDateTime::from_unix_timestamp_nanos(1_554_158_542_123_456_789).unwrap(),
+            42,
+        );
+
+        assert_eq!(
+            TopicCheckpoint::from_file_contents(&checkpoint.to_file_contents()),
+            Some(checkpoint)
+        );
+    }
+
+    #[test]
+    fn malformed_file_contents() {
+        assert_eq!(TopicCheckpoint::from_file_contents(""), None);
+        assert_eq!(TopicCheckpoint::from_file_contents("12345"), None);
+        assert_eq!(TopicCheckpoint::from_file_contents("a b"), None);
+    }
+
+    #[tokio::test]
+    async fn file_store_save_load() {
+        let directory =
+            std::env::temp_dir().join(format!("hedera-topic-checkpoints-{}", std::process::id()));
+
+        let store = FileCheckpointStore::new(&directory);
+        let topic_id = TopicId::new(0, 0, 1001);
+
+        assert_eq!(store.load(topic_id).await.unwrap(), None);
+
+        let checkpoint = TopicCheckpoint::new(OffsetDateTime::UNIX_EPOCH, 7);
+
+        store.save(topic_id, checkpoint).await.unwrap();
+        assert_eq!(store.load(topic_id).await.unwrap(), Some(checkpoint));
+
+        store.save(topic_id, TopicCheckpoint { sequence_number: 8, ..checkpoint }).await.unwrap();
+        assert_eq!(store.load(topic_id).await.unwrap().map(|it| it.sequence_number), Some(8));
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
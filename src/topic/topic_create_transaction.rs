@@ -11,6 +11,10 @@ use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -215,6 +219,11 @@ impl TransactionData for TopicCreateTransactionData {
     fn default_max_transaction_fee(&self) -> Hbar {
         Hbar::new(25)
     }
+
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        validation::validate_memo("topic_memo", &self.topic_memo, errors);
+        validation::validate_auto_renew_period("auto_renew_period", self.auto_renew_period, errors);
+    }
 }
 
 impl TransactionExecute for TopicCreateTransactionData {
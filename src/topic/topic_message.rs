@@ -56,6 +56,40 @@ pub struct TopicMessage {
 
     /// The [`TransactionId`] of the first chunk, gets copied to every subsequent chunk in the message.
     pub transaction: Option<TransactionId>,
+
+    /// Which chunks are missing, if this message was delivered without all of them.
+    ///
+    /// See [`IncompleteTopicMessagePolicy`](crate::IncompleteTopicMessagePolicy).
+    pub incomplete: Option<IncompleteTopicMessage>,
+}
+
+/// A chunked message that was given up on before all of its chunks were received.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IncompleteTopicMessage {
+    /// Why the message was given up on.
+    pub reason: IncompleteTopicMessageReason,
+
+    /// The number of chunks the message should have.
+    pub total_chunks: u32,
+
+    /// The (1-based) numbers of the chunks that weren't received.
+    pub missing_chunks: Vec<u32>,
+}
+
+/// Why a chunked message was given up on before all of its chunks were received.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IncompleteTopicMessageReason {
+    /// The remaining chunks didn't arrive in time, see [`TopicMessageQuery::incomplete_message_expiry`](crate::TopicMessageQuery::incomplete_message_expiry).
+    Expired,
+
+    /// Too many bytes were buffered for incomplete messages, so the oldest one was given up on,
+    /// see [`TopicMessageQuery::max_incomplete_message_bytes`](crate::TopicMessageQuery::max_incomplete_message_bytes).
+    BufferLimitExceeded,
+
+    /// The subscription ended before the remaining chunks arrived.
+    StreamEnded,
 }
 
 impl TopicMessage {
@@ -68,6 +102,7 @@ impl TopicMessage {
             sequence_number: pb.sequence_number,
             chunks: None,
             transaction: None,
+            incomplete: None,
         }
     }
 
@@ -97,6 +132,19 @@ impl TopicMessage {
             }
         }
 
+        Self::assemble(pb, None)
+    }
+
+    pub(crate) fn from_incomplete_chunks(
+        pb: Vec<PbTopicMessageChunk>,
+        incomplete: IncompleteTopicMessage,
+    ) -> Self {
+        assert!(!pb.is_empty(), "no chunks provided to `TopicMessage::from_incomplete_chunks`");
+
+        Self::assemble(pb, Some(incomplete))
+    }
+
+    fn assemble(pb: Vec<PbTopicMessageChunk>, incomplete: Option<IncompleteTopicMessage>) -> Self {
         let contents = pb.iter().fold(Vec::new(), |mut acc, it| {
             acc.extend_from_slice(&it.header.message);
             acc
@@ -130,6 +178,7 @@ impl TopicMessage {
             sequence_number: last.header.sequence_number,
             chunks: Some(chunks),
             transaction: Some(last.initial_transaction_id),
+            incomplete,
         }
     }
 }
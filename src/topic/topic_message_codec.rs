@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::error::Error as StdError;
+use std::fmt;
+use std::marker::PhantomData;
+
+use hedera_proto::sdk;
+use prost::Message;
+
+use crate::Error;
+
+/// The error type returned by a [`TopicMessageCodec`].
+pub type TopicMessageCodecError = Box<dyn StdError + Send + Sync + 'static>;
+
+/// Converts between typed messages and the payload of a [`TopicMessageEnvelope`].
+///
+/// See [`TopicPublisher`](crate::TopicPublisher) and [`TopicSubscriber`](crate::TopicSubscriber).
+pub trait TopicMessageCodec: Send + Sync {
+    /// The type of the messages this codec encodes and decodes.
+    type Message;
+
+    /// Returns the media type of the encoded payloads, such as `application/json`.
+    fn content_type(&self) -> &str;
+
+    /// Encodes `message` into a payload.
+    fn encode(&self, message: &Self::Message) -> Result<Vec<u8>, TopicMessageCodecError>;
+
+    /// Decodes a payload that was encoded with version `schema_version` of the message schema.
+    fn decode(
+        &self,
+        payload: &[u8],
+        schema_version: u32,
+    ) -> Result<Self::Message, TopicMessageCodecError>;
+}
+
+/// A [`TopicMessageCodec`] that encodes messages as JSON, with `serde`.
+#[cfg(feature = "serde")]
+pub struct JsonCodec<T>(PhantomData<fn() -> T>);
+
+#[cfg(feature = "serde")]
+impl<T> JsonCodec<T> {
+    /// Create a new `JsonCodec`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> Default for JsonCodec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> Clone for JsonCodec<T> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> fmt::Debug for JsonCodec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("JsonCodec")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> TopicMessageCodec for JsonCodec<T>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    type Message = T;
+
+    fn content_type(&self) -> &str {
+        "application/json"
+    }
+
+    fn encode(&self, message: &T) -> Result<Vec<u8>, TopicMessageCodecError> {
+        Ok(serde_json::to_vec(message)?)
+    }
+
+    fn decode(&self, payload: &[u8], _schema_version: u32) -> Result<T, TopicMessageCodecError> {
+        Ok(serde_json::from_slice(payload)?)
+    }
+}
+
+/// A [`TopicMessageCodec`] that encodes messages as protobufs, with `prost`.
+pub struct ProtobufCodec<T>(PhantomData<fn() -> T>);
+
+impl<T> ProtobufCodec<T> {
+    /// Create a new `ProtobufCodec`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for ProtobufCodec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for ProtobufCodec<T> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for ProtobufCodec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProtobufCodec")
+    }
+}
+
+impl<T> TopicMessageCodec for ProtobufCodec<T>
+where
+    T: prost::Message + Default,
+{
+    type Message = T;
+
+    fn content_type(&self) -> &str {
+        "application/protobuf"
+    }
+
+    fn encode(&self, message: &T) -> Result<Vec<u8>, TopicMessageCodecError> {
+        Ok(message.encode_to_vec())
+    }
+
+    fn decode(&self, payload: &[u8], _schema_version: u32) -> Result<T, TopicMessageCodecError> {
+        Ok(T::decode(payload)?)
+    }
+}
+
+/// The contents of a topic message sent by a [`TopicPublisher`](crate::TopicPublisher),
+/// which say how to decode the payload they wrap.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TopicMessageEnvelope {
+    /// The version of the schema the payload was encoded with, chosen by the publisher.
+    pub schema_version: u32,
+
+    /// The media type of the payload, such as `application/json`.
+    pub content_type: String,
+
+    /// The encoded payload.
+    pub payload: Vec<u8>,
+}
+
+impl TopicMessageEnvelope {
+    /// Create a new envelope.
+    #[must_use]
+    pub fn new(schema_version: u32, content_type: impl Into<String>, payload: Vec<u8>) -> Self {
+        Self { schema_version, content_type: content_type.into(), payload }
+    }
+
+    /// Encodes `message` with `codec` into a new envelope.
+    ///
+    /// # Errors
+    /// - [`Error::TopicMessageEncode`] if `codec` fails to encode `message`.
+    pub fn encode<C: TopicMessageCodec + ?Sized>(
+        codec: &C,
+        schema_version: u32,
+        message: &C::Message,
+    ) -> crate::Result<Self> {
+        let payload = codec.encode(message).map_err(Error::TopicMessageEncode)?;
+
+        Ok(Self::new(schema_version, codec.content_type(), payload))
+    }
+
+    /// Decodes the payload of this envelope with `codec`.
+    ///
+    /// # Errors
+    /// - [`Error::TopicMessageDecode`] if the envelope's content type isn't the codec's,
+    ///   or `codec` fails to decode the payload.
+    pub fn decode<C: TopicMessageCodec + ?Sized>(&self, codec: &C) -> crate::Result<C::Message> {
+        if self.content_type != codec.content_type() {
+            return Err(Error::TopicMessageDecode(
+                format!(
+                    "expected content type `{}`, found `{}`",
+                    codec.content_type(),
+                    self.content_type
+                )
+                .into(),
+            ));
+        }
+
+        codec.decode(&self.payload, self.schema_version).map_err(Error::TopicMessageDecode)
+    }
+
+    /// Parses an envelope from the contents of a topic message.
+    ///
+    /// # Errors
+    /// - [`Error::TopicMessageDecode`] if `bytes` isn't an envelope.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let pb = sdk::TopicMessageEnvelope::decode(bytes)
+            .map_err(|e| Error::TopicMessageDecode(e.into()))?;
+
+        Ok(Self {
+            schema_version: pb.schema_version,
+            content_type: pb.content_type,
+            payload: pb.payload,
+        })
+    }
+
+    /// Convert `self` to the contents of a topic message.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        sdk::TopicMessageEnvelope {
+            schema_version: self.schema_version,
+            content_type: self.content_type.clone(),
+            payload: self.payload.clone(),
+        }
+        .encode_to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hedera_proto::services;
+
+    use super::{
+        ProtobufCodec,
+        TopicMessageEnvelope,
+    };
+    use crate::Error;
+
+    #[test]
+    fn envelope_round_trip() {
+        let envelope = TopicMessageEnvelope::new(2, "application/json", b"{}".to_vec());
+
+        assert_eq!(TopicMessageEnvelope::from_bytes(&envelope.to_bytes()).unwrap(), envelope);
+    }
+
+    #[test]
+    fn protobuf_round_trip() {
+        let codec = ProtobufCodec::<services::Duration>::new();
+        let duration = services::Duration { seconds: 90 };
+
+        let envelope = TopicMessageEnvelope::encode(&codec, 1, &duration).unwrap();
+
+        assert_eq!(envelope.content_type, "application/protobuf");
+        assert_eq!(envelope.decode(&codec).unwrap(), duration);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_round_trip() {
+        let codec = super::JsonCodec::<Vec<u32>>::new();
+
+        let envelope = TopicMessageEnvelope::encode(&codec, 1, &Vec::from([1, 2, 3])).unwrap();
+
+        assert_eq!(envelope.payload, b"[1,2,3]");
+        assert_eq!(envelope.decode(&codec).unwrap(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn decode_wrong_content_type() {
+        let envelope = TopicMessageEnvelope::new(1, "application/json", b"{}".to_vec());
+
+        assert_matches!(
+            envelope.decode(&ProtobufCodec::<services::Duration>::new()),
+            Err(Error::TopicMessageDecode(_))
+        );
+    }
+}
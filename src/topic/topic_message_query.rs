@@ -1,7 +1,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashMap;
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
 use std::{
+    cmp,
+    fmt,
     mem,
     task,
 };
@@ -9,7 +17,10 @@ use std::{
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
 use futures_core::Stream;
-use futures_util::TryStreamExt;
+use futures_util::{
+    StreamExt,
+    TryStreamExt,
+};
 use hedera_proto::mirror;
 use hedera_proto::mirror::consensus_service_client::ConsensusServiceClient;
 use hedera_proto::mirror::ConsensusTopicQuery;
@@ -17,14 +28,22 @@ use time::{
     Duration,
     OffsetDateTime,
 };
+use tokio::time::Instant;
 use tonic::transport::Channel;
 use tonic::Response;
 
 use super::topic_message::{
+    IncompleteTopicMessage,
+    IncompleteTopicMessageReason,
     PbTopicMessageChunk,
     PbTopicMessageHeader,
 };
+use super::topic_message_verifier::{
+    TopicMessageVerifier,
+    VerifiedMessage,
+};
 use crate::mirror_query::{
+    self,
     AnyMirrorQueryData,
     AnyMirrorQueryMessage,
     MirrorRequest,
@@ -32,8 +51,12 @@ use crate::mirror_query::{
 use crate::protobuf::FromProtobuf;
 use crate::{
     AnyMirrorQueryResponse,
+    Client,
+    Error,
     MirrorQuery,
     ToProtobuf,
+    TopicCheckpoint,
+    TopicCheckpointStore,
     TopicId,
     TopicMessage,
     TransactionId,
@@ -44,6 +67,11 @@ use crate::{
 
 // TODO: validate checksums after PR is merged
 
+const DEFAULT_INCOMPLETE_MESSAGE_EXPIRY: Duration = Duration::minutes(15);
+
+// the publisher picks `total`, so, cap it, a message with more chunks than this is never going to be reassembled anyway.
+const MAX_TOTAL_CHUNKS: i32 = 10_000;
+
 #[derive(Default)]
 pub struct TopicMessageQueryContext {
     start_time: Option<OffsetDateTime>,
@@ -67,14 +95,57 @@ pub struct TopicMessageQueryData {
 
     /// The maximum number of messages to receive before stopping.
     limit: u64,
+
+    /// Skip messages that were delivered before the checkpoint a subscription resumed from.
+    deduplicate: bool,
+
+    /// Fail if messages are missing or out of order.
+    verify_sequence_numbers: bool,
+
+    /// Fail if a message's running hash doesn't follow from the previous message.
+    verify_running_hashes: bool,
+
+    /// The message before the first message expected, to verify the first message against.
+    verify_after: Option<VerifiedMessage>,
+
+    /// How long to wait for the remaining chunks of a chunked message.
+    incomplete_message_expiry: Option<Duration>,
+
+    /// The maximum number of bytes to buffer for chunked messages that are still incomplete.
+    max_incomplete_message_bytes: Option<usize>,
+
+    /// What to do with a chunked message that's given up on.
+    incomplete_message_policy: IncompleteTopicMessagePolicy,
+
+    /// Called with every chunked message that's given up on.
+    on_incomplete_message: Option<IncompleteMessageHandler>,
 }
 
 impl TopicMessageQueryData {
-    fn map_stream<'a, S>(stream: S) -> impl Stream<Item = crate::Result<TopicMessage>>
+    fn map_stream<'a, S>(&self, stream: S) -> MessagesMapStream<S>
     where
         S: Stream<Item = crate::Result<mirror::ConsensusTopicResponse>> + Send + 'a,
     {
-        MessagesMapStream { inner: stream, incomplete_messages: HashMap::new() }
+        MessagesMapStream {
+            inner: stream,
+            assembler: ChunkAssembler::new(self),
+            verifier: self.verifier(),
+            ready: VecDeque::new(),
+            expiry_timer: None,
+            done: false,
+        }
+    }
+
+    fn verifier(&self) -> Option<TopicMessageVerifier> {
+        if !self.verify_sequence_numbers && !self.verify_running_hashes {
+            return None;
+        }
+
+        Some(TopicMessageVerifier::new(
+            self.topic_id?,
+            self.verify_running_hashes,
+            self.verify_after.clone(),
+        ))
     }
 }
 
@@ -128,6 +199,217 @@ impl TopicMessageQuery {
         self.data.limit = limit;
         self
     }
+
+    /// Returns whether messages delivered before the checkpoint a subscription resumes from are skipped.
+    #[must_use]
+    pub fn get_deduplicate(&self) -> bool {
+        self.data.deduplicate
+    }
+
+    /// Sets whether to skip messages delivered before the checkpoint a subscription resumes from,
+    /// by comparing their sequence numbers to the checkpoint's.
+    ///
+    /// Only applies to [`subscribe_with_checkpoints`](Self::subscribe_with_checkpoints).
+    /// Defaults to `false`, in which case a message may be delivered more than once across restarts.
+    pub fn deduplicate(&mut self, deduplicate: bool) -> &mut Self {
+        self.data.deduplicate = deduplicate;
+        self
+    }
+
+    /// Returns whether the subscription fails when messages are missing or out of order.
+    #[must_use]
+    pub fn get_verify_sequence_numbers(&self) -> bool {
+        self.data.verify_sequence_numbers
+    }
+
+    /// Sets whether the subscription fails with [`Error::TopicMessageVerification`]
+    /// when a message's sequence number doesn't directly follow the previous message's.
+    ///
+    /// Defaults to `false`.
+    pub fn verify_sequence_numbers(&mut self, verify: bool) -> &mut Self {
+        self.data.verify_sequence_numbers = verify;
+        self
+    }
+
+    /// Returns whether the subscription fails when a message's running hash doesn't follow from the previous message.
+    #[must_use]
+    pub fn get_verify_running_hashes(&self) -> bool {
+        self.data.verify_running_hashes
+    }
+
+    /// Sets whether the subscription fails with [`Error::TopicMessageVerification`]
+    /// when a message's running hash doesn't match the one recomputed from the previous message,
+    /// this also verifies sequence numbers.
+    ///
+    /// Only version 3 running hashes can be recomputed, and they depend on the account that paid for the message,
+    /// which the mirror node only provides for messages submitted with chunk info.
+    ///
+    /// Defaults to `false`.
+    pub fn verify_running_hashes(&mut self, verify: bool) -> &mut Self {
+        self.data.verify_running_hashes = verify;
+        self
+    }
+
+    /// Returns the sequence number and running hash of the message before the first message expected.
+    #[must_use]
+    pub fn get_verify_after(&self) -> Option<(u64, &[u8])> {
+        self.data.verify_after.as_ref().map(|it| (it.sequence_number, it.running_hash.as_slice()))
+    }
+
+    /// Sets the sequence number and running hash of the message before the first message expected,
+    /// so that the first message is verified too.
+    ///
+    /// Without this, the first message is trusted, and the rest are verified against it.
+    pub fn verify_after(
+        &mut self,
+        sequence_number: u64,
+        running_hash: impl Into<Vec<u8>>,
+    ) -> &mut Self {
+        self.data.verify_after =
+            Some(VerifiedMessage { sequence_number, running_hash: running_hash.into() });
+        self
+    }
+
+    /// Returns how long to wait for the remaining chunks of a chunked message before giving up on it.
+    #[must_use]
+    pub fn get_incomplete_message_expiry(&self) -> Duration {
+        self.data.incomplete_message_expiry.unwrap_or(DEFAULT_INCOMPLETE_MESSAGE_EXPIRY)
+    }
+
+    /// Sets how long to wait for the remaining chunks of a chunked message before giving up on it,
+    /// counting from when its first chunk is received.
+    ///
+    /// Defaults to 15 minutes.
+    pub fn incomplete_message_expiry(&mut self, expiry: Duration) -> &mut Self {
+        self.data.incomplete_message_expiry = Some(expiry);
+        self
+    }
+
+    /// Returns the maximum number of bytes to buffer for chunked messages that are still incomplete.
+    #[must_use]
+    pub fn get_max_incomplete_message_bytes(&self) -> Option<usize> {
+        self.data.max_incomplete_message_bytes
+    }
+
+    /// Sets the maximum number of bytes to buffer for chunked messages that are still incomplete,
+    /// when it's exceeded, the oldest incomplete messages are given up on.
+    ///
+    /// Defaults to _unlimited_.
+    pub fn max_incomplete_message_bytes(&mut self, max: usize) -> &mut Self {
+        self.data.max_incomplete_message_bytes = Some(max);
+        self
+    }
+
+    /// Returns what happens to a chunked message that's given up on.
+    #[must_use]
+    pub fn get_incomplete_message_policy(&self) -> IncompleteTopicMessagePolicy {
+        self.data.incomplete_message_policy
+    }
+
+    /// Sets what happens to a chunked message that's given up on.
+    ///
+    /// Defaults to [`IncompleteTopicMessagePolicy::Drop`].
+    pub fn incomplete_message_policy(&mut self, policy: IncompleteTopicMessagePolicy) -> &mut Self {
+        self.data.incomplete_message_policy = policy;
+        self
+    }
+
+    /// Sets a function to call with every chunked message that's given up on, whatever the policy is.
+    ///
+    /// The message contains the chunks that were received, and [`TopicMessage::incomplete`] says which are missing.
+    pub fn on_incomplete_message<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(&TopicMessage) + Send + Sync + 'static,
+    {
+        self.data.on_incomplete_message = Some(IncompleteMessageHandler(Arc::new(f)));
+        self
+    }
+
+    /// Subscribe to this query, resuming from the checkpoint in `store` and saving a new checkpoint as messages are consumed.
+    ///
+    /// A message is checkpointed once the next message is requested from the stream, so every message is delivered
+    /// at least once, even if the process stops while handling it.
+    /// If `store` has no checkpoint for the topic, the subscription starts at [`start_time`](Self::start_time).
+    ///
+    /// # Errors
+    /// - [`Error::TopicIdUnset`] as the first item if the topic ID isn't set.
+    pub fn subscribe_with_checkpoints<'a, S>(
+        &self,
+        client: &'a Client,
+        store: S,
+    ) -> BoxStream<'a, crate::Result<TopicMessage>>
+    where
+        S: TopicCheckpointStore + 'a,
+    {
+        let mut query = self.clone();
+
+        Box::pin(async_stream::try_stream! {
+            let topic_id = query.data.topic_id.ok_or(Error::TopicIdUnset)?;
+
+            let checkpoint = store.load(topic_id).await.map_err(Error::TopicCheckpointStore)?;
+
+            if let Some(checkpoint) = checkpoint {
+                query.data.start_time =
+                    Some(checkpoint.consensus_timestamp + Duration::nanoseconds(1));
+            }
+
+            let deduplicate = query.data.deduplicate;
+
+            let mut messages = query.subscribe_resumable(client);
+
+            while let Some(item) = messages.next().await {
+                let (message, resume_after) = item?;
+
+                if deduplicate
+                    && checkpoint.is_some_and(|it| message.sequence_number <= it.sequence_number)
+                {
+                    continue;
+                }
+
+                let checkpoint = TopicCheckpoint::new(resume_after, message.sequence_number);
+
+                yield message;
+
+                // the next message was requested, so the consumer is done with this one.
+                store.save(topic_id, checkpoint).await.map_err(Error::TopicCheckpointStore)?;
+            }
+        })
+    }
+
+    /// Subscribe to this query, yielding each message along with the consensus timestamp to resume after,
+    /// which is early enough to see every chunk of the messages that are still incomplete.
+    pub(crate) fn subscribe_resumable<'a>(
+        &self,
+        client: &'a Client,
+    ) -> BoxStream<'a, crate::Result<(TopicMessage, OffsetDateTime)>> {
+        let timeout = client.request_timeout().unwrap_or_else(|| {
+            std::time::Duration::from_millis(backoff::default::MAX_ELAPSED_TIME_MILLIS)
+        });
+
+        // note: we don't care about keeping the mirrornet around, so, we just take the channel (which is arc-like)
+        let channel = client.mirrornet().load().channel();
+
+        let data = self.data.clone();
+
+        Box::pin(async_stream::try_stream! {
+            let mut messages = std::pin::pin!(
+                data.map_stream(mirror_query::subscribe(channel, timeout, data.clone()))
+            );
+
+            while let Some(message) = messages.next().await {
+                let message = message?;
+
+                let resume_after = match messages.earliest_incomplete_timestamp() {
+                    Some(it) => {
+                        cmp::min(message.consensus_timestamp, it - Duration::nanoseconds(1))
+                    }
+                    None => message.consensus_timestamp,
+                };
+
+                yield (message, resume_after);
+            }
+        })
+    }
 }
 
 impl From<TopicMessageQueryData> for AnyMirrorQueryData {
@@ -181,19 +463,19 @@ impl MirrorRequest for TopicMessageQueryData {
         })
     }
 
-    fn make_item_stream<'a, S>(stream: S) -> Self::ItemStream<'a>
+    fn make_item_stream<'a, S>(&self, stream: S) -> Self::ItemStream<'a>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a,
     {
-        Box::pin(Self::map_stream(stream))
+        Box::pin(self.map_stream(stream))
     }
 
-    fn try_collect<'a, S>(stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
+    fn try_collect<'a, S>(&self, stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a,
     {
         // this doesn't reuse the work in `make_item_stream`
-        Box::pin(Self::map_stream(stream).try_collect())
+        Box::pin(self.map_stream(stream).try_collect())
     }
 
     fn update_context(context: &mut Self::Context, item: &Self::GrpcItem) {
@@ -214,22 +496,268 @@ impl From<Vec<TopicMessage>> for AnyMirrorQueryResponse {
     }
 }
 
+/// How a subscription handles a chunked message that's given up on before all of its chunks are received.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IncompleteTopicMessagePolicy {
+    /// Drop the message.
+    #[default]
+    Drop,
+
+    /// Deliver the chunks that were received, as a message with [`TopicMessage::incomplete`] set.
+    Deliver,
+
+    /// End the subscription with [`Error::IncompleteTopicMessage`].
+    Fail,
+}
+
+/// Called with every chunked message that's given up on, see [`TopicMessageQuery::on_incomplete_message`].
+#[derive(Clone)]
+struct IncompleteMessageHandler(Arc<dyn Fn(&TopicMessage) + Send + Sync>);
+
+impl fmt::Debug for IncompleteMessageHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IncompleteMessageHandler").finish_non_exhaustive()
+    }
+}
+
 enum IncompleteMessage {
-    Partial(OffsetDateTime, Vec<PbTopicMessageChunk>),
-    Expired,
+    Partial(Instant, Vec<PbTopicMessageChunk>),
+    /// Given up on, any chunks that arrive later are dropped.
+    Abandoned,
     Complete,
 }
 
-impl IncompleteMessage {
-    fn handle_expiry(&mut self) -> &mut Self {
-        match self {
-            IncompleteMessage::Partial(expiry, _) if *expiry < OffsetDateTime::now_utc() => {
-                *self = Self::Expired;
+/// Reassembles chunked messages, giving up on the ones that take too long or use too much memory.
+struct ChunkAssembler {
+    expiry: std::time::Duration,
+    max_buffered_bytes: Option<usize>,
+    policy: IncompleteTopicMessagePolicy,
+    handler: Option<IncompleteMessageHandler>,
+    incomplete_messages: HashMap<TransactionId, IncompleteMessage>,
+
+    /// The number of message bytes in the chunks of `Partial` messages.
+    buffered_bytes: usize,
+}
+
+impl ChunkAssembler {
+    fn new(data: &TopicMessageQueryData) -> Self {
+        Self {
+            // a negative expiry expires incomplete messages right away.
+            expiry: data
+                .incomplete_message_expiry
+                .unwrap_or(DEFAULT_INCOMPLETE_MESSAGE_EXPIRY)
+                .try_into()
+                .unwrap_or(std::time::Duration::ZERO),
+            max_buffered_bytes: data.max_incomplete_message_bytes,
+            policy: data.incomplete_message_policy,
+            handler: data.on_incomplete_message.clone(),
+            incomplete_messages: HashMap::new(),
+            buffered_bytes: 0,
+        }
+    }
+
+    fn push(
+        &mut self,
+        mut item: mirror::ConsensusTopicResponse,
+        out: &mut VecDeque<crate::Result<TopicMessage>>,
+    ) -> crate::Result<()> {
+        self.abandon_expired(out);
+
+        let header = PbTopicMessageHeader {
+            consensus_timestamp: pb_getf!(item, consensus_timestamp)?.into(),
+            sequence_number: item.sequence_number,
+            running_hash: item.running_hash,
+            running_hash_version: item.running_hash_version,
+            message: item.message,
+        };
+
+        let item = match item.chunk_info.take() {
+            Some(chunk_info) if chunk_info.total > 1 => PbTopicMessageChunk {
+                header,
+                initial_transaction_id: TransactionId::from_protobuf(pb_getf!(
+                    chunk_info,
+                    initial_transaction_id
+                )?)?,
+                number: chunk_info.number,
+                total: chunk_info.total,
+            },
+            _ => {
+                out.push_back(Ok(TopicMessage::from_single(header)));
+                return Ok(());
             }
-            _ => {}
+        };
+
+        let tx_id = item.initial_transaction_id;
+        let len = item.header.message.len();
+
+        if item.total > MAX_TOTAL_CHUNKS || !(1..=item.total).contains(&item.number) {
+            log::warn!(
+                "dropping chunk {} of {} of message `{tx_id}`, which is out of range",
+                item.number,
+                item.total
+            );
+            return Ok(());
         }
 
-        self
+        let entry = self.incomplete_messages.entry(tx_id).or_insert_with(|| {
+            IncompleteMessage::Partial(Instant::now() + self.expiry, Vec::new())
+        });
+
+        let IncompleteMessage::Partial(_, messages) = entry else {
+            log::debug!("dropping chunk {} of finished message `{tx_id}`", item.number);
+            return Ok(());
+        };
+
+        match messages.binary_search_by_key(&item.number, |it| it.number) {
+            // We have a duplicate `number`, so, we'll just ignore it (this is unspecified behavior)
+            Ok(_) => {
+                log::debug!("dropping duplicate chunk {} of message `{tx_id}`", item.number);
+                return Ok(());
+            }
+            Err(index) => messages.insert(index, item),
+        };
+
+        self.buffered_bytes += len;
+
+        // find the smallest `total` so that we aren't susceptable to stuff like total changing (and getting bigger)
+        // later on there's a check that ensures that they all have the same total.
+        let total = messages.iter().map(|it| it.total).min().unwrap();
+
+        // note: because of the way we handle `total`, `total` can get *smaller*.
+
+        if messages.len() >= total as usize {
+            let messages = mem::take(messages);
+            *entry = IncompleteMessage::Complete;
+            self.buffered_bytes -= messages.iter().map(|it| it.header.message.len()).sum::<usize>();
+            out.push_back(Ok(TopicMessage::from_chunks(messages)));
+        }
+
+        self.enforce_buffer_limit(out);
+
+        Ok(())
+    }
+
+    /// Gives up on every message that's still incomplete, because no more chunks will arrive.
+    fn finish(&mut self, out: &mut VecDeque<crate::Result<TopicMessage>>) {
+        for tx_id in self.partial_by_expiry() {
+            self.abandon(tx_id, IncompleteTopicMessageReason::StreamEnded, out);
+        }
+    }
+
+    fn abandon_expired(&mut self, out: &mut VecDeque<crate::Result<TopicMessage>>) {
+        let now = Instant::now();
+
+        let expired: Vec<_> = self
+            .partial_by_expiry()
+            .into_iter()
+            .filter(|tx_id| {
+                matches!(
+                    self.incomplete_messages.get(tx_id),
+                    Some(IncompleteMessage::Partial(expiry, _)) if *expiry <= now
+                )
+            })
+            .collect();
+
+        for tx_id in expired {
+            self.abandon(tx_id, IncompleteTopicMessageReason::Expired, out);
+        }
+    }
+
+    fn enforce_buffer_limit(&mut self, out: &mut VecDeque<crate::Result<TopicMessage>>) {
+        let Some(max_buffered_bytes) = self.max_buffered_bytes else { return };
+
+        // give up on the oldest messages first.
+        for tx_id in self.partial_by_expiry() {
+            if self.buffered_bytes <= max_buffered_bytes {
+                break;
+            }
+
+            self.abandon(tx_id, IncompleteTopicMessageReason::BufferLimitExceeded, out);
+        }
+    }
+
+    /// Returns the IDs of the incomplete messages, the ones that expire first (the oldest) first.
+    fn partial_by_expiry(&self) -> Vec<TransactionId> {
+        let mut partial: Vec<_> = self
+            .incomplete_messages
+            .iter()
+            .filter_map(|(tx_id, it)| match it {
+                IncompleteMessage::Partial(expiry, _) => Some((*expiry, *tx_id)),
+                IncompleteMessage::Abandoned | IncompleteMessage::Complete => None,
+            })
+            .collect();
+
+        partial.sort_by_key(|(expiry, _)| *expiry);
+
+        partial.into_iter().map(|(_, tx_id)| tx_id).collect()
+    }
+
+    fn abandon(
+        &mut self,
+        tx_id: TransactionId,
+        reason: IncompleteTopicMessageReason,
+        out: &mut VecDeque<crate::Result<TopicMessage>>,
+    ) {
+        let Some(entry) = self.incomplete_messages.get_mut(&tx_id) else { return };
+
+        let IncompleteMessage::Partial(_, messages) = entry else { return };
+
+        let messages = mem::take(messages);
+        *entry = IncompleteMessage::Abandoned;
+
+        self.buffered_bytes -= messages.iter().map(|it| it.header.message.len()).sum::<usize>();
+
+        // a `Partial` message always has at least one chunk.
+        let total_chunks = messages.iter().map(|it| it.total).min().unwrap_or_default();
+
+        let missing_chunks = (1..=total_chunks)
+            .filter(|number| messages.binary_search_by_key(number, |it| it.number).is_err())
+            .map(|number| number as u32)
+            .collect();
+
+        let message = TopicMessage::from_incomplete_chunks(
+            messages,
+            IncompleteTopicMessage { reason, total_chunks: total_chunks as u32, missing_chunks },
+        );
+
+        log::warn!("giving up on chunked message `{tx_id}` ({reason:?})");
+
+        if let Some(handler) = &self.handler {
+            (handler.0)(&message);
+        }
+
+        match self.policy {
+            IncompleteTopicMessagePolicy::Drop => {}
+            IncompleteTopicMessagePolicy::Deliver => out.push_back(Ok(message)),
+            IncompleteTopicMessagePolicy::Fail => {
+                out.push_back(Err(Error::IncompleteTopicMessage(Box::new(message))));
+            }
+        }
+    }
+
+    /// Returns when the first incomplete message expires.
+    fn next_expiry(&self) -> Option<Instant> {
+        self.incomplete_messages
+            .values()
+            .filter_map(|it| match it {
+                IncompleteMessage::Partial(expiry, _) => Some(*expiry),
+                IncompleteMessage::Abandoned | IncompleteMessage::Complete => None,
+            })
+            .min()
+    }
+
+    /// Returns the consensus timestamp of the earliest chunk that's part of a message which is still incomplete.
+    fn earliest_incomplete_timestamp(&self) -> Option<OffsetDateTime> {
+        self.incomplete_messages
+            .values()
+            .filter_map(|it| match it {
+                IncompleteMessage::Partial(_, chunks) => {
+                    chunks.iter().map(|it| it.header.consensus_timestamp).min()
+                }
+                IncompleteMessage::Abandoned | IncompleteMessage::Complete => None,
+            })
+            .min()
     }
 }
 
@@ -237,7 +765,19 @@ pin_project_lite::pin_project! {
     struct MessagesMapStream<S> {
         #[pin]
         inner: S,
-        incomplete_messages: HashMap<TransactionId, IncompleteMessage>,
+        assembler: ChunkAssembler,
+        verifier: Option<TopicMessageVerifier>,
+        ready: VecDeque<crate::Result<TopicMessage>>,
+        // fires when the first incomplete message expires, so messages expire even when no more chunks arrive.
+        expiry_timer: Option<(Instant, Pin<Box<tokio::time::Sleep>>)>,
+        done: bool,
+    }
+}
+
+impl<S> MessagesMapStream<S> {
+    /// Returns the consensus timestamp of the earliest chunk that's part of a message which is still incomplete.
+    fn earliest_incomplete_timestamp(&self) -> Option<OffsetDateTime> {
+        self.assembler.earliest_incomplete_timestamp()
     }
 }
 
@@ -256,90 +796,119 @@ where
         let mut this = self.project();
 
         loop {
-            let item = match task::ready!(this.inner.as_mut().poll_next(cx)) {
-                Some(Ok(item)) => item,
-                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
-                None => return Poll::Ready(None),
-            };
-
-            match filter_map(item, this.incomplete_messages) {
-                Ok(Some(item)) => return Poll::Ready(Some(Ok(item))),
-                Ok(None) => {}
-                Err(e) => return Poll::Ready(Some(Err(e))),
+            if let Some(item) = this.ready.pop_front() {
+                // `IncompleteTopicMessagePolicy::Fail` ends the subscription.
+                if let Err(Error::IncompleteTopicMessage(_)) = &item {
+                    this.ready.clear();
+                    *this.done = true;
+                }
+
+                return Poll::Ready(Some(item));
             }
-        }
-    }
-}
 
-fn filter_map(
-    mut item: mirror::ConsensusTopicResponse,
-    incomplete_messages: &mut HashMap<TransactionId, IncompleteMessage>,
-) -> crate::Result<Option<TopicMessage>> {
-    let header = PbTopicMessageHeader {
-        consensus_timestamp: pb_getf!(item, consensus_timestamp)?.into(),
-        sequence_number: item.sequence_number,
-        running_hash: item.running_hash,
-        running_hash_version: item.running_hash_version,
-        message: item.message,
-    };
-
-    let item = match item.chunk_info.take() {
-        Some(chunk_info) if chunk_info.total > 1 => PbTopicMessageChunk {
-            header,
-            initial_transaction_id: TransactionId::from_protobuf(pb_getf!(
-                chunk_info,
-                initial_transaction_id
-            )?)?,
-            number: chunk_info.number,
-            total: chunk_info.total,
-        },
-        _ => return Ok(Some(TopicMessage::from_single(header))),
-    };
-
-    let tx_id = item.initial_transaction_id;
-
-    let entry = incomplete_messages.entry(tx_id).or_insert_with(|| {
-        IncompleteMessage::Partial(
-            // todo: configurable?
-            OffsetDateTime::now_utc() + time::Duration::minutes(15),
-            Vec::new(),
-        )
-    });
-
-    let IncompleteMessage::Partial(_, messages) = entry.handle_expiry() else { return Ok(None) };
-
-    match messages.binary_search_by_key(&item.number, |it| it.number) {
-        // We have a duplicate `number`, so, we'll just ignore it (this is unspecified behavior)
-        Ok(_) => {}
-        Err(index) => messages.insert(index, item),
-    };
+            if *this.done {
+                return Poll::Ready(None);
+            }
 
-    // find the smallest `total` so that we aren't susceptable to stuff like total changing (and getting bigger)
-    // later on there's a check that ensures that they all have the same total.
-    let total = messages.iter().map(|it| it.total).min().unwrap();
+            let item = match this.inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(item))) => item,
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => {
+                    this.assembler.finish(this.ready);
+                    *this.done = true;
+                    continue;
+                }
+                // no chunks are arriving, but messages can still expire in the meantime.
+                Poll::Pending => {
+                    let Some(expiry) = this.assembler.next_expiry() else {
+                        *this.expiry_timer = None;
+                        return Poll::Pending;
+                    };
+
+                    // note: the timer and the expiries use the same clock,
+                    // so once the timer fires, the message it was set for has expired (and the timer is replaced).
+                    let timer = match this.expiry_timer.take() {
+                        Some((timer_expiry, timer)) if timer_expiry == expiry => timer,
+                        _ => Box::pin(tokio::time::sleep_until(expiry)),
+                    };
+
+                    let (_, timer) = this.expiry_timer.insert((expiry, timer));
+
+                    task::ready!(timer.as_mut().poll(cx));
+
+                    this.assembler.abandon_expired(this.ready);
+                    continue;
+                }
+            };
 
-    // note: because of the way we handle `total`, `total` can get *smaller*.
+            if let Some(verifier) = this.verifier.as_mut() {
+                if let Err(e) = verifier.verify(&item) {
+                    return Poll::Ready(Some(Err(e.into())));
+                }
+            }
 
-    match messages.len() >= total as usize {
-        true => {
-            let messages = mem::take(messages);
-            *entry = IncompleteMessage::Complete;
-            Ok(Some(TopicMessage::from_chunks(messages)))
+            if let Err(e) = this.assembler.push(item, this.ready) {
+                return Poll::Ready(Some(Err(e)));
+            }
         }
-
-        false => Ok(None),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use time::OffsetDateTime;
+    use std::collections::VecDeque;
+    use std::sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    };
+    use std::sync::Arc;
+
+    use assert_matches::assert_matches;
+    use futures_util::StreamExt;
+    use hedera_proto::{
+        mirror,
+        services,
+    };
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
 
+    use super::ChunkAssembler;
+    use crate::protobuf::ToProtobuf;
     use crate::{
+        AccountId,
+        Client,
+        Error,
+        FileCheckpointStore,
+        IncompleteTopicMessage,
+        IncompleteTopicMessagePolicy,
+        IncompleteTopicMessageReason,
         TopicId,
         TopicMessageQuery,
+        TransactionId,
     };
 
+    fn chunk(
+        tx_id: TransactionId,
+        number: i32,
+        total: i32,
+        message: &[u8],
+    ) -> mirror::ConsensusTopicResponse {
+        mirror::ConsensusTopicResponse {
+            consensus_timestamp: Some(OffsetDateTime::UNIX_EPOCH.into()),
+            message: message.to_vec(),
+            running_hash: Vec::new(),
+            sequence_number: number as u64,
+            running_hash_version: 3,
+            chunk_info: Some(services::ConsensusMessageChunkInfo {
+                initial_transaction_id: Some(tx_id.to_protobuf()),
+                number,
+                total,
+            }),
+        }
+    }
+
     #[test]
     fn get_set_topic_id() {
         let mut query = TopicMessageQuery::new();
@@ -347,6 +916,7 @@ mod tests {
 
         assert_eq!(query.get_topic_id(), Some(TopicId::new(31, 41, 59)));
     }
+
     #[test]
     fn get_set_start_time() {
         let start_time = OffsetDateTime::now_utc();
@@ -356,6 +926,7 @@ mod tests {
 
         assert_eq!(query.get_start_time(), Some(start_time));
     }
+
     #[test]
     fn get_set_end_time() {
         let end_time = OffsetDateTime::now_utc();
@@ -365,6 +936,7 @@ mod tests {
 
         assert_eq!(query.get_end_time(), Some(end_time));
     }
+
     #[test]
     fn get_set_limit() {
         let mut query = TopicMessageQuery::new();
@@ -372,4 +944,215 @@ mod tests {
 
         assert_eq!(query.get_limit(), 1415);
     }
+
+    #[test]
+    fn get_set_deduplicate() {
+        let mut query = TopicMessageQuery::new();
+        assert!(!query.get_deduplicate());
+
+        query.deduplicate(true);
+
+        assert!(query.get_deduplicate());
+    }
+
+    #[test]
+    fn get_set_verify_sequence_numbers() {
+        let mut query = TopicMessageQuery::new();
+        query.verify_sequence_numbers(true);
+
+        assert!(query.get_verify_sequence_numbers());
+    }
+
+    #[test]
+    fn get_set_verify_running_hashes() {
+        let mut query = TopicMessageQuery::new();
+        query.verify_running_hashes(true);
+
+        assert!(query.get_verify_running_hashes());
+    }
+
+    #[test]
+    fn get_set_verify_after() {
+        let mut query = TopicMessageQuery::new();
+        assert_eq!(query.get_verify_after(), None);
+
+        query.verify_after(5, [1; 48]);
+
+        assert_eq!(query.get_verify_after(), Some((5, [1; 48].as_slice())));
+    }
+
+    #[test]
+    fn get_set_incomplete_message_expiry() {
+        let mut query = TopicMessageQuery::new();
+        assert_eq!(query.get_incomplete_message_expiry(), Duration::minutes(15));
+
+        query.incomplete_message_expiry(Duration::seconds(30));
+
+        assert_eq!(query.get_incomplete_message_expiry(), Duration::seconds(30));
+    }
+
+    #[test]
+    fn get_set_max_incomplete_message_bytes() {
+        let mut query = TopicMessageQuery::new();
+        query.max_incomplete_message_bytes(1024);
+
+        assert_eq!(query.get_max_incomplete_message_bytes(), Some(1024));
+    }
+
+    #[test]
+    fn get_set_incomplete_message_policy() {
+        let mut query = TopicMessageQuery::new();
+        assert_eq!(query.get_incomplete_message_policy(), IncompleteTopicMessagePolicy::Drop);
+
+        query.incomplete_message_policy(IncompleteTopicMessagePolicy::Fail);
+
+        assert_eq!(query.get_incomplete_message_policy(), IncompleteTopicMessagePolicy::Fail);
+    }
+
+    #[test]
+    fn assemble_chunks() {
+        let tx_id = TransactionId::generate(AccountId::new(0, 0, 5006));
+        let mut assembler = ChunkAssembler::new(&TopicMessageQuery::new().data);
+        let mut out = VecDeque::new();
+
+        assembler.push(chunk(tx_id, 2, 2, b"world"), &mut out).unwrap();
+        assert!(out.is_empty());
+
+        assembler.push(chunk(tx_id, 1, 2, b"hello "), &mut out).unwrap();
+
+        let message = assert_matches!(out.pop_front(), Some(Ok(it)) => it);
+        assert_eq!(message.contents, b"hello world");
+        assert_eq!(message.incomplete, None);
+        assert_eq!(assembler.buffered_bytes, 0);
+    }
+
+    #[test]
+    fn out_of_range_chunks_are_dropped() {
+        let tx_id = TransactionId::generate(AccountId::new(0, 0, 5006));
+        let mut assembler = ChunkAssembler::new(&TopicMessageQuery::new().data);
+        let mut out = VecDeque::new();
+
+        assembler.push(chunk(tx_id, 1, i32::MAX, b"hello "), &mut out).unwrap();
+        assembler.push(chunk(tx_id, 3, 2, b"world"), &mut out).unwrap();
+        assembler.push(chunk(tx_id, -1, 2, b"world"), &mut out).unwrap();
+
+        assert!(out.is_empty());
+        assert!(assembler.incomplete_messages.is_empty());
+        assert_eq!(assembler.buffered_bytes, 0);
+    }
+
+    #[test]
+    fn expired_chunks_are_observed_and_dropped() {
+        let tx_id = TransactionId::generate(AccountId::new(0, 0, 5006));
+        let observed = Arc::new(AtomicUsize::new(0));
+
+        let mut query = TopicMessageQuery::new();
+        query.incomplete_message_expiry(Duration::seconds(-1)).on_incomplete_message({
+            let observed = Arc::clone(&observed);
+            move |message| {
+                assert_eq!(
+                    message.incomplete.as_ref().map(|it| it.reason),
+                    Some(IncompleteTopicMessageReason::Expired)
+                );
+                observed.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        let mut assembler = ChunkAssembler::new(&query.data);
+        let mut out = VecDeque::new();
+
+        assembler.push(chunk(tx_id, 1, 2, b"hello "), &mut out).unwrap();
+        assembler.push(chunk(tx_id, 2, 2, b"world"), &mut out).unwrap();
+
+        assert!(out.is_empty());
+        assert_eq!(observed.load(Ordering::SeqCst), 1);
+        assert_eq!(assembler.buffered_bytes, 0);
+    }
+
+    #[test]
+    fn buffer_limit_delivers_incomplete_message() {
+        let tx_id = TransactionId::generate(AccountId::new(0, 0, 5006));
+
+        let mut query = TopicMessageQuery::new();
+        query
+            .max_incomplete_message_bytes(4)
+            .incomplete_message_policy(IncompleteTopicMessagePolicy::Deliver);
+
+        let mut assembler = ChunkAssembler::new(&query.data);
+        let mut out = VecDeque::new();
+
+        assembler.push(chunk(tx_id, 1, 3, b"hello "), &mut out).unwrap();
+
+        let message = assert_matches!(out.pop_front(), Some(Ok(it)) => it);
+        assert_eq!(message.contents, b"hello ");
+        assert_eq!(
+            message.incomplete,
+            Some(IncompleteTopicMessage {
+                reason: IncompleteTopicMessageReason::BufferLimitExceeded,
+                total_chunks: 3,
+                missing_chunks: Vec::from([2, 3]),
+            })
+        );
+        assert_eq!(assembler.buffered_bytes, 0);
+    }
+
+    #[test]
+    fn stream_end_fails_incomplete_message() {
+        let tx_id = TransactionId::generate(AccountId::new(0, 0, 5006));
+
+        let mut query = TopicMessageQuery::new();
+        query.incomplete_message_policy(IncompleteTopicMessagePolicy::Fail);
+
+        let mut assembler = ChunkAssembler::new(&query.data);
+        let mut out = VecDeque::new();
+
+        assembler.push(chunk(tx_id, 2, 2, b"world"), &mut out).unwrap();
+        assembler.finish(&mut out);
+
+        let message =
+            assert_matches!(out.pop_front(), Some(Err(Error::IncompleteTopicMessage(it))) => it);
+        assert_eq!(
+            message.incomplete.map(|it| (it.reason, it.missing_chunks)),
+            Some((IncompleteTopicMessageReason::StreamEnded, Vec::from([1])))
+        );
+    }
+
+    #[tokio::test]
+    async fn subscribe_with_checkpoints_without_topic_id() {
+        let client = Client::for_testnet();
+        let store =
+            FileCheckpointStore::new(std::env::temp_dir().join("hedera-unused-checkpoints"));
+
+        let mut messages = TopicMessageQuery::new().subscribe_with_checkpoints(&client, store);
+
+        assert_matches!(messages.next().await, Some(Err(Error::TopicIdUnset)));
+        assert_matches!(messages.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn idle_stream_expires_incomplete_message() {
+        let tx_id = TransactionId::generate(AccountId::new(0, 0, 5006));
+
+        let mut query = TopicMessageQuery::new();
+        query
+            .incomplete_message_expiry(Duration::milliseconds(10))
+            .incomplete_message_policy(IncompleteTopicMessagePolicy::Deliver);
+
+        // the second chunk never arrives, and neither does anything else.
+        let responses = futures_util::stream::iter([Ok(chunk(tx_id, 1, 2, b"hello "))])
+            .chain(futures_util::stream::pending());
+
+        let mut messages = std::pin::pin!(query.data.map_stream(responses));
+
+        let message = tokio::time::timeout(std::time::Duration::from_secs(5), messages.next())
+            .await
+            .expect("incomplete message should expire while the stream is idle");
+
+        let message = assert_matches!(message, Some(Ok(it)) => it);
+        assert_eq!(message.contents, b"hello ");
+        assert_eq!(
+            message.incomplete.map(|it| (it.reason, it.missing_chunks)),
+            Some((IncompleteTopicMessageReason::Expired, Vec::from([2])))
+        );
+    }
 }
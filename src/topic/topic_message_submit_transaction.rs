@@ -81,6 +81,10 @@ impl TransactionData for TopicMessageSubmitTransactionData {
         Some(self.chunk_data())
     }
 
+    fn maybe_chunk_data_mut(&mut self) -> Option<&mut ChunkData> {
+        Some(self.chunk_data_mut())
+    }
+
     fn wait_for_receipt(&self) -> bool {
         false
     }
@@ -196,6 +200,7 @@ impl FromProtobuf<Vec<services::ConsensusSubmitMessageTransactionBody>>
                 chunk_size: NonZeroUsize::new(largest_chunk_size)
                     .unwrap_or_else(|| NonZeroUsize::new(1).unwrap()),
                 data: message,
+                ..Default::default()
             },
         })
     }
@@ -0,0 +1,344 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use hedera_proto::mirror;
+use sha2::{
+    Digest,
+    Sha384,
+};
+use time::OffsetDateTime;
+
+use crate::protobuf::FromProtobuf;
+use crate::{
+    AccountId,
+    TopicId,
+    TopicMessageVerificationError,
+};
+
+/// The only running hash version that can be recomputed.
+const RUNNING_HASH_VERSION: u64 = 3;
+
+/// The sequence number and running hash of the last message that passed verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct VerifiedMessage {
+    pub(crate) sequence_number: u64,
+    pub(crate) running_hash: Vec<u8>,
+}
+
+/// Checks that each message received from a topic subscription directly follows the previous one.
+#[derive(Debug, Clone)]
+pub(crate) struct TopicMessageVerifier {
+    topic_id: TopicId,
+    verify_running_hashes: bool,
+
+    /// `None` until the first message is received, unless the subscription was told what precedes it.
+    previous: Option<VerifiedMessage>,
+}
+
+impl TopicMessageVerifier {
+    pub(crate) fn new(
+        topic_id: TopicId,
+        verify_running_hashes: bool,
+        previous: Option<VerifiedMessage>,
+    ) -> Self {
+        Self { topic_id, verify_running_hashes, previous }
+    }
+
+    pub(crate) fn verify(
+        &mut self,
+        item: &mirror::ConsensusTopicResponse,
+    ) -> Result<(), TopicMessageVerificationError> {
+        let sequence_number = item.sequence_number;
+
+        if let Some(previous) = &self.previous {
+            let expected = previous.sequence_number + 1;
+
+            if sequence_number > expected {
+                return Err(TopicMessageVerificationError::SequenceGap {
+                    expected,
+                    actual: sequence_number,
+                });
+            }
+
+            if sequence_number < expected {
+                return Err(TopicMessageVerificationError::SequenceOutOfOrder {
+                    expected,
+                    actual: sequence_number,
+                });
+            }
+
+            if self.verify_running_hashes {
+                let expected = self.recompute_running_hash(&previous.running_hash, item)?;
+
+                if expected != item.running_hash {
+                    return Err(TopicMessageVerificationError::RunningHashMismatch {
+                        sequence_number,
+                        expected,
+                        actual: item.running_hash.clone(),
+                    });
+                }
+            }
+        }
+
+        self.previous =
+            Some(VerifiedMessage { sequence_number, running_hash: item.running_hash.clone() });
+
+        Ok(())
+    }
+
+    fn recompute_running_hash(
+        &self,
+        previous_running_hash: &[u8],
+        item: &mirror::ConsensusTopicResponse,
+    ) -> Result<Vec<u8>, TopicMessageVerificationError> {
+        let sequence_number = item.sequence_number;
+
+        if item.running_hash_version != RUNNING_HASH_VERSION {
+            return Err(TopicMessageVerificationError::UnsupportedRunningHashVersion {
+                sequence_number,
+                version: item.running_hash_version,
+            });
+        }
+
+        // the mirror node only says who paid for a message if it was submitted with chunk info.
+        let payer_account_id = item
+            .chunk_info
+            .as_ref()
+            .and_then(|it| it.initial_transaction_id.as_ref())
+            .and_then(|it| it.account_id.clone())
+            .and_then(|it| AccountId::from_protobuf(it).ok())
+            .ok_or(TopicMessageVerificationError::MissingPayerAccountId { sequence_number })?;
+
+        // a message without a timestamp can't match any running hash, so, the epoch is as good as anything.
+        let consensus_timestamp =
+            item.consensus_timestamp.map_or(OffsetDateTime::UNIX_EPOCH, OffsetDateTime::from);
+
+        Ok(running_hash_v3(
+            previous_running_hash,
+            payer_account_id,
+            self.topic_id,
+            consensus_timestamp,
+            sequence_number,
+            &item.message,
+        ))
+    }
+}
+
+/// Computes a version 3 running hash, the way consensus nodes do.
+///
+/// Consensus nodes write the hashed fields with a Java `ObjectOutputStream`,
+/// so they're preceded by the stream header and a block data header.
+fn running_hash_v3(
+    previous_running_hash: &[u8],
+    payer_account_id: AccountId,
+    topic_id: TopicId,
+    consensus_timestamp: OffsetDateTime,
+    sequence_number: u64,
+    message: &[u8],
+) -> Vec<u8> {
+    let mut data = Vec::new();
+
+    data.extend_from_slice(previous_running_hash);
+    data.extend_from_slice(&RUNNING_HASH_VERSION.to_be_bytes());
+    data.extend_from_slice(&payer_account_id.shard.to_be_bytes());
+    data.extend_from_slice(&payer_account_id.realm.to_be_bytes());
+    data.extend_from_slice(&payer_account_id.num.to_be_bytes());
+    data.extend_from_slice(&topic_id.shard.to_be_bytes());
+    data.extend_from_slice(&topic_id.realm.to_be_bytes());
+    data.extend_from_slice(&topic_id.num.to_be_bytes());
+    data.extend_from_slice(&consensus_timestamp.unix_timestamp().to_be_bytes());
+    data.extend_from_slice(&(consensus_timestamp.nanosecond() as i32).to_be_bytes());
+    data.extend_from_slice(&sequence_number.to_be_bytes());
+    data.extend_from_slice(&Sha384::digest(message));
+
+    let mut hasher = Sha384::new();
+
+    // `STREAM_MAGIC` and `STREAM_VERSION`.
+    hasher.update([0xac, 0xed, 0x00, 0x05]);
+
+    // `TC_BLOCKDATA` (with a 1 byte length) or `TC_BLOCKDATALONG` (with a 4 byte length).
+    match u8::try_from(data.len()) {
+        Ok(len) => hasher.update([0x77, len]),
+        Err(_) => {
+            hasher.update([0x7a]);
+            hasher.update((data.len() as u32).to_be_bytes());
+        }
+    }
+
+    hasher.update(&data);
+
+    hasher.finalize().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hedera_proto::{
+        mirror,
+        services,
+    };
+    use time::OffsetDateTime;
+
+    use super::{
+        running_hash_v3,
+        TopicMessageVerifier,
+        VerifiedMessage,
+    };
+    use crate::protobuf::ToProtobuf;
+    use crate::{
+        AccountId,
+        TopicId,
+        TopicMessageVerificationError,
+        TransactionId,
+    };
+
+    const TOPIC_ID: TopicId = TopicId::new(0, 0, 1001);
+    const PAYER_ACCOUNT_ID: AccountId = AccountId::new(0, 0, 5006);
+
+    /// Makes the message that follows `previous` on `TOPIC_ID`.
+    fn next_message(previous: &VerifiedMessage, message: &[u8]) -> mirror::ConsensusTopicResponse {
+        let sequence_number = previous.sequence_number + 1;
+        let consensus_timestamp =
+            OffsetDateTime::from_unix_timestamp(1_700_000_000 + sequence_number as i64).unwrap();
+
+        mirror::ConsensusTopicResponse {
+            consensus_timestamp: Some(consensus_timestamp.into()),
+            message: message.to_vec(),
+            running_hash: running_hash_v3(
+                &previous.running_hash,
+                PAYER_ACCOUNT_ID,
+                TOPIC_ID,
+                consensus_timestamp,
+                sequence_number,
+                message,
+            ),
+            sequence_number,
+            running_hash_version: 3,
+            chunk_info: Some(services::ConsensusMessageChunkInfo {
+                initial_transaction_id: Some(
+                    TransactionId {
+                        account_id: PAYER_ACCOUNT_ID,
+                        valid_start: consensus_timestamp,
+                        nonce: None,
+                        scheduled: false,
+                    }
+                    .to_protobuf(),
+                ),
+                total: 1,
+                number: 1,
+            }),
+        }
+    }
+
+    fn verified(item: &mirror::ConsensusTopicResponse) -> VerifiedMessage {
+        VerifiedMessage {
+            sequence_number: item.sequence_number,
+            running_hash: item.running_hash.clone(),
+        }
+    }
+
+    fn genesis() -> VerifiedMessage {
+        VerifiedMessage { sequence_number: 0, running_hash: vec![0; 48] }
+    }
+
+    #[test]
+    fn verify_chain() {
+        let first = next_message(&genesis(), b"hello");
+        let second = next_message(&verified(&first), b"world");
+
+        let mut verifier = TopicMessageVerifier::new(TOPIC_ID, true, Some(genesis()));
+
+        verifier.verify(&first).unwrap();
+        verifier.verify(&second).unwrap();
+    }
+
+    #[test]
+    fn verify_first_message_without_previous() {
+        let first = next_message(&genesis(), b"hello");
+        let mut second = next_message(&verified(&first), b"world");
+
+        second.running_hash_version = 2;
+
+        let mut verifier = TopicMessageVerifier::new(TOPIC_ID, true, None);
+
+        // the first message is trusted, since there's nothing to check it against.
+        verifier.verify(&second).unwrap();
+    }
+
+    #[test]
+    fn verify_tampered_message() {
+        let first = next_message(&genesis(), b"hello");
+        let mut second = next_message(&verified(&first), b"world");
+
+        second.message = b"w0rld".to_vec();
+
+        let mut verifier = TopicMessageVerifier::new(TOPIC_ID, true, None);
+
+        verifier.verify(&first).unwrap();
+
+        assert_matches!(
+            verifier.verify(&second),
+            Err(TopicMessageVerificationError::RunningHashMismatch { sequence_number: 2, .. })
+        );
+    }
+
+    #[test]
+    fn verify_without_running_hashes() {
+        let first = next_message(&genesis(), b"hello");
+        let mut second = next_message(&verified(&first), b"world");
+
+        second.message = b"w0rld".to_vec();
+
+        let mut verifier = TopicMessageVerifier::new(TOPIC_ID, false, None);
+
+        verifier.verify(&first).unwrap();
+        verifier.verify(&second).unwrap();
+    }
+
+    #[test]
+    fn verify_gap() {
+        let first = next_message(&genesis(), b"hello");
+        let second = next_message(&verified(&first), b"world");
+        let third = next_message(&verified(&second), b"!");
+
+        let mut verifier = TopicMessageVerifier::new(TOPIC_ID, true, None);
+
+        verifier.verify(&first).unwrap();
+
+        assert_matches!(
+            verifier.verify(&third),
+            Err(TopicMessageVerificationError::SequenceGap { expected: 2, actual: 3 })
+        );
+    }
+
+    #[test]
+    fn verify_out_of_order() {
+        let first = next_message(&genesis(), b"hello");
+        let second = next_message(&verified(&first), b"world");
+
+        let mut verifier = TopicMessageVerifier::new(TOPIC_ID, true, None);
+
+        verifier.verify(&second).unwrap();
+
+        assert_matches!(
+            verifier.verify(&first),
+            Err(TopicMessageVerificationError::SequenceOutOfOrder { expected: 3, actual: 1 })
+        );
+    }
+
+    #[test]
+    fn verify_missing_payer() {
+        let first = next_message(&genesis(), b"hello");
+        let mut second = next_message(&verified(&first), b"world");
+
+        second.chunk_info = None;
+
+        let mut verifier = TopicMessageVerifier::new(TOPIC_ID, true, None);
+
+        verifier.verify(&first).unwrap();
+
+        assert_matches!(
+            verifier.verify(&second),
+            Err(TopicMessageVerificationError::MissingPayerAccountId { sequence_number: 2 })
+        );
+    }
+}
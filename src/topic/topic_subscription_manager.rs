@@ -0,0 +1,504 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::cmp::Ordering;
+use std::collections::{
+    BinaryHeap,
+    HashMap,
+    HashSet,
+    VecDeque,
+};
+use std::fmt;
+use std::pin::pin;
+use std::sync::{
+    Arc,
+    Weak,
+};
+use std::time::Duration;
+
+use async_stream::stream;
+use backoff::backoff::Backoff;
+use backoff::ExponentialBackoff;
+use futures_core::stream::BoxStream;
+use futures_core::Stream;
+use futures_util::future::{
+    self,
+    Either,
+};
+use futures_util::StreamExt;
+use parking_lot::Mutex;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+use crate::{
+    Client,
+    Error,
+    TopicId,
+    TopicMessage,
+    TopicMessageQuery,
+};
+
+const DEFAULT_CHANNEL_CAPACITY: usize = 1024;
+
+const DEFAULT_MERGE_DELAY: Duration = Duration::from_secs(1);
+
+/// What a shared subscription broadcasts to its consumers.
+type SharedItem = Result<TopicMessage, Arc<Error>>;
+
+/// The one subscription to a topic that all of its consumers share.
+///
+/// Dropped, and the subscription with it, once the last consumer is gone.
+struct SharedSubscription {
+    /// Never read, kept so that consumers joining later can `resubscribe`.
+    receiver: broadcast::Receiver<SharedItem>,
+    task: JoinHandle<()>,
+}
+
+impl Drop for SharedSubscription {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Multiplexes many topic subscriptions over the client's mirror network.
+///
+/// Each topic is subscribed to at most once, no matter how many consumers it has:
+/// its messages are fanned out to every consumer through a broadcast channel,
+/// and the subscription is closed once the last consumer is dropped.
+/// All subscriptions share the client's connection to the mirror node.
+///
+/// When a subscription fails, the manager reopens it after the last message it received
+/// (or before the first chunk of a message that's still incomplete),
+/// so its consumers see the error followed by the messages that come after it.
+///
+/// Subscriptions run as tasks on the Tokio runtime, so the manager must be used from within one.
+pub struct TopicSubscriptionManager {
+    client: Client,
+    query: TopicMessageQuery,
+    channel_capacity: usize,
+    merge_delay: Duration,
+    subscriptions: Mutex<HashMap<TopicId, Weak<SharedSubscription>>>,
+}
+
+impl fmt::Debug for TopicSubscriptionManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TopicSubscriptionManager")
+            .field("query", &self.query)
+            .field("channel_capacity", &self.channel_capacity)
+            .field("merge_delay", &self.merge_delay)
+            .field("topic_ids", &self.get_topic_ids())
+            .finish_non_exhaustive()
+    }
+}
+
+impl TopicSubscriptionManager {
+    /// Create a new `TopicSubscriptionManager` that subscribes to topics through `client`.
+    #[must_use]
+    pub fn new(client: &Client) -> Self {
+        Self {
+            client: client.clone(),
+            query: TopicMessageQuery::new(),
+            channel_capacity: DEFAULT_CHANNEL_CAPACITY,
+            merge_delay: DEFAULT_MERGE_DELAY,
+            subscriptions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the query each topic is subscribed to with.
+    #[must_use]
+    pub fn get_query(&self) -> &TopicMessageQuery {
+        &self.query
+    }
+
+    /// Sets the query each topic is subscribed to with, such as to start from an earlier time.
+    ///
+    /// Its topic ID is replaced with the topic being subscribed to,
+    /// and whenever the subscription is reopened, its start time with the time after the last message,
+    /// and its limit with the number of messages that are left.
+    ///
+    /// Only applies to topics that aren't already subscribed to.
+    pub fn query(&mut self, query: TopicMessageQuery) -> &mut Self {
+        self.query = query;
+        self
+    }
+
+    /// Returns how many messages each topic buffers for consumers that fall behind.
+    #[must_use]
+    pub fn get_channel_capacity(&self) -> usize {
+        self.channel_capacity
+    }
+
+    /// Sets how many messages each topic buffers for consumers that fall behind.
+    ///
+    /// A consumer that falls further behind skips the oldest messages,
+    /// and receives [`Error::TopicSubscriptionLagged`] saying how many.
+    ///
+    /// Defaults to 1024, only applies to topics that aren't already subscribed to.
+    ///
+    /// # Panics
+    /// - If `capacity` is 0.
+    pub fn channel_capacity(&mut self, capacity: usize) -> &mut Self {
+        assert!(capacity > 0, "channel capacity must be non-zero");
+
+        self.channel_capacity = capacity;
+        self
+    }
+
+    /// Returns how long [`subscribe_merged`](Self::subscribe_merged) holds each message back to order it.
+    #[must_use]
+    pub fn get_merge_delay(&self) -> Duration {
+        self.merge_delay
+    }
+
+    /// Sets how long [`subscribe_merged`](Self::subscribe_merged) holds each message back to order it.
+    ///
+    /// Defaults to 1 second.
+    pub fn merge_delay(&mut self, delay: Duration) -> &mut Self {
+        self.merge_delay = delay;
+        self
+    }
+
+    /// Returns the IDs of the topics that currently have consumers.
+    #[must_use]
+    pub fn get_topic_ids(&self) -> Vec<TopicId> {
+        self.subscriptions
+            .lock()
+            .iter()
+            .filter(|(_, subscription)| subscription.strong_count() > 0)
+            .map(|(topic_id, _)| *topic_id)
+            .collect()
+    }
+
+    /// Subscribe to the messages of `topic_id`, sharing the subscription with every other consumer of the topic.
+    ///
+    /// A consumer that joins an existing subscription receives the messages that arrive after it joins.
+    ///
+    /// Errors are returned as `Err` without ending the stream, only a subscription that can't be reopened,
+    /// such as to a topic that doesn't exist, ends after its error.
+    ///
+    /// # Panics
+    /// - If called outside of a Tokio runtime.
+    pub fn subscribe(
+        &self,
+        topic_id: impl Into<TopicId>,
+    ) -> BoxStream<'static, crate::Result<TopicMessage>> {
+        let topic_id = topic_id.into();
+        let (subscription, mut receiver) = self.join(topic_id);
+
+        Box::pin(stream! {
+            // keeps the shared subscription open for as long as this consumer exists.
+            let _subscription = subscription;
+
+            loop {
+                match receiver.recv().await {
+                    Ok(Ok(message)) => yield Ok(message),
+                    Ok(Err(error)) => {
+                        yield Err(Error::TopicSubscription { topic_id: Box::new(topic_id), error });
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        yield Err(Error::TopicSubscriptionLagged {
+                            topic_id: Box::new(topic_id),
+                            skipped,
+                        });
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return,
+                }
+            }
+        })
+    }
+
+    /// Subscribe to the messages of all of `topic_ids` as one stream, ordered by consensus timestamp,
+    /// each along with the topic it was sent to.
+    ///
+    /// Each message is held back for the [merge delay](Self::merge_delay) so that messages from other topics
+    /// that reached consensus before it, but arrived after it, can overtake it.
+    /// Messages that arrive later than that are delivered as soon as they arrive, out of order.
+    ///
+    /// # Panics
+    /// - If called outside of a Tokio runtime.
+    pub fn subscribe_merged(
+        &self,
+        topic_ids: impl IntoIterator<Item = TopicId>,
+    ) -> BoxStream<'static, crate::Result<(TopicId, TopicMessage)>> {
+        let topic_ids: HashSet<_> = topic_ids.into_iter().collect();
+
+        let streams = futures_util::stream::select_all(topic_ids.into_iter().map(|topic_id| {
+            self.subscribe(topic_id).map(move |it| it.map(|message| (topic_id, message)))
+        }));
+
+        Box::pin(merge_by_consensus_timestamp(streams, self.merge_delay))
+    }
+
+    /// Joins the subscription to `topic_id`, opening it if there isn't one.
+    fn join(
+        &self,
+        topic_id: TopicId,
+    ) -> (Arc<SharedSubscription>, broadcast::Receiver<SharedItem>) {
+        let mut subscriptions = self.subscriptions.lock();
+
+        subscriptions.retain(|_, subscription| subscription.strong_count() > 0);
+
+        if let Some(subscription) = subscriptions.get(&topic_id).and_then(Weak::upgrade) {
+            if !subscription.task.is_finished() {
+                let receiver = subscription.receiver.resubscribe();
+                return (subscription, receiver);
+            }
+        }
+
+        let (sender, receiver) = broadcast::channel(self.channel_capacity);
+
+        let mut query = self.query.clone();
+        query.topic_id(topic_id);
+
+        let task = tokio::task::spawn(run_shared_subscription(
+            self.client.clone(),
+            topic_id,
+            query,
+            sender,
+        ));
+
+        let subscription = Arc::new(SharedSubscription { receiver: receiver.resubscribe(), task });
+
+        subscriptions.insert(topic_id, Arc::downgrade(&subscription));
+
+        (subscription, receiver)
+    }
+}
+
+/// Broadcasts the messages of `query`, reopening it after it fails, until it ends or fails permanently.
+async fn run_shared_subscription(
+    client: Client,
+    topic_id: TopicId,
+    mut query: TopicMessageQuery,
+    sender: broadcast::Sender<SharedItem>,
+) {
+    let mut backoff =
+        ExponentialBackoff { max_elapsed_time: None, ..ExponentialBackoff::default() };
+
+    // `0` is no limit.
+    let limit = query.get_limit();
+
+    // the number of responses (chunks) delivered, which is what the mirror node counts towards the limit.
+    let mut delivered = 0_u64;
+    let mut last_sequence_number = None;
+
+    loop {
+        let mut stream = query.subscribe_resumable(&client);
+
+        let error = loop {
+            match stream.next().await {
+                Some(Ok((message, resume_after))) => {
+                    backoff.reset();
+
+                    // a reopened subscription starts before the messages that were incomplete,
+                    // so it can repeat messages that were completed after them.
+                    if last_sequence_number.is_some_and(|it| message.sequence_number <= it) {
+                        continue;
+                    }
+
+                    last_sequence_number = Some(message.sequence_number);
+                    delivered += message.chunks.as_ref().map_or(1, Vec::len) as u64;
+
+                    // if the subscription has to be reopened, it picks up after this message.
+                    query.start_time(resume_after + time::Duration::NANOSECOND);
+
+                    // note: the subscription holds a receiver itself, so, this can't fail.
+                    let _ = sender.send(Ok(message));
+                }
+                Some(Err(error)) => break error,
+                None => return,
+            }
+        };
+
+        if limit != 0 {
+            // every message was delivered before the subscription failed.
+            if delivered >= limit {
+                return;
+            }
+
+            query.limit(limit - delivered);
+        }
+
+        let permanent = is_permanent(&error);
+
+        if !permanent {
+            log::debug!("reopening subscription to topic `{topic_id}` after error: {error}");
+        }
+
+        let _ = sender.send(Err(Arc::new(error)));
+
+        if permanent {
+            return;
+        }
+
+        tokio::time::sleep(backoff.next_backoff().expect("backoff has no maximum elapsed time"))
+            .await;
+    }
+}
+
+/// Returns `true` if reopening the subscription would fail the same way.
+fn is_permanent(error: &Error) -> bool {
+    matches!(
+        error,
+        Error::GrpcStatus(status) if matches!(
+            status.code(),
+            tonic::Code::NotFound | tonic::Code::InvalidArgument | tonic::Code::PermissionDenied
+        )
+    )
+}
+
+/// Orders messages by consensus timestamp, the earliest first.
+struct ByConsensusTimestamp(TopicId, TopicMessage);
+
+impl ByConsensusTimestamp {
+    // note: messages sent to different topics can have the same consensus timestamp, but not to the same topic.
+    fn key(&self) -> (time::OffsetDateTime, u64, u64, u64) {
+        (self.1.consensus_timestamp, self.0.shard, self.0.realm, self.0.num)
+    }
+}
+
+impl PartialEq for ByConsensusTimestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for ByConsensusTimestamp {}
+
+impl PartialOrd for ByConsensusTimestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByConsensusTimestamp {
+    // note: reversed, since `BinaryHeap` pops the greatest element first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.key().cmp(&self.key())
+    }
+}
+
+/// Merges the messages of `streams`, holding each back for `delay` to deliver them ordered by consensus timestamp.
+///
+/// Each time a message has been held for `delay`, the earliest message held is delivered,
+/// which isn't necessarily that message.
+fn merge_by_consensus_timestamp<S>(
+    streams: S,
+    delay: Duration,
+) -> impl Stream<Item = crate::Result<(TopicId, TopicMessage)>> + Send
+where
+    S: Stream<Item = crate::Result<(TopicId, TopicMessage)>> + Send + Unpin,
+{
+    stream! {
+        let mut streams = streams;
+        let mut held = BinaryHeap::new();
+
+        // when each held message is up, in the order they arrived.
+        let mut deadlines = VecDeque::new();
+
+        loop {
+            while deadlines.front().is_some_and(|it| *it <= Instant::now()) {
+                deadlines.pop_front();
+
+                if let Some(ByConsensusTimestamp(topic_id, message)) = held.pop() {
+                    yield Ok((topic_id, message));
+                }
+            }
+
+            let sleep = match deadlines.front() {
+                Some(deadline) => Either::Left(tokio::time::sleep_until(*deadline)),
+                None => Either::Right(future::pending()),
+            };
+
+            match future::select(streams.next(), pin!(sleep)).await {
+                Either::Left((Some(Ok((topic_id, message))), _)) => {
+                    held.push(ByConsensusTimestamp(topic_id, message));
+                    deadlines.push_back(Instant::now() + delay);
+                }
+                Either::Left((Some(Err(error)), _)) => yield Err(error),
+                Either::Left((None, _)) => break,
+                Either::Right(_) => {}
+            }
+        }
+
+        while let Some(ByConsensusTimestamp(topic_id, message)) = held.pop() {
+            yield Ok((topic_id, message));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures_util::StreamExt;
+    use time::OffsetDateTime;
+
+    use super::merge_by_consensus_timestamp;
+    use crate::{
+        TopicId,
+        TopicMessage,
+    };
+
+    fn message(topic_num: u64, consensus_timestamp: i64) -> (TopicId, TopicMessage) {
+        let message = TopicMessage {
+            consensus_timestamp: OffsetDateTime::from_unix_timestamp(consensus_timestamp).unwrap(),
+            contents: Vec::new(),
+            running_hash: Vec::new(),
+            running_hash_version: 3,
+            sequence_number: 1,
+            chunks: None,
+            transaction: None,
+            incomplete: None,
+        };
+
+        (TopicId::new(0, 0, topic_num), message)
+    }
+
+    async fn merge(messages: Vec<(TopicId, TopicMessage)>, delay: Duration) -> Vec<(u64, i64)> {
+        let streams = futures_util::stream::iter(messages.into_iter().map(Ok))
+            .chain(futures_util::stream::pending());
+
+        merge_by_consensus_timestamp(Box::pin(streams), delay)
+            .take(3)
+            .map(|it| {
+                let (topic_id, message) = it.unwrap();
+                (topic_id.num, message.consensus_timestamp.unix_timestamp())
+            })
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn merge_orders_held_messages() {
+        let messages = Vec::from([message(1, 3), message(2, 1), message(1, 2)]);
+
+        assert_eq!(merge(messages, Duration::from_millis(10)).await, [(2, 1), (1, 2), (1, 3)]);
+    }
+
+    #[tokio::test]
+    async fn merge_orders_same_timestamp_by_topic() {
+        let messages = Vec::from([message(3, 1), message(1, 1), message(2, 1)]);
+
+        assert_eq!(merge(messages, Duration::from_millis(10)).await, [(1, 1), (2, 1), (3, 1)]);
+    }
+
+    #[tokio::test]
+    async fn merge_without_delay_keeps_arrival_order() {
+        let messages = Vec::from([message(1, 3), message(1, 1), message(1, 2)]);
+
+        assert_eq!(merge(messages, Duration::ZERO).await, [(1, 3), (1, 1), (1, 2)]);
+    }
+
+    #[tokio::test]
+    async fn merge_flushes_when_streams_end() {
+        let streams = futures_util::stream::iter([Ok(message(1, 2)), Ok(message(1, 1))]);
+
+        let merged: Vec<_> = merge_by_consensus_timestamp(streams, Duration::from_secs(3600))
+            .map(|it| it.unwrap().1.consensus_timestamp.unix_timestamp())
+            .collect()
+            .await;
+
+        assert_eq!(merged, [1, 2]);
+    }
+}
@@ -14,6 +14,10 @@ use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -247,7 +251,14 @@ impl TopicUpdateTransaction {
     }
 }
 
-impl TransactionData for TopicUpdateTransactionData {}
+impl TransactionData for TopicUpdateTransactionData {
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        if let Some(memo) = &self.topic_memo {
+            validation::validate_memo("topic_memo", memo, errors);
+        }
+        validation::validate_auto_renew_period("auto_renew_period", self.auto_renew_period, errors);
+    }
+}
 
 impl TransactionExecute for TopicUpdateTransactionData {
     fn execute(
@@ -0,0 +1,225 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use futures_core::stream::BoxStream;
+use futures_util::StreamExt;
+
+use crate::{
+    Client,
+    TopicId,
+    TopicMessage,
+    TopicMessageCodec,
+    TopicMessageEnvelope,
+    TopicMessageQuery,
+    TopicMessageSubmitTransaction,
+    TransactionResponse,
+};
+
+/// Publishes typed messages to a topic, wrapped in a [`TopicMessageEnvelope`].
+///
+/// Read them back with a [`TopicSubscriber`] that uses the same codec.
+#[derive(Clone, Debug)]
+pub struct TopicPublisher<C> {
+    topic_id: TopicId,
+    codec: C,
+    schema_version: u32,
+}
+
+impl<C: TopicMessageCodec> TopicPublisher<C> {
+    /// Create a new `TopicPublisher` that encodes messages to `topic_id` with `codec`.
+    #[must_use]
+    pub fn new(topic_id: impl Into<TopicId>, codec: C) -> Self {
+        Self { topic_id: topic_id.into(), codec, schema_version: 1 }
+    }
+
+    /// Returns the ID of the topic messages are published to.
+    #[must_use]
+    pub fn get_topic_id(&self) -> TopicId {
+        self.topic_id
+    }
+
+    /// Returns the codec messages are encoded with.
+    #[must_use]
+    pub fn get_codec(&self) -> &C {
+        &self.codec
+    }
+
+    /// Returns the schema version recorded in each envelope.
+    #[must_use]
+    pub fn get_schema_version(&self) -> u32 {
+        self.schema_version
+    }
+
+    /// Sets the schema version recorded in each envelope, which is passed to the codec when decoding.
+    ///
+    /// Defaults to `1`.
+    pub fn schema_version(&mut self, schema_version: u32) -> &mut Self {
+        self.schema_version = schema_version;
+        self
+    }
+
+    /// Returns a transaction that submits `message`, to be configured further before executing it.
+    ///
+    /// # Errors
+    /// - [`Error::TopicMessageEncode`](crate::Error::TopicMessageEncode) if the codec fails to encode `message`.
+    pub fn transaction(
+        &self,
+        message: &C::Message,
+    ) -> crate::Result<TopicMessageSubmitTransaction> {
+        let envelope = TopicMessageEnvelope::encode(&self.codec, self.schema_version, message)?;
+
+        let mut transaction = TopicMessageSubmitTransaction::new();
+
+        transaction.topic_id(self.topic_id).message(envelope.to_bytes());
+
+        Ok(transaction)
+    }
+
+    /// Publishes `message`, returning the response of each chunk it's submitted in.
+    ///
+    /// # Errors
+    /// - [`Error::TopicMessageEncode`](crate::Error::TopicMessageEncode) if the codec fails to encode `message`.
+    /// - Any error from executing the transaction.
+    pub async fn publish(
+        &self,
+        client: &Client,
+        message: &C::Message,
+    ) -> crate::Result<Vec<TransactionResponse>> {
+        self.transaction(message)?.execute_all(client).await
+    }
+}
+
+/// A topic message received by a [`TopicSubscriber`].
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct TypedTopicMessage<T> {
+    /// The message as it was received, its contents are the encoded envelope.
+    pub message: TopicMessage,
+
+    /// The schema version of the envelope, `None` if the contents aren't an envelope.
+    pub schema_version: Option<u32>,
+
+    /// The decoded message, or why it couldn't be decoded.
+    pub decoded: crate::Result<T>,
+}
+
+/// Subscribes to typed messages published by a [`TopicPublisher`].
+#[derive(Clone, Debug)]
+pub struct TopicSubscriber<C> {
+    query: TopicMessageQuery,
+    codec: C,
+}
+
+impl<C: TopicMessageCodec> TopicSubscriber<C> {
+    /// Create a new `TopicSubscriber` that decodes the messages `query` receives with `codec`.
+    #[must_use]
+    pub fn new(query: TopicMessageQuery, codec: C) -> Self {
+        Self { query, codec }
+    }
+
+    /// Returns the query messages are received with.
+    #[must_use]
+    pub fn get_query(&self) -> &TopicMessageQuery {
+        &self.query
+    }
+
+    /// Returns the codec messages are decoded with.
+    #[must_use]
+    pub fn get_codec(&self) -> &C {
+        &self.codec
+    }
+
+    /// Decodes a message received from the topic.
+    ///
+    /// Use this to decode messages from any subscription, such as [`TopicMessageQuery::subscribe_with_checkpoints`].
+    pub fn decode(&self, message: TopicMessage) -> TypedTopicMessage<C::Message> {
+        let envelope = TopicMessageEnvelope::from_bytes(&message.contents);
+
+        let schema_version = envelope.as_ref().ok().map(|it| it.schema_version);
+        let decoded = envelope.and_then(|it| it.decode(&self.codec));
+
+        TypedTopicMessage { message, schema_version, decoded }
+    }
+
+    /// Subscribe to the topic, decoding each message as it arrives.
+    ///
+    /// A message that fails to decode is still delivered, with [`TypedTopicMessage::decoded`] set to the error,
+    /// only errors from the subscription itself are returned as `Err`.
+    pub fn subscribe<'a>(
+        &'a self,
+        client: &'a Client,
+    ) -> BoxStream<'a, crate::Result<TypedTopicMessage<C::Message>>>
+    where
+        C::Message: Send + 'a,
+    {
+        Box::pin(
+            self.query.subscribe(client).map(|message| message.map(|message| self.decode(message))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hedera_proto::services;
+    use time::OffsetDateTime;
+
+    use crate::{
+        Error,
+        ProtobufCodec,
+        TopicId,
+        TopicMessage,
+        TopicMessageQuery,
+        TopicPublisher,
+        TopicSubscriber,
+    };
+
+    fn received(contents: Vec<u8>) -> TopicMessage {
+        TopicMessage {
+            consensus_timestamp: OffsetDateTime::UNIX_EPOCH,
+            contents,
+            running_hash: Vec::new(),
+            running_hash_version: 3,
+            sequence_number: 1,
+            chunks: None,
+            transaction: None,
+            incomplete: None,
+        }
+    }
+
+    #[test]
+    fn publish_then_decode() {
+        let mut publisher = TopicPublisher::new(
+            TopicId::new(0, 0, 1001),
+            ProtobufCodec::<services::Duration>::new(),
+        );
+        publisher.schema_version(2);
+
+        let transaction = publisher.transaction(&services::Duration { seconds: 90 }).unwrap();
+
+        assert_eq!(transaction.get_topic_id(), Some(TopicId::new(0, 0, 1001)));
+
+        let subscriber = TopicSubscriber::new(
+            TopicMessageQuery::new(),
+            ProtobufCodec::<services::Duration>::new(),
+        );
+
+        let message =
+            subscriber.decode(received(transaction.get_message().unwrap_or_default().to_vec()));
+
+        assert_eq!(message.schema_version, Some(2));
+        assert_eq!(message.decoded.unwrap(), services::Duration { seconds: 90 });
+    }
+
+    #[test]
+    fn decode_not_an_envelope() {
+        let subscriber = TopicSubscriber::new(
+            TopicMessageQuery::new(),
+            ProtobufCodec::<services::Duration>::new(),
+        );
+
+        let message = subscriber.decode(received(Vec::from([0xff; 4])));
+
+        assert_eq!(message.schema_version, None);
+        assert_matches!(message.decoded, Err(Error::TopicMessageDecode(_)));
+    }
+}
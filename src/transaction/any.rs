@@ -1,9 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use tonic::transport::Channel;
 
 use super::chunked::ChunkInfo;
+use super::validation::ValidationError;
 use super::{
     TransactionData,
     TransactionExecuteChunked,
@@ -21,6 +23,7 @@ use crate::transaction::{
 use crate::{
     AccountId,
     BoxGrpcFuture,
+    Client,
     Error,
     Hbar,
     Transaction,
@@ -34,6 +37,8 @@ mod data {
         AccountCreateTransactionData as AccountCreate,
         AccountDeleteTransactionData as AccountDelete,
         AccountUpdateTransactionData as AccountUpdate,
+        LiveHashAddTransactionData as LiveHashAdd,
+        LiveHashDeleteTransactionData as LiveHashDelete,
     };
     pub(super) use crate::address_book::{
         NodeCreateTransactionData as NodeCreate,
@@ -150,6 +155,8 @@ pub enum AnyTransactionData {
     TokenAirdrop(data::TokenAirdrop),
     TokenClaimAirdrop(data::TokenClaimAirdrop),
     TokenCancelAirdrop(data::TokenCancelAirdrop),
+    LiveHashAdd(data::LiveHashAdd),
+    LiveHashDelete(data::LiveHashDelete),
 }
 
 impl ToTransactionDataProtobuf for AnyTransactionData {
@@ -300,6 +307,12 @@ impl ToTransactionDataProtobuf for AnyTransactionData {
             Self::TokenCancelAirdrop(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
+
+            Self::LiveHashAdd(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
+
+            Self::LiveHashDelete(transaction) => {
+                transaction.to_transaction_data_protobuf(chunk_info)
+            }
         }
     }
 }
@@ -356,6 +369,8 @@ impl TransactionData for AnyTransactionData {
             Self::TokenAirdrop(transaction) => transaction.default_max_transaction_fee(),
             Self::TokenClaimAirdrop(transaction) => transaction.default_max_transaction_fee(),
             Self::TokenCancelAirdrop(transaction) => transaction.default_max_transaction_fee(),
+            Self::LiveHashAdd(transaction) => transaction.default_max_transaction_fee(),
+            Self::LiveHashDelete(transaction) => transaction.default_max_transaction_fee(),
         }
     }
 
@@ -410,6 +425,64 @@ impl TransactionData for AnyTransactionData {
             Self::TokenAirdrop(it) => it.maybe_chunk_data(),
             Self::TokenClaimAirdrop(it) => it.maybe_chunk_data(),
             Self::TokenCancelAirdrop(it) => it.maybe_chunk_data(),
+            Self::LiveHashAdd(it) => it.maybe_chunk_data(),
+            Self::LiveHashDelete(it) => it.maybe_chunk_data(),
+        }
+    }
+
+    fn maybe_chunk_data_mut(&mut self) -> Option<&mut super::ChunkData> {
+        match self {
+            Self::AccountCreate(it) => it.maybe_chunk_data_mut(),
+            Self::AccountUpdate(it) => it.maybe_chunk_data_mut(),
+            Self::AccountDelete(it) => it.maybe_chunk_data_mut(),
+            Self::AccountAllowanceApprove(it) => it.maybe_chunk_data_mut(),
+            Self::AccountAllowanceDelete(it) => it.maybe_chunk_data_mut(),
+            Self::ContractCreate(it) => it.maybe_chunk_data_mut(),
+            Self::ContractUpdate(it) => it.maybe_chunk_data_mut(),
+            Self::ContractDelete(it) => it.maybe_chunk_data_mut(),
+            Self::ContractExecute(it) => it.maybe_chunk_data_mut(),
+            Self::Transfer(it) => it.maybe_chunk_data_mut(),
+            Self::TopicCreate(it) => it.maybe_chunk_data_mut(),
+            Self::TopicUpdate(it) => it.maybe_chunk_data_mut(),
+            Self::TopicDelete(it) => it.maybe_chunk_data_mut(),
+            Self::TopicMessageSubmit(it) => it.maybe_chunk_data_mut(),
+            Self::FileAppend(it) => it.maybe_chunk_data_mut(),
+            Self::FileCreate(it) => it.maybe_chunk_data_mut(),
+            Self::FileUpdate(it) => it.maybe_chunk_data_mut(),
+            Self::FileDelete(it) => it.maybe_chunk_data_mut(),
+            Self::Prng(it) => it.maybe_chunk_data_mut(),
+            Self::TokenAssociate(it) => it.maybe_chunk_data_mut(),
+            Self::TokenBurn(it) => it.maybe_chunk_data_mut(),
+            Self::TokenCreate(it) => it.maybe_chunk_data_mut(),
+            Self::TokenDelete(it) => it.maybe_chunk_data_mut(),
+            Self::TokenDissociate(it) => it.maybe_chunk_data_mut(),
+            Self::TokenFeeScheduleUpdate(it) => it.maybe_chunk_data_mut(),
+            Self::TokenFreeze(it) => it.maybe_chunk_data_mut(),
+            Self::TokenGrantKyc(it) => it.maybe_chunk_data_mut(),
+            Self::TokenMint(it) => it.maybe_chunk_data_mut(),
+            Self::TokenPause(it) => it.maybe_chunk_data_mut(),
+            Self::TokenRevokeKyc(it) => it.maybe_chunk_data_mut(),
+            Self::TokenUnfreeze(it) => it.maybe_chunk_data_mut(),
+            Self::TokenUnpause(it) => it.maybe_chunk_data_mut(),
+            Self::TokenUpdate(it) => it.maybe_chunk_data_mut(),
+            Self::TokenWipe(it) => it.maybe_chunk_data_mut(),
+            Self::SystemDelete(it) => it.maybe_chunk_data_mut(),
+            Self::SystemUndelete(it) => it.maybe_chunk_data_mut(),
+            Self::Freeze(it) => it.maybe_chunk_data_mut(),
+            Self::ScheduleCreate(it) => it.maybe_chunk_data_mut(),
+            Self::ScheduleSign(it) => it.maybe_chunk_data_mut(),
+            Self::ScheduleDelete(it) => it.maybe_chunk_data_mut(),
+            Self::Ethereum(it) => it.maybe_chunk_data_mut(),
+            Self::TokenUpdateNfts(it) => it.maybe_chunk_data_mut(),
+            Self::NodeCreate(it) => it.maybe_chunk_data_mut(),
+            Self::NodeUpdate(it) => it.maybe_chunk_data_mut(),
+            Self::NodeDelete(it) => it.maybe_chunk_data_mut(),
+            Self::TokenReject(it) => it.maybe_chunk_data_mut(),
+            Self::TokenAirdrop(it) => it.maybe_chunk_data_mut(),
+            Self::TokenClaimAirdrop(it) => it.maybe_chunk_data_mut(),
+            Self::TokenCancelAirdrop(it) => it.maybe_chunk_data_mut(),
+            Self::LiveHashAdd(it) => it.maybe_chunk_data_mut(),
+            Self::LiveHashDelete(it) => it.maybe_chunk_data_mut(),
         }
     }
 
@@ -464,6 +537,176 @@ impl TransactionData for AnyTransactionData {
             Self::TokenAirdrop(it) => it.wait_for_receipt(),
             Self::TokenClaimAirdrop(it) => it.wait_for_receipt(),
             Self::TokenCancelAirdrop(it) => it.wait_for_receipt(),
+            Self::LiveHashAdd(it) => it.wait_for_receipt(),
+            Self::LiveHashDelete(it) => it.wait_for_receipt(),
+        }
+    }
+
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        match self {
+            Self::AccountCreate(it) => it.validate_data(errors),
+            Self::AccountUpdate(it) => it.validate_data(errors),
+            Self::AccountDelete(it) => it.validate_data(errors),
+            Self::AccountAllowanceApprove(it) => it.validate_data(errors),
+            Self::AccountAllowanceDelete(it) => it.validate_data(errors),
+            Self::ContractCreate(it) => it.validate_data(errors),
+            Self::ContractUpdate(it) => it.validate_data(errors),
+            Self::ContractDelete(it) => it.validate_data(errors),
+            Self::ContractExecute(it) => it.validate_data(errors),
+            Self::Transfer(it) => it.validate_data(errors),
+            Self::TopicCreate(it) => it.validate_data(errors),
+            Self::TopicUpdate(it) => it.validate_data(errors),
+            Self::TopicDelete(it) => it.validate_data(errors),
+            Self::TopicMessageSubmit(it) => it.validate_data(errors),
+            Self::FileAppend(it) => it.validate_data(errors),
+            Self::FileCreate(it) => it.validate_data(errors),
+            Self::FileUpdate(it) => it.validate_data(errors),
+            Self::FileDelete(it) => it.validate_data(errors),
+            Self::Prng(it) => it.validate_data(errors),
+            Self::TokenAssociate(it) => it.validate_data(errors),
+            Self::TokenBurn(it) => it.validate_data(errors),
+            Self::TokenCreate(it) => it.validate_data(errors),
+            Self::TokenDelete(it) => it.validate_data(errors),
+            Self::TokenDissociate(it) => it.validate_data(errors),
+            Self::TokenFeeScheduleUpdate(it) => it.validate_data(errors),
+            Self::TokenFreeze(it) => it.validate_data(errors),
+            Self::TokenGrantKyc(it) => it.validate_data(errors),
+            Self::TokenMint(it) => it.validate_data(errors),
+            Self::TokenPause(it) => it.validate_data(errors),
+            Self::TokenRevokeKyc(it) => it.validate_data(errors),
+            Self::TokenUnfreeze(it) => it.validate_data(errors),
+            Self::TokenUnpause(it) => it.validate_data(errors),
+            Self::TokenUpdate(it) => it.validate_data(errors),
+            Self::TokenWipe(it) => it.validate_data(errors),
+            Self::SystemDelete(it) => it.validate_data(errors),
+            Self::SystemUndelete(it) => it.validate_data(errors),
+            Self::Freeze(it) => it.validate_data(errors),
+            Self::ScheduleCreate(it) => it.validate_data(errors),
+            Self::ScheduleSign(it) => it.validate_data(errors),
+            Self::ScheduleDelete(it) => it.validate_data(errors),
+            Self::Ethereum(it) => it.validate_data(errors),
+            Self::TokenUpdateNfts(it) => it.validate_data(errors),
+            Self::NodeCreate(it) => it.validate_data(errors),
+            Self::NodeUpdate(it) => it.validate_data(errors),
+            Self::NodeDelete(it) => it.validate_data(errors),
+            Self::TokenReject(it) => it.validate_data(errors),
+            Self::TokenAirdrop(it) => it.validate_data(errors),
+            Self::TokenClaimAirdrop(it) => it.validate_data(errors),
+            Self::TokenCancelAirdrop(it) => it.validate_data(errors),
+            Self::LiveHashAdd(it) => it.validate_data(errors),
+            Self::LiveHashDelete(it) => it.validate_data(errors),
+        }
+    }
+
+    fn prepare<'a>(&'a mut self, client: &'a Client) -> Option<BoxFuture<'a, crate::Result<()>>> {
+        match self {
+            Self::AccountCreate(it) => it.prepare(client),
+            Self::AccountUpdate(it) => it.prepare(client),
+            Self::AccountDelete(it) => it.prepare(client),
+            Self::AccountAllowanceApprove(it) => it.prepare(client),
+            Self::AccountAllowanceDelete(it) => it.prepare(client),
+            Self::ContractCreate(it) => it.prepare(client),
+            Self::ContractUpdate(it) => it.prepare(client),
+            Self::ContractDelete(it) => it.prepare(client),
+            Self::ContractExecute(it) => it.prepare(client),
+            Self::Transfer(it) => it.prepare(client),
+            Self::TopicCreate(it) => it.prepare(client),
+            Self::TopicUpdate(it) => it.prepare(client),
+            Self::TopicDelete(it) => it.prepare(client),
+            Self::TopicMessageSubmit(it) => it.prepare(client),
+            Self::FileAppend(it) => it.prepare(client),
+            Self::FileCreate(it) => it.prepare(client),
+            Self::FileUpdate(it) => it.prepare(client),
+            Self::FileDelete(it) => it.prepare(client),
+            Self::Prng(it) => it.prepare(client),
+            Self::TokenAssociate(it) => it.prepare(client),
+            Self::TokenBurn(it) => it.prepare(client),
+            Self::TokenCreate(it) => it.prepare(client),
+            Self::TokenDelete(it) => it.prepare(client),
+            Self::TokenDissociate(it) => it.prepare(client),
+            Self::TokenFeeScheduleUpdate(it) => it.prepare(client),
+            Self::TokenFreeze(it) => it.prepare(client),
+            Self::TokenGrantKyc(it) => it.prepare(client),
+            Self::TokenMint(it) => it.prepare(client),
+            Self::TokenPause(it) => it.prepare(client),
+            Self::TokenRevokeKyc(it) => it.prepare(client),
+            Self::TokenUnfreeze(it) => it.prepare(client),
+            Self::TokenUnpause(it) => it.prepare(client),
+            Self::TokenUpdate(it) => it.prepare(client),
+            Self::TokenWipe(it) => it.prepare(client),
+            Self::SystemDelete(it) => it.prepare(client),
+            Self::SystemUndelete(it) => it.prepare(client),
+            Self::Freeze(it) => it.prepare(client),
+            Self::ScheduleCreate(it) => it.prepare(client),
+            Self::ScheduleSign(it) => it.prepare(client),
+            Self::ScheduleDelete(it) => it.prepare(client),
+            Self::Ethereum(it) => it.prepare(client),
+            Self::TokenUpdateNfts(it) => it.prepare(client),
+            Self::NodeCreate(it) => it.prepare(client),
+            Self::NodeUpdate(it) => it.prepare(client),
+            Self::NodeDelete(it) => it.prepare(client),
+            Self::TokenReject(it) => it.prepare(client),
+            Self::TokenAirdrop(it) => it.prepare(client),
+            Self::TokenClaimAirdrop(it) => it.prepare(client),
+            Self::TokenCancelAirdrop(it) => it.prepare(client),
+            Self::LiveHashAdd(it) => it.prepare(client),
+            Self::LiveHashDelete(it) => it.prepare(client),
+        }
+    }
+
+    fn require_prepared(&self) -> crate::Result<()> {
+        match self {
+            Self::AccountCreate(it) => it.require_prepared(),
+            Self::AccountUpdate(it) => it.require_prepared(),
+            Self::AccountDelete(it) => it.require_prepared(),
+            Self::AccountAllowanceApprove(it) => it.require_prepared(),
+            Self::AccountAllowanceDelete(it) => it.require_prepared(),
+            Self::ContractCreate(it) => it.require_prepared(),
+            Self::ContractUpdate(it) => it.require_prepared(),
+            Self::ContractDelete(it) => it.require_prepared(),
+            Self::ContractExecute(it) => it.require_prepared(),
+            Self::Transfer(it) => it.require_prepared(),
+            Self::TopicCreate(it) => it.require_prepared(),
+            Self::TopicUpdate(it) => it.require_prepared(),
+            Self::TopicDelete(it) => it.require_prepared(),
+            Self::TopicMessageSubmit(it) => it.require_prepared(),
+            Self::FileAppend(it) => it.require_prepared(),
+            Self::FileCreate(it) => it.require_prepared(),
+            Self::FileUpdate(it) => it.require_prepared(),
+            Self::FileDelete(it) => it.require_prepared(),
+            Self::Prng(it) => it.require_prepared(),
+            Self::TokenAssociate(it) => it.require_prepared(),
+            Self::TokenBurn(it) => it.require_prepared(),
+            Self::TokenCreate(it) => it.require_prepared(),
+            Self::TokenDelete(it) => it.require_prepared(),
+            Self::TokenDissociate(it) => it.require_prepared(),
+            Self::TokenFeeScheduleUpdate(it) => it.require_prepared(),
+            Self::TokenFreeze(it) => it.require_prepared(),
+            Self::TokenGrantKyc(it) => it.require_prepared(),
+            Self::TokenMint(it) => it.require_prepared(),
+            Self::TokenPause(it) => it.require_prepared(),
+            Self::TokenRevokeKyc(it) => it.require_prepared(),
+            Self::TokenUnfreeze(it) => it.require_prepared(),
+            Self::TokenUnpause(it) => it.require_prepared(),
+            Self::TokenUpdate(it) => it.require_prepared(),
+            Self::TokenWipe(it) => it.require_prepared(),
+            Self::SystemDelete(it) => it.require_prepared(),
+            Self::SystemUndelete(it) => it.require_prepared(),
+            Self::Freeze(it) => it.require_prepared(),
+            Self::ScheduleCreate(it) => it.require_prepared(),
+            Self::ScheduleSign(it) => it.require_prepared(),
+            Self::ScheduleDelete(it) => it.require_prepared(),
+            Self::Ethereum(it) => it.require_prepared(),
+            Self::TokenUpdateNfts(it) => it.require_prepared(),
+            Self::NodeCreate(it) => it.require_prepared(),
+            Self::NodeUpdate(it) => it.require_prepared(),
+            Self::NodeDelete(it) => it.require_prepared(),
+            Self::TokenReject(it) => it.require_prepared(),
+            Self::TokenAirdrop(it) => it.require_prepared(),
+            Self::TokenClaimAirdrop(it) => it.require_prepared(),
+            Self::TokenCancelAirdrop(it) => it.require_prepared(),
+            Self::LiveHashAdd(it) => it.require_prepared(),
+            Self::LiveHashDelete(it) => it.require_prepared(),
         }
     }
 }
@@ -524,6 +767,8 @@ impl TransactionExecute for AnyTransactionData {
             Self::TokenAirdrop(transaction) => transaction.execute(channel, request),
             Self::TokenClaimAirdrop(transaction) => transaction.execute(channel, request),
             Self::TokenCancelAirdrop(transaction) => transaction.execute(channel, request),
+            Self::LiveHashAdd(transaction) => transaction.execute(channel, request),
+            Self::LiveHashDelete(transaction) => transaction.execute(channel, request),
         }
     }
 }
@@ -582,6 +827,8 @@ impl ValidateChecksums for AnyTransactionData {
             Self::TokenAirdrop(transaction) => transaction.validate_checksums(ledger_id),
             Self::TokenClaimAirdrop(transaction) => transaction.validate_checksums(ledger_id),
             Self::TokenCancelAirdrop(transaction) => transaction.validate_checksums(ledger_id),
+            Self::LiveHashAdd(transaction) => transaction.validate_checksums(ledger_id),
+            Self::LiveHashDelete(transaction) => transaction.validate_checksums(ledger_id),
         }
     }
 }
@@ -648,16 +895,8 @@ impl FromProtobuf<services::transaction_body::Data> for AnyTransactionData {
             Data::TokenAirdrop(pb) => data::TokenAirdrop::from_protobuf(pb)?.into(),
             Data::TokenClaimAirdrop(pb) => data::TokenClaimAirdrop::from_protobuf(pb)?.into(),
             Data::TokenCancelAirdrop(pb) => data::TokenCancelAirdrop::from_protobuf(pb)?.into(),
-            Data::CryptoAddLiveHash(_) => {
-                return Err(Error::from_protobuf(
-                    "unsupported transaction `AddLiveHashTransaction`",
-                ))
-            }
-            Data::CryptoDeleteLiveHash(_) => {
-                return Err(Error::from_protobuf(
-                    "unsupported transaction `DeleteLiveHashTransaction`",
-                ))
-            }
+            Data::CryptoAddLiveHash(pb) => data::LiveHashAdd::from_protobuf(pb)?.into(),
+            Data::CryptoDeleteLiveHash(pb) => data::LiveHashDelete::from_protobuf(pb)?.into(),
             Data::UncheckedSubmit(_) => {
                 return Err(Error::from_protobuf(
                     "unsupported transaction `UncheckedSubmitTransaction`",
@@ -838,6 +1077,12 @@ impl AnyTransactionData {
             ServicesTransactionDataList::TokenCancelAirdrop(v) => {
                 data::TokenCancelAirdrop::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            ServicesTransactionDataList::LiveHashAdd(v) => {
+                data::LiveHashAdd::from_protobuf(try_into_only_element(v)?)?.into()
+            }
+            ServicesTransactionDataList::LiveHashDelete(v) => {
+                data::LiveHashDelete::from_protobuf(try_into_only_element(v)?)?.into()
+            }
         };
 
         Ok(data)
@@ -955,6 +1200,8 @@ enum ServicesTransactionDataList {
     TokenAirdrop(Vec<services::TokenAirdropTransactionBody>),
     TokenClaimAirdrop(Vec<services::TokenClaimAirdropTransactionBody>),
     TokenCancelAirdrop(Vec<services::TokenCancelAirdropTransactionBody>),
+    LiveHashAdd(Vec<services::CryptoAddLiveHashTransactionBody>),
+    LiveHashDelete(Vec<services::CryptoDeleteLiveHashTransactionBody>),
 }
 
 impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransactionDataList {
@@ -1026,16 +1273,8 @@ impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransaction
             Data::TokenAirdrop(it) => Self::TokenAirdrop(make_vec(it, len)),
             Data::TokenClaimAirdrop(it) => Self::TokenClaimAirdrop(make_vec(it, len)),
             Data::TokenCancelAirdrop(it) => Self::TokenCancelAirdrop(make_vec(it, len)),
-            Data::CryptoAddLiveHash(_) => {
-                return Err(Error::from_protobuf(
-                    "unsupported transaction `AddLiveHashTransaction`",
-                ))
-            }
-            Data::CryptoDeleteLiveHash(_) => {
-                return Err(Error::from_protobuf(
-                    "unsupported transaction `DeleteLiveHashTransaction`",
-                ))
-            }
+            Data::CryptoAddLiveHash(it) => Self::LiveHashAdd(make_vec(it, len)),
+            Data::CryptoDeleteLiveHash(it) => Self::LiveHashDelete(make_vec(it, len)),
 
             Data::UncheckedSubmit(_) => {
                 return Err(Error::from_protobuf(
@@ -1106,6 +1345,8 @@ impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransaction
                 (Self::TokenAirdrop(v), Data::TokenAirdrop(element)) => v.push(element),
                 (Self::TokenClaimAirdrop(v), Data::TokenClaimAirdrop(element)) => v.push(element),
                 (Self::TokenCancelAirdrop(v), Data::TokenCancelAirdrop(element)) => v.push(element),
+                (Self::LiveHashAdd(v), Data::CryptoAddLiveHash(element)) => v.push(element),
+                (Self::LiveHashDelete(v), Data::CryptoDeleteLiveHash(element)) => v.push(element),
 
                 _ => return Err(Error::from_protobuf("mismatched transaction types")),
             }
@@ -1230,5 +1471,7 @@ impl_cast_any! {
     TokenReject,
     TokenAirdrop,
     TokenClaimAirdrop,
-    TokenCancelAirdrop
+    TokenCancelAirdrop,
+    LiveHashAdd,
+    LiveHashDelete
 }
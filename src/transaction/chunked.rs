@@ -1,4 +1,5 @@
 use std::cmp;
+use std::collections::BTreeSet;
 use std::num::NonZeroUsize;
 
 use hedera_proto::services;
@@ -19,6 +20,7 @@ use crate::{
     Transaction,
     TransactionHash,
     TransactionId,
+    TransactionReceipt,
     TransactionResponse,
 };
 
@@ -27,6 +29,7 @@ use crate::{
 pub struct ChunkData {
     pub(crate) max_chunks: usize,
     pub(crate) chunk_size: NonZeroUsize,
+    pub(crate) max_concurrent_chunks: NonZeroUsize,
     pub(crate) data: Vec<u8>,
 }
 
@@ -35,6 +38,7 @@ impl Default for ChunkData {
         Self {
             max_chunks: Self::DEFAULT_MAX_CHUNKS,
             chunk_size: Self::DEFAULT_CHUNK_SIZE,
+            max_concurrent_chunks: NonZeroUsize::MIN,
             data: Vec::new(),
         }
     }
@@ -72,6 +76,126 @@ impl ChunkData {
     }
 }
 
+/// The progress of executing a chunked transaction, see [`Transaction::execute_all_resumable`].
+///
+/// Executing a transaction with a `ChunkedExecution` records every chunk that lands,
+/// so that if a chunk fails, execution can be resumed from the first missing chunk rather than from the start.
+#[derive(Debug, Default)]
+pub struct ChunkedExecution {
+    /// The number of chunks in the transaction, `0` if execution hasn't started yet.
+    pub(super) total_chunks: usize,
+
+    /// The chunks submitted so far, `None` for a chunk that didn't land while the chunks after it did.
+    pub(super) chunks: Vec<Option<ExecutedChunk>>,
+
+    /// The indices of chunks that have to be submitted again,
+    /// either because they reached consensus with a failing status, or because they didn't land.
+    pub(super) failed: BTreeSet<usize>,
+}
+
+impl ChunkedExecution {
+    /// Create a new `ChunkedExecution` for a transaction that hasn't been executed yet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of chunks in the transaction, or `0` if execution hasn't started yet.
+    #[must_use]
+    pub fn total_chunks(&self) -> usize {
+        self.total_chunks
+    }
+
+    /// Returns the chunks that have been submitted so far, in order.
+    ///
+    /// This includes the [`failed_chunks`](Self::failed_chunks), until they're submitted again,
+    /// a failed chunk is `None` if it didn't land at all (while the chunks after it did).
+    #[must_use]
+    pub fn chunks(&self) -> &[Option<ExecutedChunk>] {
+        &self.chunks
+    }
+
+    /// Returns the indices of the chunks that reached consensus with a failing status,
+    /// or that failed to submit while the chunks after them landed,
+    /// which are submitted again when execution resumes.
+    pub fn failed_chunks(&self) -> impl Iterator<Item = usize> + '_ {
+        self.failed.iter().copied()
+    }
+
+    /// Returns the transaction ID of the first chunk, which links the remaining chunks to it.
+    #[must_use]
+    pub fn initial_transaction_id(&self) -> Option<TransactionId> {
+        self.chunks.first().and_then(Option::as_ref).map(|it| it.response.transaction_id)
+    }
+
+    /// Returns the index of the next chunk to be submitted, or `None` if every chunk has been submitted.
+    ///
+    /// Failed chunks are submitted again before any chunk that hasn't been submitted yet.
+    #[must_use]
+    pub fn next_chunk(&self) -> Option<usize> {
+        self.failed.first().copied().or_else(|| {
+            (self.total_chunks == 0 || self.chunks.len() < self.total_chunks)
+                .then_some(self.chunks.len())
+        })
+    }
+
+    /// Records that the chunk at `index` reached consensus with a failing status.
+    ///
+    /// Every other chunk references the first chunk's transaction ID,
+    /// so if the first chunk failed, every chunk has to be submitted again.
+    pub(super) fn chunk_failed(&mut self, index: usize) {
+        if index == 0 {
+            self.chunks.clear();
+            self.failed.clear();
+        } else {
+            self.failed.insert(index);
+        }
+    }
+
+    /// Records that the chunk at `index` landed, which may be ahead of chunks before it that are still in flight.
+    pub(super) fn chunk_landed(&mut self, index: usize, chunk: ExecutedChunk) {
+        if index >= self.chunks.len() {
+            self.chunks.resize_with(index + 1, || None);
+        }
+
+        self.chunks[index] = Some(chunk);
+        self.failed.remove(&index);
+    }
+
+    /// Records that every chunk that didn't land, while the chunks after it did, has to be submitted again.
+    pub(super) fn missing_chunks_failed(&mut self) {
+        let missing = self.chunks.iter().enumerate().filter(|(_, it)| it.is_none());
+
+        self.failed.extend(missing.map(|(index, _)| index));
+    }
+
+    /// Returns `true` if every chunk has been submitted.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.next_chunk().is_none()
+    }
+
+    /// Returns the responses of every chunk that landed so far, in order.
+    #[must_use]
+    pub fn into_responses(self) -> Vec<TransactionResponse> {
+        self.chunks.into_iter().flatten().map(|it| it.response).collect()
+    }
+}
+
+/// A chunk that has been submitted as part of a [`ChunkedExecution`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ExecutedChunk {
+    /// The response to submitting the chunk, which includes its transaction ID.
+    pub response: TransactionResponse,
+
+    /// The chunk's receipt, if it has been fetched.
+    ///
+    /// Receipts are only fetched for transactions that wait for each chunk's receipt,
+    /// or that submit chunks concurrently (see [`Transaction::max_concurrent_chunks`]).
+    pub receipt: Option<TransactionReceipt>,
+}
+
 pub struct ChunkInfo {
     /// Current chunk # out of [`max_chunks`](ChunkData.max_chunks) total.
     pub(crate) current: usize,
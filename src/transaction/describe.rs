@@ -0,0 +1,1244 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+use hedera_proto::services;
+use num_bigint::BigUint;
+use prost::Message;
+use rust_decimal::Decimal;
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+
+use super::{
+    ChunkInfo,
+    TransactionExecute,
+    DEFAULT_TRANSACTION_VALID_DURATION,
+};
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::schedule::scheduled_transaction_from_protobuf;
+use crate::signer::AnySigner;
+use crate::transfer_transaction::{
+    TokenTransfer,
+    Transfer,
+};
+use crate::{
+    AccountId,
+    ContractId,
+    Error,
+    EthereumData,
+    EvmAddress,
+    Hbar,
+    HbarUnit,
+    Key,
+    KeyList,
+    PublicKey,
+    TokenId,
+    TokenNftTransfer,
+    Transaction,
+    TransactionId,
+};
+
+/// A human-readable summary of what a transaction does, see [`Transaction::describe`].
+///
+/// The [`Display`](fmt::Display) implementation renders the description as line based text
+/// whose layout doesn't depend on the order the transaction was built in,
+/// so that it can be shown on approval screens and written to audit logs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TransactionDescription {
+    /// The name of the transaction's type, for instance `TransferTransaction`.
+    pub transaction_type: &'static str,
+
+    /// The ID of the transaction, if one has been set.
+    pub transaction_id: Option<TransactionId>,
+
+    /// The account that pays for the transaction, if known.
+    ///
+    /// For a scheduled transaction this is the schedule's payer, if it has one.
+    pub payer_account_id: Option<AccountId>,
+
+    /// How long the transaction is valid for, starting at its transaction ID's valid start.
+    ///
+    /// `None` for scheduled transactions, which are valid until their schedule expires.
+    pub transaction_valid_duration: Option<Duration>,
+
+    /// The maximum fee the payer is willing to pay, or `None` if it's picked when the transaction is executed.
+    pub max_transaction_fee: Option<Hbar>,
+
+    /// The memo attached to the transaction.
+    pub transaction_memo: String,
+
+    /// The nodes the transaction can be submitted to, empty if it hasn't been decided yet.
+    pub node_account_ids: Vec<AccountId>,
+
+    /// Every hbar transfer, in the order they appear in the transaction.
+    pub hbar_transfers: Vec<HbarTransferDescription>,
+
+    /// Hbar moved other than by a transfer list, like the initial balance of a new account.
+    pub hbar_movements: Vec<HbarMovementDescription>,
+
+    /// Every fungible token transfer, in the order they appear in the transaction.
+    pub token_transfers: Vec<TokenTransferDescription>,
+
+    /// Every NFT transfer, in the order they appear in the transaction.
+    pub nft_transfers: Vec<TokenNftTransfer>,
+
+    /// Every key the transaction sets.
+    pub key_changes: Vec<KeyChange>,
+
+    /// Every account the transaction sets for a role, like the treasury of a token.
+    pub account_changes: Vec<AccountChange>,
+
+    /// Every allowance the transaction approves or removes.
+    pub allowances: Vec<AllowanceDescription>,
+
+    /// The tokens minted, burned or wiped, if the transaction changes a token's supply.
+    pub token_supply_change: Option<TokenSupplyChange>,
+
+    /// What the wrapped ethereum transaction does, if this is an `EthereumTransaction`.
+    pub ethereum_transaction: Option<EthereumTransactionDescription>,
+
+    /// The transaction to be scheduled, if this is a `ScheduleCreateTransaction`.
+    pub scheduled_transaction: Option<Box<TransactionDescription>>,
+
+    /// The signatures the transaction has, or will have once it's serialized or executed.
+    pub signatures: Vec<SignatureDescription>,
+}
+
+impl TransactionDescription {
+    /// Returns the time the transaction stops being valid, if known.
+    #[must_use]
+    pub fn valid_until(&self) -> Option<OffsetDateTime> {
+        Some(self.transaction_id?.valid_start + self.transaction_valid_duration?)
+    }
+}
+
+/// An hbar transfer in a [`TransactionDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct HbarTransferDescription {
+    /// The account the hbar is transferred to or from.
+    pub account_id: AccountId,
+
+    /// The amount transferred, negative if the account sends hbar.
+    pub amount: Hbar,
+
+    /// If the transfer spends an allowance granted to the payer.
+    pub is_approved: bool,
+}
+
+/// Hbar moved other than by a transfer list, in a [`TransactionDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct HbarMovementDescription {
+    /// The name of the field that moves the hbar, as named by its setter (for instance, `initial_balance`).
+    pub field: &'static str,
+
+    /// Where the hbar comes from.
+    pub from: HbarMovementParty,
+
+    /// Where the hbar goes.
+    pub to: HbarMovementParty,
+
+    /// The amount moved, or `None` if it's the whole balance of `from`.
+    pub amount: Option<Hbar>,
+}
+
+/// Where hbar comes from or goes to, in a [`HbarMovementDescription`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HbarMovementParty {
+    /// The account that pays for the transaction.
+    Payer,
+
+    /// An existing account.
+    Account(AccountId),
+
+    /// An existing contract.
+    Contract(ContractId),
+
+    /// The account the transaction creates.
+    NewAccount,
+
+    /// The contract the transaction creates.
+    NewContract,
+}
+
+impl fmt::Display for HbarMovementParty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Payer => f.write_str("payer"),
+            Self::Account(account_id) => write!(f, "{account_id}"),
+            Self::Contract(contract_id) => write!(f, "contract {contract_id}"),
+            Self::NewAccount => f.write_str("new account"),
+            Self::NewContract => f.write_str("new contract"),
+        }
+    }
+}
+
+/// A fungible token transfer in a [`TransactionDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TokenTransferDescription {
+    /// The token being transferred.
+    pub token_id: TokenId,
+
+    /// The account the token is transferred to or from.
+    pub account_id: AccountId,
+
+    /// The amount transferred in the token's smallest denomination, negative if the account sends tokens.
+    pub amount: i64,
+
+    /// The number of decimals the transaction expects the token to have, if it specifies any.
+    pub decimals: Option<u32>,
+
+    /// If the transfer spends an allowance granted to the payer.
+    pub is_approved: bool,
+}
+
+impl TokenTransferDescription {
+    /// Returns [`amount`](Self::amount) with [`decimals`](Self::decimals) applied.
+    ///
+    /// Without decimals the amount is returned as is, followed by `(smallest unit)`.
+    #[must_use]
+    pub fn formatted_amount(&self) -> String {
+        match self.decimals.and_then(|decimals| Decimal::try_new(self.amount, decimals).ok()) {
+            Some(amount) => amount.to_string(),
+            None => format!("{} (smallest unit)", self.amount),
+        }
+    }
+}
+
+/// A key set by a transaction, in a [`TransactionDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct KeyChange {
+    /// The name of the field the key is set for, as named by its setter (for instance, `admin_key`).
+    pub field: &'static str,
+
+    /// The new key.
+    pub key: Key,
+}
+
+/// An account set by a transaction, in a [`TransactionDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AccountChange {
+    /// The name of the field the account is set for, as named by its setter (for instance, `treasury_account_id`).
+    pub field: &'static str,
+
+    /// The new account.
+    pub account_id: AccountId,
+}
+
+/// An allowance approved or removed by a transaction, in a [`TransactionDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AllowanceDescription {
+    /// The account whose hbar or tokens can be spent, `None` if it's the payer.
+    pub owner_account_id: Option<AccountId>,
+
+    /// The account allowed to spend them, `None` if the allowance is removed from every spender.
+    pub spender_account_id: Option<AccountId>,
+
+    /// What can be spent.
+    pub amount: AllowanceAmount,
+}
+
+/// What an allowance lets a spender spend, in an [`AllowanceDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AllowanceAmount {
+    /// Up to this much hbar, zero removes the allowance.
+    Hbar(Hbar),
+
+    /// Up to `amount` of a fungible token, in its smallest denomination, zero removes the allowance.
+    Token {
+        /// The token that can be spent.
+        token_id: TokenId,
+
+        /// The amount that can be spent.
+        amount: i64,
+    },
+
+    /// Specific NFTs of a token.
+    Nfts {
+        /// The token the NFTs belong to.
+        token_id: TokenId,
+
+        /// The serial numbers of the NFTs.
+        serials: Vec<i64>,
+
+        /// The spender with an allowance for every serial that grants this allowance, if any.
+        delegating_spender_account_id: Option<AccountId>,
+    },
+
+    /// Every NFT of a token the owner has, now or in the future.
+    AllNfts {
+        /// The token the NFTs belong to.
+        token_id: TokenId,
+
+        /// `false` if the allowance is revoked instead.
+        approved: bool,
+    },
+}
+
+/// Tokens minted, burned or wiped by a transaction, in a [`TransactionDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TokenSupplyChange {
+    /// New tokens given to the treasury.
+    Mint {
+        /// The token minted, if set.
+        token_id: Option<TokenId>,
+
+        /// The amount of a fungible token minted, in its smallest denomination.
+        amount: u64,
+
+        /// The metadata of each NFT minted.
+        nft_metadata: Vec<Vec<u8>>,
+    },
+
+    /// Tokens removed from the treasury.
+    Burn {
+        /// The token burned, if set.
+        token_id: Option<TokenId>,
+
+        /// The amount of a fungible token burned, in its smallest denomination.
+        amount: u64,
+
+        /// The serial numbers of the NFTs burned.
+        serials: Vec<i64>,
+    },
+
+    /// Tokens removed from an account other than the treasury.
+    Wipe {
+        /// The token wiped, if set.
+        token_id: Option<TokenId>,
+
+        /// The account the tokens are wiped from, if set.
+        account_id: Option<AccountId>,
+
+        /// The amount of a fungible token wiped, in its smallest denomination.
+        amount: u64,
+
+        /// The serial numbers of the NFTs wiped.
+        serials: Vec<i64>,
+    },
+}
+
+/// What the ethereum transaction wrapped by an `EthereumTransaction` does, in a [`TransactionDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EthereumTransactionDescription {
+    /// The address the ethereum transaction calls or sends value to, `None` if it creates a contract.
+    pub to: Option<EvmAddress>,
+
+    /// The value sent to `to` by the signer of the ethereum transaction, in weibars (10^-18 hbar).
+    pub value: BigUint,
+
+    /// The most hbar the payer pays for gas the signer can't pay for.
+    pub max_gas_allowance: Hbar,
+}
+
+/// A signature of a transaction, in a [`TransactionDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SignatureDescription {
+    /// The (possibly truncated) raw public key that the signature is for.
+    pub public_key_prefix: Vec<u8>,
+
+    /// The public key that the signature is for, if the prefix is a full key.
+    pub public_key: Option<PublicKey>,
+
+    /// `true` if the signature will only be added when the transaction is serialized or executed.
+    pub is_pending: bool,
+}
+
+impl SignatureDescription {
+    fn from_signature_pair(pb: &services::SignaturePair) -> Self {
+        use services::signature_pair::Signature;
+
+        let public_key = match &pb.signature {
+            Some(Signature::Ed25519(_)) => PublicKey::from_bytes_ed25519(&pb.pub_key_prefix).ok(),
+            Some(Signature::EcdsaSecp256k1(_)) => {
+                PublicKey::from_bytes_ecdsa(&pb.pub_key_prefix).ok()
+            }
+            _ => None,
+        };
+
+        Self { public_key_prefix: pb.pub_key_prefix.clone(), public_key, is_pending: false }
+    }
+}
+
+impl<D: TransactionExecute> Transaction<D> {
+    /// Describes what this transaction does, for review before signing it.
+    ///
+    /// This works on transactions in any state, including ones read with [`AnyTransaction::from_bytes`](crate::AnyTransaction::from_bytes).
+    /// Once a transaction is frozen, the description is decoded from the body bytes that are signed
+    /// (for the first node and chunk), rather than from the builder's fields.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if the transaction has malformed data,
+    ///   for instance a scheduled transaction that can't be parsed.
+    pub fn describe(&self) -> crate::Result<TransactionDescription> {
+        let node_account_ids = match self.sources() {
+            Some(sources) => sources.node_ids().to_vec(),
+            None => self.body.node_account_ids.clone().unwrap_or_default(),
+        };
+
+        let (body, max_transaction_fee) = match self.sources() {
+            Some(sources) => {
+                let body = services::TransactionBody::decode(
+                    &*sources.signed_transactions()[0].body_bytes,
+                )
+                .map_err(Error::from_protobuf)?;
+
+                let fee = Hbar::from_tinybars(body.transaction_fee as i64);
+
+                (body, Some(fee))
+            }
+
+            None if self.is_frozen() && self.body.transaction_id.is_some() => {
+                let body = self.chunk_bodies()?.swap_remove(0);
+                let fee = Hbar::from_tinybars(body.transaction_fee as i64);
+
+                (body, Some(fee))
+            }
+
+            None => {
+                (self.draft_body(node_account_ids.first().copied()), self.body.max_transaction_fee)
+            }
+        };
+
+        let transaction_id = Option::<TransactionId>::from_protobuf(body.transaction_id)?;
+
+        let transaction_valid_duration = body
+            .transaction_valid_duration
+            .map_or(DEFAULT_TRANSACTION_VALID_DURATION, Duration::from);
+
+        let data = body.data.ok_or_else(|| Error::from_protobuf("transaction has no data"))?;
+
+        let mut description = TransactionDescription {
+            transaction_type: transaction_type(&data),
+            transaction_id,
+            payer_account_id: transaction_id.map(|it| it.account_id),
+            transaction_valid_duration: Some(transaction_valid_duration),
+            max_transaction_fee,
+            transaction_memo: body.memo,
+            node_account_ids,
+            hbar_transfers: Vec::new(),
+            hbar_movements: Vec::new(),
+            token_transfers: Vec::new(),
+            nft_transfers: Vec::new(),
+            key_changes: Vec::new(),
+            account_changes: Vec::new(),
+            allowances: Vec::new(),
+            token_supply_change: None,
+            ethereum_transaction: None,
+            scheduled_transaction: None,
+            signatures: self.describe_signatures(),
+        };
+
+        description.add_data(data)?;
+
+        Ok(description)
+    }
+
+    /// The body of a transaction that isn't frozen yet, with the whole message of chunked transactions.
+    fn draft_body(&self, node_account_id: Option<AccountId>) -> services::TransactionBody {
+        // only used for chunk info, which a single chunk doesn't have.
+        let chunk_transaction_id = self
+            .body
+            .transaction_id
+            .unwrap_or_else(|| TransactionId::generate(AccountId::new(0, 0, 0)));
+
+        let data = self.unchunked_data().to_transaction_data_protobuf(&ChunkInfo {
+            current: 0,
+            total: 1,
+            initial_transaction_id: chunk_transaction_id,
+            current_transaction_id: chunk_transaction_id,
+            node_account_id,
+        });
+
+        services::TransactionBody {
+            transaction_id: self.body.transaction_id.to_protobuf(),
+            node_account_id: node_account_id.to_protobuf(),
+            transaction_valid_duration: self.body.transaction_valid_duration.to_protobuf(),
+            memo: self.body.transaction_memo.clone(),
+            data: Some(data),
+            ..services::TransactionBody::default()
+        }
+    }
+
+    fn describe_signatures(&self) -> Vec<SignatureDescription> {
+        let mut signatures: Vec<_> = self
+            .sources
+            .as_ref()
+            .and_then(|it| it.signed_transactions().first())
+            .and_then(|it| it.sig_map.as_ref())
+            .map(|it| it.sig_pair.iter().map(SignatureDescription::from_signature_pair).collect())
+            .unwrap_or_default();
+
+        let pending = self
+            .body
+            .operator
+            .iter()
+            .map(|it| it.signer.public_key())
+            .chain(self.signers.iter().map(AnySigner::public_key));
+
+        for public_key in pending {
+            let public_key_prefix = public_key.to_bytes_raw();
+
+            if signatures.iter().any(|it| public_key_prefix.starts_with(&it.public_key_prefix)) {
+                continue;
+            }
+
+            signatures.push(SignatureDescription {
+                public_key_prefix,
+                public_key: Some(public_key),
+                is_pending: true,
+            });
+        }
+
+        signatures
+    }
+}
+
+impl TransactionDescription {
+    fn add_data(&mut self, data: services::transaction_body::Data) -> crate::Result<()> {
+        use services::transaction_body::Data;
+
+        match data {
+            Data::CryptoTransfer(pb) => {
+                let transfers = pb.transfers.map(|it| it.account_amounts).unwrap_or_default();
+
+                for transfer in transfers {
+                    let transfer = Transfer::from_protobuf(transfer)?;
+
+                    self.hbar_transfers.push(HbarTransferDescription {
+                        account_id: transfer.account_id,
+                        amount: Hbar::from_tinybars(transfer.amount),
+                        is_approved: transfer.is_approval,
+                    });
+                }
+
+                self.add_token_transfers(pb.token_transfers)?;
+            }
+            Data::TokenAirdrop(pb) => self.add_token_transfers(pb.token_transfers)?,
+            Data::CryptoCreateAccount(pb) => {
+                self.add_key_change("key", pb.key)?;
+
+                self.add_hbar_movement(
+                    "initial_balance",
+                    HbarMovementParty::Payer,
+                    HbarMovementParty::NewAccount,
+                    pb.initial_balance as i64,
+                );
+            }
+            Data::CryptoDelete(pb) => {
+                let from = Option::<AccountId>::from_protobuf(pb.delete_account_id)?;
+
+                if let Some(to) = Option::<AccountId>::from_protobuf(pb.transfer_account_id)? {
+                    self.hbar_movements.push(HbarMovementDescription {
+                        field: "transfer_account_id",
+                        from: from.map_or(HbarMovementParty::Payer, HbarMovementParty::Account),
+                        to: HbarMovementParty::Account(to),
+                        amount: None,
+                    });
+                }
+            }
+            Data::ContractCall(pb) => {
+                if let Some(contract_id) = Option::<ContractId>::from_protobuf(pb.contract_id)? {
+                    self.add_hbar_movement(
+                        "payable_amount",
+                        HbarMovementParty::Payer,
+                        HbarMovementParty::Contract(contract_id),
+                        pb.amount,
+                    );
+                }
+            }
+            Data::ContractDeleteInstance(pb) => {
+                use services::contract_delete_transaction_body::Obtainers;
+
+                let to = match pb.obtainers {
+                    Some(Obtainers::TransferAccountId(it)) => {
+                        Some(HbarMovementParty::Account(AccountId::from_protobuf(it)?))
+                    }
+                    Some(Obtainers::TransferContractId(it)) => {
+                        Some(HbarMovementParty::Contract(ContractId::from_protobuf(it)?))
+                    }
+                    None => None,
+                };
+
+                let from = Option::<ContractId>::from_protobuf(pb.contract_id)?;
+
+                if let (Some(from), Some(to)) = (from, to) {
+                    self.hbar_movements.push(HbarMovementDescription {
+                        field: "transfer_account_id",
+                        from: HbarMovementParty::Contract(from),
+                        to,
+                        amount: None,
+                    });
+                }
+            }
+            Data::CryptoUpdateAccount(pb) => self.add_key_change("key", pb.key)?,
+            Data::ContractCreateInstance(pb) => {
+                self.add_key_change("admin_key", pb.admin_key)?;
+
+                self.add_hbar_movement(
+                    "initial_balance",
+                    HbarMovementParty::Payer,
+                    HbarMovementParty::NewContract,
+                    pb.initial_balance,
+                );
+            }
+            Data::ContractUpdateInstance(pb) => self.add_key_change("admin_key", pb.admin_key)?,
+            Data::FileCreate(pb) => self.add_key_list_change("keys", pb.keys)?,
+            Data::FileUpdate(pb) => self.add_key_list_change("keys", pb.keys)?,
+            Data::NodeCreate(pb) => self.add_key_change("admin_key", pb.admin_key)?,
+            Data::NodeUpdate(pb) => self.add_key_change("admin_key", pb.admin_key)?,
+            Data::ConsensusCreateTopic(pb) => {
+                self.add_key_change("admin_key", pb.admin_key)?;
+                self.add_key_change("submit_key", pb.submit_key)?;
+                self.add_key_change("fee_schedule_key", pb.fee_schedule_key)?;
+
+                for key in pb.fee_exempt_key_list {
+                    self.add_key_change("fee_exempt_keys", Some(key))?;
+                }
+            }
+            Data::ConsensusUpdateTopic(pb) => {
+                self.add_key_change("admin_key", pb.admin_key)?;
+                self.add_key_change("submit_key", pb.submit_key)?;
+                self.add_key_change("fee_schedule_key", pb.fee_schedule_key)?;
+
+                for key in pb.fee_exempt_key_list.map(|it| it.keys).unwrap_or_default() {
+                    self.add_key_change("fee_exempt_keys", Some(key))?;
+                }
+            }
+            Data::TokenCreation(pb) => {
+                self.add_key_change("admin_key", pb.admin_key)?;
+                self.add_key_change("kyc_key", pb.kyc_key)?;
+                self.add_key_change("freeze_key", pb.freeze_key)?;
+                self.add_key_change("wipe_key", pb.wipe_key)?;
+                self.add_key_change("supply_key", pb.supply_key)?;
+                self.add_key_change("fee_schedule_key", pb.fee_schedule_key)?;
+                self.add_key_change("pause_key", pb.pause_key)?;
+                self.add_key_change("metadata_key", pb.metadata_key)?;
+
+                self.add_account_change("treasury_account_id", pb.treasury)?;
+                self.add_account_change("auto_renew_account_id", pb.auto_renew_account)?;
+            }
+            Data::TokenUpdate(pb) => {
+                self.add_key_change("admin_key", pb.admin_key)?;
+                self.add_key_change("kyc_key", pb.kyc_key)?;
+                self.add_key_change("freeze_key", pb.freeze_key)?;
+                self.add_key_change("wipe_key", pb.wipe_key)?;
+                self.add_key_change("supply_key", pb.supply_key)?;
+                self.add_key_change("fee_schedule_key", pb.fee_schedule_key)?;
+                self.add_key_change("pause_key", pb.pause_key)?;
+                self.add_key_change("metadata_key", pb.metadata_key)?;
+
+                self.add_account_change("treasury_account_id", pb.treasury)?;
+                self.add_account_change("auto_renew_account_id", pb.auto_renew_account)?;
+            }
+            Data::CryptoApproveAllowance(pb) => {
+                for allowance in pb.crypto_allowances {
+                    self.allowances.push(AllowanceDescription {
+                        owner_account_id: Option::from_protobuf(allowance.owner)?,
+                        spender_account_id: Option::from_protobuf(allowance.spender)?,
+                        amount: AllowanceAmount::Hbar(Hbar::from_tinybars(allowance.amount)),
+                    });
+                }
+
+                for allowance in pb.token_allowances {
+                    self.allowances.push(AllowanceDescription {
+                        owner_account_id: Option::from_protobuf(allowance.owner)?,
+                        spender_account_id: Option::from_protobuf(allowance.spender)?,
+                        amount: AllowanceAmount::Token {
+                            token_id: TokenId::from_protobuf(pb_getf!(allowance, token_id)?)?,
+                            amount: allowance.amount,
+                        },
+                    });
+                }
+
+                for allowance in pb.nft_allowances {
+                    let owner_account_id = Option::from_protobuf(allowance.owner)?;
+                    let spender_account_id = Option::from_protobuf(allowance.spender)?;
+                    let token_id = TokenId::from_protobuf(pb_getf!(allowance, token_id)?)?;
+
+                    if let Some(approved) = allowance.approved_for_all {
+                        self.allowances.push(AllowanceDescription {
+                            owner_account_id,
+                            spender_account_id,
+                            amount: AllowanceAmount::AllNfts { token_id, approved },
+                        });
+                    }
+
+                    if !allowance.serial_numbers.is_empty() {
+                        self.allowances.push(AllowanceDescription {
+                            owner_account_id,
+                            spender_account_id,
+                            amount: AllowanceAmount::Nfts {
+                                token_id,
+                                serials: allowance.serial_numbers,
+                                delegating_spender_account_id: Option::from_protobuf(
+                                    allowance.delegating_spender,
+                                )?,
+                            },
+                        });
+                    }
+                }
+            }
+            Data::CryptoDeleteAllowance(pb) => {
+                for allowance in pb.nft_allowances {
+                    self.allowances.push(AllowanceDescription {
+                        owner_account_id: Option::from_protobuf(allowance.owner)?,
+                        spender_account_id: None,
+                        amount: AllowanceAmount::Nfts {
+                            token_id: TokenId::from_protobuf(pb_getf!(allowance, token_id)?)?,
+                            serials: allowance.serial_numbers,
+                            delegating_spender_account_id: None,
+                        },
+                    });
+                }
+            }
+            Data::TokenMint(pb) => {
+                self.token_supply_change = Some(TokenSupplyChange::Mint {
+                    token_id: Option::from_protobuf(pb.token)?,
+                    amount: pb.amount,
+                    nft_metadata: pb.metadata,
+                });
+            }
+            Data::TokenBurn(pb) => {
+                self.token_supply_change = Some(TokenSupplyChange::Burn {
+                    token_id: Option::from_protobuf(pb.token)?,
+                    amount: pb.amount,
+                    serials: pb.serial_numbers,
+                });
+            }
+            Data::TokenWipe(pb) => {
+                self.token_supply_change = Some(TokenSupplyChange::Wipe {
+                    token_id: Option::from_protobuf(pb.token)?,
+                    account_id: Option::from_protobuf(pb.account)?,
+                    amount: pb.amount,
+                    serials: pb.serial_numbers,
+                });
+            }
+            Data::EthereumTransaction(pb) => {
+                // a draft may not have its data yet.
+                if !pb.ethereum_data.is_empty() {
+                    let data = EthereumData::from_bytes(&pb.ethereum_data)
+                        .map_err(Error::from_protobuf)?;
+
+                    let to = match data.to() {
+                        [] => None,
+                        to => Some(*<&EvmAddress>::try_from(to)?),
+                    };
+
+                    self.ethereum_transaction = Some(EthereumTransactionDescription {
+                        to,
+                        value: BigUint::from_bytes_be(data.value()),
+                        max_gas_allowance: Hbar::from_tinybars(pb.max_gas_allowance),
+                    });
+                }
+            }
+            // these don't move hbar or tokens, or set keys or accounts.
+            Data::FileAppend(_)
+            | Data::FileDelete(_)
+            | Data::UtilPrng(_)
+            | Data::SystemDelete(_)
+            | Data::SystemUndelete(_)
+            | Data::Freeze(_)
+            | Data::ConsensusDeleteTopic(_)
+            | Data::ConsensusSubmitMessage(_)
+            | Data::TokenFreeze(_)
+            | Data::TokenUnfreeze(_)
+            | Data::TokenGrantKyc(_)
+            | Data::TokenRevokeKyc(_)
+            | Data::TokenDeletion(_)
+            | Data::TokenAssociate(_)
+            | Data::TokenDissociate(_)
+            | Data::TokenFeeScheduleUpdate(_)
+            | Data::TokenPause(_)
+            | Data::TokenUnpause(_)
+            | Data::TokenReject(_)
+            | Data::TokenUpdateNfts(_)
+            | Data::TokenClaimAirdrop(_)
+            | Data::TokenCancelAirdrop(_)
+            | Data::ScheduleDelete(_)
+            | Data::ScheduleSign(_)
+            | Data::NodeDelete(_)
+            | Data::CryptoAddLiveHash(_)
+            | Data::CryptoDeleteLiveHash(_)
+            | Data::UncheckedSubmit(_)
+            | Data::NodeStakeUpdate(_)
+            | Data::AtomicBatch(_) => {}
+        }
+
+        Ok(())
+    }
+
+    fn add_token_transfers(&mut self, pb: Vec<services::TokenTransferList>) -> crate::Result<()> {
+        for transfers in pb {
+            let transfers = TokenTransfer::from_protobuf(transfers)?;
+
+            self.token_transfers.extend(transfers.transfers.into_iter().map(|it| {
+                TokenTransferDescription {
+                    token_id: transfers.token_id,
+                    account_id: it.account_id,
+                    amount: it.amount,
+                    decimals: transfers.expected_decimals,
+                    is_approved: it.is_approval,
+                }
+            }));
+
+            self.nft_transfers.extend(transfers.nft_transfers);
+        }
+
+        Ok(())
+    }
+
+    fn add_hbar_movement(
+        &mut self,
+        field: &'static str,
+        from: HbarMovementParty,
+        to: HbarMovementParty,
+        tinybars: i64,
+    ) {
+        if tinybars != 0 {
+            self.hbar_movements.push(HbarMovementDescription {
+                field,
+                from,
+                to,
+                amount: Some(Hbar::from_tinybars(tinybars)),
+            });
+        }
+    }
+
+    fn add_key_change(
+        &mut self,
+        field: &'static str,
+        key: Option<services::Key>,
+    ) -> crate::Result<()> {
+        if let Some(key) = key {
+            self.key_changes.push(KeyChange { field, key: Key::from_protobuf(key)? });
+        }
+
+        Ok(())
+    }
+
+    fn add_account_change(
+        &mut self,
+        field: &'static str,
+        account_id: Option<services::AccountId>,
+    ) -> crate::Result<()> {
+        if let Some(account_id) = Option::<AccountId>::from_protobuf(account_id)? {
+            self.account_changes.push(AccountChange { field, account_id });
+        }
+
+        Ok(())
+    }
+
+    fn add_key_list_change(
+        &mut self,
+        field: &'static str,
+        keys: Option<services::KeyList>,
+    ) -> crate::Result<()> {
+        if let Some(keys) = keys {
+            self.key_changes
+                .push(KeyChange { field, key: Key::KeyList(KeyList::from_protobuf(keys)?) });
+        }
+
+        Ok(())
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
+        let item = indent + 2;
+
+        writeln!(f, "{:indent$}{}", "", self.transaction_type)?;
+
+        if let Some(transaction_id) = self.transaction_id {
+            writeln!(f, "{:item$}transaction id: {transaction_id}", "")?;
+        }
+
+        if let Some(payer_account_id) = self.payer_account_id {
+            writeln!(f, "{:item$}payer: {payer_account_id}", "")?;
+        }
+
+        if let Some(transaction_id) = self.transaction_id {
+            writeln!(f, "{:item$}valid from: {}", "", Timestamp(transaction_id.valid_start))?;
+        }
+
+        if let Some(valid_until) = self.valid_until() {
+            writeln!(f, "{:item$}valid until: {}", "", Timestamp(valid_until))?;
+        }
+
+        match self.max_transaction_fee {
+            Some(fee) => writeln!(f, "{:item$}max transaction fee: {}", "", HbarAmount(fee))?,
+            None => writeln!(f, "{:item$}max transaction fee: unset", "")?,
+        }
+
+        writeln!(f, "{:item$}memo: {:?}", "", self.transaction_memo)?;
+
+        if !self.node_account_ids.is_empty() {
+            write!(f, "{:item$}nodes:", "")?;
+
+            for node_account_id in &self.node_account_ids {
+                write!(f, " {node_account_id}")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        let entry = item + 2;
+
+        if !self.hbar_transfers.is_empty() {
+            writeln!(f, "{:item$}hbar transfers:", "")?;
+
+            for transfer in &self.hbar_transfers {
+                write!(f, "{:entry$}{}: {}", "", transfer.account_id, HbarAmount(transfer.amount))?;
+                writeln!(f, "{}", if transfer.is_approved { " (approved)" } else { "" })?;
+            }
+        }
+
+        if !self.hbar_movements.is_empty() {
+            writeln!(f, "{:item$}hbar movements:", "")?;
+
+            for movement in &self.hbar_movements {
+                write!(f, "{:entry$}{}: ", "", movement.field)?;
+
+                match movement.amount {
+                    Some(amount) => write!(f, "{}", HbarAmount(amount))?,
+                    None => write!(f, "entire balance")?,
+                }
+
+                writeln!(f, " from {} to {}", movement.from, movement.to)?;
+            }
+        }
+
+        if !self.token_transfers.is_empty() {
+            writeln!(f, "{:item$}token transfers:", "")?;
+
+            for transfer in &self.token_transfers {
+                write!(
+                    f,
+                    "{:entry$}{} {}: {}",
+                    "",
+                    transfer.token_id,
+                    transfer.account_id,
+                    transfer.formatted_amount()
+                )?;
+                writeln!(f, "{}", if transfer.is_approved { " (approved)" } else { "" })?;
+            }
+        }
+
+        if !self.nft_transfers.is_empty() {
+            writeln!(f, "{:item$}nft transfers:", "")?;
+
+            for transfer in &self.nft_transfers {
+                write!(
+                    f,
+                    "{:entry$}{}/{}: {} -> {}",
+                    "", transfer.token_id, transfer.serial, transfer.sender, transfer.receiver
+                )?;
+                writeln!(f, "{}", if transfer.is_approved { " (approved)" } else { "" })?;
+            }
+        }
+
+        if !self.key_changes.is_empty() {
+            writeln!(f, "{:item$}key changes:", "")?;
+
+            for change in &self.key_changes {
+                writeln!(f, "{:entry$}{}: {}", "", change.field, KeyDisplay(&change.key))?;
+            }
+        }
+
+        if !self.account_changes.is_empty() {
+            writeln!(f, "{:item$}account changes:", "")?;
+
+            for change in &self.account_changes {
+                writeln!(f, "{:entry$}{}: {}", "", change.field, change.account_id)?;
+            }
+        }
+
+        if !self.allowances.is_empty() {
+            writeln!(f, "{:item$}allowances:", "")?;
+
+            for allowance in &self.allowances {
+                let owner = allowance
+                    .owner_account_id
+                    .map_or(HbarMovementParty::Payer, HbarMovementParty::Account);
+
+                match allowance.spender_account_id {
+                    Some(spender) => write!(f, "{:entry$}{owner} -> {spender}: ", "")?,
+                    None => write!(f, "{:entry$}{owner} -> every spender: ", "")?,
+                }
+
+                match &allowance.amount {
+                    AllowanceAmount::Hbar(amount) => writeln!(f, "{}", HbarAmount(*amount))?,
+                    AllowanceAmount::Token { token_id, amount } => {
+                        writeln!(f, "{token_id} {amount} (smallest unit)")?;
+                    }
+                    AllowanceAmount::Nfts { token_id, serials, delegating_spender_account_id } => {
+                        write!(f, "{token_id} serials {}", Serials(serials))?;
+
+                        match delegating_spender_account_id {
+                            Some(delegating_spender) => {
+                                writeln!(f, " (delegated by {delegating_spender})")?;
+                            }
+                            None => writeln!(f)?,
+                        }
+                    }
+                    AllowanceAmount::AllNfts { token_id, approved: true } => {
+                        writeln!(f, "{token_id} all serials")?;
+                    }
+                    AllowanceAmount::AllNfts { token_id, approved: false } => {
+                        writeln!(f, "{token_id} all serials revoked")?;
+                    }
+                }
+            }
+        }
+
+        if let Some(change) = &self.token_supply_change {
+            writeln!(f, "{:item$}token supply change:", "")?;
+
+            let token = |token_id: &Option<TokenId>| {
+                token_id.map_or_else(|| "unset token".to_owned(), |it| it.to_string())
+            };
+
+            let account = |account_id: &Option<AccountId>| {
+                account_id.map_or_else(|| "unset account".to_owned(), |it| it.to_string())
+            };
+
+            match change {
+                TokenSupplyChange::Mint { token_id, nft_metadata, .. }
+                    if !nft_metadata.is_empty() =>
+                {
+                    writeln!(
+                        f,
+                        "{:entry$}mint {}: {} nfts",
+                        "",
+                        token(token_id),
+                        nft_metadata.len()
+                    )?;
+                }
+                TokenSupplyChange::Mint { token_id, amount, .. } => {
+                    writeln!(f, "{:entry$}mint {}: {amount} (smallest unit)", "", token(token_id))?;
+                }
+                TokenSupplyChange::Burn { token_id, serials, .. } if !serials.is_empty() => {
+                    writeln!(
+                        f,
+                        "{:entry$}burn {}: serials {}",
+                        "",
+                        token(token_id),
+                        Serials(serials)
+                    )?;
+                }
+                TokenSupplyChange::Burn { token_id, amount, .. } => {
+                    writeln!(f, "{:entry$}burn {}: {amount} (smallest unit)", "", token(token_id))?;
+                }
+                TokenSupplyChange::Wipe { token_id, account_id, serials, .. }
+                    if !serials.is_empty() =>
+                {
+                    writeln!(
+                        f,
+                        "{:entry$}wipe {} from {}: serials {}",
+                        "",
+                        token(token_id),
+                        account(account_id),
+                        Serials(serials)
+                    )?;
+                }
+                TokenSupplyChange::Wipe { token_id, account_id, amount, .. } => {
+                    writeln!(
+                        f,
+                        "{:entry$}wipe {} from {}: {amount} (smallest unit)",
+                        "",
+                        token(token_id),
+                        account(account_id)
+                    )?;
+                }
+            }
+        }
+
+        if let Some(ethereum) = &self.ethereum_transaction {
+            writeln!(f, "{:item$}ethereum transaction:", "")?;
+
+            match ethereum.to {
+                Some(to) => writeln!(f, "{:entry$}to: {to}", "")?,
+                None => writeln!(f, "{:entry$}to: new contract", "")?,
+            }
+
+            writeln!(f, "{:entry$}value: {} weibar", "", ethereum.value)?;
+            writeln!(
+                f,
+                "{:entry$}max gas allowance: {}",
+                "",
+                HbarAmount(ethereum.max_gas_allowance)
+            )?;
+        }
+
+        if !self.signatures.is_empty() {
+            writeln!(f, "{:item$}signatures:", "")?;
+
+            for signature in &self.signatures {
+                match &signature.public_key {
+                    Some(public_key) => write!(f, "{:entry$}{public_key}", "")?,
+                    None => write!(
+                        f,
+                        "{:entry$}key prefix {}",
+                        "",
+                        hex::encode(&signature.public_key_prefix)
+                    )?,
+                }
+
+                writeln!(f, "{}", if signature.is_pending { " (pending)" } else { "" })?;
+            }
+        }
+
+        if let Some(scheduled) = &self.scheduled_transaction {
+            writeln!(f, "{:item$}scheduled transaction:", "")?;
+            scheduled.fmt_indented(f, entry)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for TransactionDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+/// Formats a time as `seconds.nanoseconds` since the unix epoch, like the network does.
+struct Timestamp(OffsetDateTime);
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{:09}", self.0.unix_timestamp(), self.0.nanosecond())
+    }
+}
+
+/// Formats an amount of hbar in `ℏ`, regardless of how small it is.
+struct HbarAmount(Hbar);
+
+impl fmt::Display for HbarAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ℏ", self.0.to(HbarUnit::Hbar).normalize())
+    }
+}
+
+/// Formats serial numbers as a comma separated list.
+struct Serials<'a>(&'a [i64]);
+
+impl fmt::Display for Serials<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, serial) in self.0.iter().enumerate() {
+            if index != 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{serial}")?;
+        }
+
+        Ok(())
+    }
+}
+
+struct KeyDisplay<'a>(&'a Key);
+
+impl fmt::Display for KeyDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Key::Single(public_key) => write!(f, "{public_key}"),
+            Key::ContractId(contract_id) => write!(f, "contract {contract_id}"),
+            Key::DelegateContractId(contract_id) => {
+                write!(f, "delegatable contract {contract_id}")
+            }
+            Key::KeyList(list) => {
+                match list.threshold {
+                    Some(threshold) => write!(f, "{threshold} of [")?,
+                    None => write!(f, "all of [")?,
+                }
+
+                for (index, key) in list.keys.iter().enumerate() {
+                    if index != 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "{}", KeyDisplay(key))?;
+                }
+
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+fn transaction_type(data: &services::transaction_body::Data) -> &'static str {
+    use services::transaction_body::Data;
+
+    match data {
+        Data::ContractCall(_) => "ContractExecuteTransaction",
+        Data::ContractCreateInstance(_) => "ContractCreateTransaction",
+        Data::ContractUpdateInstance(_) => "ContractUpdateTransaction",
+        Data::ContractDeleteInstance(_) => "ContractDeleteTransaction",
+        Data::EthereumTransaction(_) => "EthereumTransaction",
+        Data::CryptoApproveAllowance(_) => "AccountAllowanceApproveTransaction",
+        Data::CryptoDeleteAllowance(_) => "AccountAllowanceDeleteTransaction",
+        Data::CryptoCreateAccount(_) => "AccountCreateTransaction",
+        Data::CryptoDelete(_) => "AccountDeleteTransaction",
+        Data::CryptoTransfer(_) => "TransferTransaction",
+        Data::CryptoUpdateAccount(_) => "AccountUpdateTransaction",
+        Data::FileAppend(_) => "FileAppendTransaction",
+        Data::FileCreate(_) => "FileCreateTransaction",
+        Data::FileDelete(_) => "FileDeleteTransaction",
+        Data::FileUpdate(_) => "FileUpdateTransaction",
+        Data::UtilPrng(_) => "PrngTransaction",
+        Data::SystemDelete(_) => "SystemDeleteTransaction",
+        Data::SystemUndelete(_) => "SystemUndeleteTransaction",
+        Data::Freeze(_) => "FreezeTransaction",
+        Data::ConsensusCreateTopic(_) => "TopicCreateTransaction",
+        Data::ConsensusUpdateTopic(_) => "TopicUpdateTransaction",
+        Data::ConsensusDeleteTopic(_) => "TopicDeleteTransaction",
+        Data::ConsensusSubmitMessage(_) => "TopicMessageSubmitTransaction",
+        Data::TokenCreation(_) => "TokenCreateTransaction",
+        Data::TokenFreeze(_) => "TokenFreezeTransaction",
+        Data::TokenUnfreeze(_) => "TokenUnfreezeTransaction",
+        Data::TokenGrantKyc(_) => "TokenGrantKycTransaction",
+        Data::TokenRevokeKyc(_) => "TokenRevokeKycTransaction",
+        Data::TokenDeletion(_) => "TokenDeleteTransaction",
+        Data::TokenUpdate(_) => "TokenUpdateTransaction",
+        Data::TokenMint(_) => "TokenMintTransaction",
+        Data::TokenBurn(_) => "TokenBurnTransaction",
+        Data::TokenWipe(_) => "TokenWipeTransaction",
+        Data::TokenAssociate(_) => "TokenAssociateTransaction",
+        Data::TokenDissociate(_) => "TokenDissociateTransaction",
+        Data::TokenFeeScheduleUpdate(_) => "TokenFeeScheduleUpdateTransaction",
+        Data::TokenPause(_) => "TokenPauseTransaction",
+        Data::TokenUnpause(_) => "TokenUnpauseTransaction",
+        Data::TokenReject(_) => "TokenRejectTransaction",
+        Data::TokenUpdateNfts(_) => "TokenUpdateNftsTransaction",
+        Data::TokenAirdrop(_) => "TokenAirdropTransaction",
+        Data::TokenClaimAirdrop(_) => "TokenClaimAirdropTransaction",
+        Data::TokenCancelAirdrop(_) => "TokenCancelAirdropTransaction",
+        Data::ScheduleCreate(_) => "ScheduleCreateTransaction",
+        Data::ScheduleDelete(_) => "ScheduleDeleteTransaction",
+        Data::ScheduleSign(_) => "ScheduleSignTransaction",
+        Data::NodeCreate(_) => "NodeCreateTransaction",
+        Data::NodeUpdate(_) => "NodeUpdateTransaction",
+        Data::NodeDelete(_) => "NodeDeleteTransaction",
+        Data::CryptoAddLiveHash(_) => "LiveHashAddTransaction",
+        Data::CryptoDeleteLiveHash(_) => "LiveHashDeleteTransaction",
+        Data::UncheckedSubmit(_) => "UncheckedSubmitTransaction",
+        Data::NodeStakeUpdate(_) => "NodeStakeUpdateTransaction",
+        Data::AtomicBatch(_) => "AtomicBatchTransaction",
+    }
+}
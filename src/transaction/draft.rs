@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::num::NonZeroUsize;
+
+use hedera_proto::{
+    sdk,
+    services,
+};
+use prost::Message;
+
+use super::{
+    AnyTransaction,
+    ChunkInfo,
+    TransactionData,
+    TransactionExecute,
+};
+use crate::protobuf::FromProtobuf;
+use crate::{
+    AccountId,
+    Error,
+    ToProtobuf,
+    Transaction,
+    TransactionId,
+};
+
+impl<D: TransactionExecute> Transaction<D> {
+    /// Returns a copy of this transaction's data with the entire message in its first (and only) chunk.
+    pub(super) fn unchunked_data(&self) -> D {
+        let mut data = self.body.data.clone();
+
+        if let Some(chunk_data) = data.maybe_chunk_data_mut() {
+            chunk_data.chunk_size =
+                NonZeroUsize::new(chunk_data.data.len()).unwrap_or(NonZeroUsize::MIN);
+        }
+
+        data
+    }
+
+    /// Encodes the builder state of an unfrozen transaction, see [`AnyTransaction::from_draft`].
+    ///
+    /// Signers can't be encoded, so they're dropped.
+    #[allow(deprecated)]
+    pub(super) fn to_draft_bytes(&self) -> Vec<u8> {
+        let data = self.unchunked_data();
+
+        let draft = sdk::TransactionDraft {
+            has_max_transaction_fee: self.body.max_transaction_fee.is_some(),
+            regenerate_transaction_id: self.body.regenerate_transaction_id,
+            max_chunks: self.data().maybe_chunk_data().map_or(0, |it| it.max_chunks as u64),
+            chunk_size: self.data().maybe_chunk_data().map_or(0, |it| it.chunk_size.get() as u64),
+            max_concurrent_chunks: self
+                .data()
+                .maybe_chunk_data()
+                .map_or(0, |it| it.max_concurrent_chunks.get() as u64),
+        };
+
+        let node_account_ids: Vec<Option<AccountId>> = match self.body.node_account_ids.as_deref() {
+            Some(ids) if !ids.is_empty() => ids.iter().copied().map(Some).collect(),
+            _ => Vec::from([None]),
+        };
+
+        // only used for chunk info, which a single chunk doesn't have.
+        let chunk_transaction_id = self
+            .body
+            .transaction_id
+            .unwrap_or_else(|| TransactionId::generate(AccountId::new(0, 0, 0)));
+
+        let transaction_list = node_account_ids
+            .into_iter()
+            .map(|node_account_id| {
+                let body = services::TransactionBody {
+                    transaction_id: self.body.transaction_id.to_protobuf(),
+                    node_account_id: node_account_id.to_protobuf(),
+                    transaction_fee: self
+                        .body
+                        .max_transaction_fee
+                        .map_or(0, |it| it.to_tinybars() as u64),
+                    transaction_valid_duration: self.body.transaction_valid_duration.to_protobuf(),
+                    generate_record: false,
+                    memo: self.body.transaction_memo.clone(),
+                    data: Some(data.to_transaction_data_protobuf(&ChunkInfo {
+                        current: 0,
+                        total: 1,
+                        initial_transaction_id: chunk_transaction_id,
+                        current_transaction_id: chunk_transaction_id,
+                        node_account_id,
+                    })),
+                    max_custom_fees: self.body.custom_fee_limits.to_protobuf(),
+                    batch_key: None,
+                };
+
+                let signed_transaction =
+                    services::SignedTransaction { body_bytes: body.encode_to_vec(), sig_map: None };
+
+                services::Transaction {
+                    signed_transaction_bytes: signed_transaction.encode_to_vec(),
+                    ..services::Transaction::default()
+                }
+            })
+            .collect();
+
+        sdk::TransactionList { transaction_list, draft: Some(draft) }.encode_to_vec()
+    }
+}
+
+impl AnyTransaction {
+    /// Restores the builder state of a transaction encoded with [`Transaction::to_draft_bytes`].
+    #[allow(deprecated)]
+    pub(super) fn from_draft(
+        transactions: Vec<services::Transaction>,
+        draft: sdk::TransactionDraft,
+    ) -> crate::Result<Self> {
+        let bodies = transactions
+            .into_iter()
+            .map(|it| {
+                let body_bytes = if it.signed_transaction_bytes.is_empty() {
+                    it.body_bytes
+                } else {
+                    services::SignedTransaction::decode(&*it.signed_transaction_bytes)
+                        .map_err(Error::from_protobuf)?
+                        .body_bytes
+                };
+
+                services::TransactionBody::decode(&*body_bytes).map_err(Error::from_protobuf)
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        let node_account_ids = bodies
+            .iter()
+            .filter_map(|it| it.node_account_id.clone())
+            .map(AccountId::from_protobuf)
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        let first = bodies
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::from_protobuf("no transactions found"))?;
+
+        let data =
+            first.data.clone().ok_or_else(|| Error::from_protobuf("unexpected missing `data`"))?;
+
+        let mut transaction = Self::from_protobuf(first, Vec::from([data]))?;
+
+        let body = &mut transaction.body;
+
+        body.node_account_ids = (!node_account_ids.is_empty()).then_some(node_account_ids);
+        body.regenerate_transaction_id = draft.regenerate_transaction_id;
+
+        if !draft.has_max_transaction_fee {
+            body.max_transaction_fee = None;
+        }
+
+        if let Some(chunk_data) = body.data.maybe_chunk_data_mut() {
+            if draft.max_chunks != 0 {
+                chunk_data.max_chunks = draft.max_chunks as usize;
+            }
+
+            if let Some(chunk_size) = NonZeroUsize::new(draft.chunk_size as usize) {
+                chunk_data.chunk_size = chunk_size;
+            }
+
+            if let Some(max_concurrent_chunks) =
+                NonZeroUsize::new(draft.max_concurrent_chunks as usize)
+            {
+                chunk_data.max_concurrent_chunks = max_concurrent_chunks;
+            }
+        }
+
+        Ok(transaction)
+    }
+}
@@ -3,12 +3,14 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use prost::Message;
 use tonic::transport::Channel;
 
 use super::chunked::ChunkInfo;
 use super::source::SourceChunk;
+use super::validation::ValidationError;
 use super::{
     ChunkData,
     TransactionSources,
@@ -129,10 +131,36 @@ pub trait TransactionData: Clone + Into<AnyTransactionData> {
         None
     }
 
+    /// Returns the chunk data for this transaction mutably if this is a chunked transaction.
+    #[doc(hidden)]
+    fn maybe_chunk_data_mut(&mut self) -> Option<&mut ChunkData> {
+        None
+    }
+
     /// Returns `true` if `self` is a chunked transaction *and* it should wait for receipts between each chunk.
     fn wait_for_receipt(&self) -> bool {
         false
     }
+
+    /// Checks `self` against the network's documented limits, pushing every problem found to `errors`.
+    ///
+    /// This only covers what can be checked without network state.
+    #[doc(hidden)]
+    fn validate_data(&self, _errors: &mut Vec<ValidationError>) {}
+
+    /// Fills in fields that depend on the network before the transaction is frozen,
+    /// like the gas of a [`ContractExecuteTransaction`](crate::ContractExecuteTransaction) with auto gas.
+    #[doc(hidden)]
+    fn prepare<'a>(&'a mut self, _client: &'a Client) -> Option<BoxFuture<'a, crate::Result<()>>> {
+        None
+    }
+
+    /// Checks that nothing is left for [`prepare`](Self::prepare) to fill in,
+    /// since the transaction is about to be frozen or serialized without it.
+    #[doc(hidden)]
+    fn require_prepared(&self) -> crate::Result<()> {
+        Ok(())
+    }
 }
 
 pub trait TransactionExecute:
@@ -253,7 +281,10 @@ where
     D: TransactionData + ToTransactionDataProtobuf,
 {
     #[allow(deprecated)]
-    fn to_transaction_body_protobuf(&self, chunk_info: &ChunkInfo) -> services::TransactionBody {
+    pub(super) fn to_transaction_body_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::TransactionBody {
         let data = self.body.data.to_transaction_data_protobuf(chunk_info);
 
         let transaction_fee = if self.body.data.for_cost_estimate() {
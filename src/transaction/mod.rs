@@ -9,6 +9,8 @@ use std::fmt::{
 };
 use std::num::NonZeroUsize;
 
+use futures_util::stream::FuturesUnordered;
+use futures_util::StreamExt;
 use hedera_proto::services;
 use prost::Message;
 use time::Duration;
@@ -37,11 +39,14 @@ use crate::{
 mod any;
 mod chunked;
 mod cost;
+mod describe;
+mod draft;
 mod execute;
 mod protobuf;
 mod source;
 #[cfg(test)]
 mod tests;
+pub(crate) mod validation;
 
 pub use any::AnyTransaction;
 pub(crate) use any::AnyTransactionData;
@@ -50,7 +55,25 @@ pub(crate) use chunked::{
     ChunkInfo,
     ChunkedTransactionData,
 };
+pub use chunked::{
+    ChunkedExecution,
+    ExecutedChunk,
+};
 pub(crate) use cost::CostTransaction;
+pub use describe::{
+    AccountChange,
+    AllowanceAmount,
+    AllowanceDescription,
+    EthereumTransactionDescription,
+    HbarMovementDescription,
+    HbarMovementParty,
+    HbarTransferDescription,
+    KeyChange,
+    SignatureDescription,
+    TokenSupplyChange,
+    TokenTransferDescription,
+    TransactionDescription,
+};
 pub(crate) use execute::{
     TransactionData,
     TransactionExecute,
@@ -61,6 +84,10 @@ pub(crate) use protobuf::{
     ToTransactionDataProtobuf,
 };
 pub(crate) use source::TransactionSources;
+pub use validation::{
+    ValidationError,
+    ValidationErrorKind,
+};
 
 const DEFAULT_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(120);
 
@@ -370,6 +397,32 @@ impl<D: ChunkedTransactionData> Transaction<D> {
         self
     }
 
+    /// Returns the maximum number of chunks that are submitted at once.
+    #[must_use]
+    pub fn get_max_concurrent_chunks(&self) -> usize {
+        self.data().chunk_data().max_concurrent_chunks.get()
+    }
+
+    /// Sets the maximum number of chunks that are submitted at once, defaults to `1` (one chunk after another).
+    ///
+    /// When this is greater than `1`, the first chunk is submitted on its own,
+    /// then the remaining chunks are submitted concurrently and every receipt is fetched at the end.
+    ///
+    /// Only transactions that don't wait for each chunk's receipt (such as [`TopicMessageSubmitTransaction`](crate::TopicMessageSubmitTransaction))
+    /// can be submitted concurrently, for the others this is ignored.
+    ///
+    /// # Panics
+    /// If `max_concurrent_chunks` == 0
+    pub fn max_concurrent_chunks(&mut self, max_concurrent_chunks: usize) -> &mut Self {
+        let Some(max_concurrent_chunks) = NonZeroUsize::new(max_concurrent_chunks) else {
+            panic!("Cannot set max-concurrent-chunks to zero")
+        };
+
+        self.data_mut().chunk_data_mut().max_concurrent_chunks = max_concurrent_chunks;
+
+        self
+    }
+
     /// Returns whether or not the transaction ID should be refreshed if a [`Status::TransactionExpired`](crate::Status::TransactionExpired) occurs.
     ///
     /// By default, the value on Client will be used.
@@ -389,11 +442,13 @@ impl<D: ChunkedTransactionData> Transaction<D> {
     }
 }
 
-impl<D: ValidateChecksums> Transaction<D> {
+impl<D: ValidateChecksums + TransactionData> Transaction<D> {
     /// Freeze the transaction so that no further modifications can be made.
     ///
     /// # Errors
     /// - [`Error::FreezeUnsetNodeAccountIds`] if no [`node_account_ids`](Self::node_account_ids) were set.
+    /// - [`Error::GasNotEstimated`] if the transaction has [`auto_gas`](crate::ContractExecuteTransaction::auto_gas)
+    ///   and hasn't been executed.
     ///
     /// # Panics
     /// - If `node_account_ids` is explicitly set to empty (IE: `tx.node_account_ids([]).freeze_with(None)`).
@@ -405,6 +460,8 @@ impl<D: ValidateChecksums> Transaction<D> {
     ///
     /// # Errors
     /// - [`Error::FreezeUnsetNodeAccountIds`] if no [`node_account_ids`](Self::node_account_ids) were set and `client.is_none()`.
+    /// - [`Error::GasNotEstimated`] if the transaction has [`auto_gas`](crate::ContractExecuteTransaction::auto_gas)
+    ///   and hasn't been executed.
     ///
     /// # Panics
     /// - If `node_account_ids` is explicitly set to empty (IE: `tx.node_account_ids([]).freeze_with(None)`).
@@ -415,6 +472,9 @@ impl<D: ValidateChecksums> Transaction<D> {
         if self.is_frozen() {
             return Ok(self);
         }
+
+        self.data().require_prepared()?;
+
         let client: Option<&Client> = client.into();
 
         // set transaction id if not set based on client operator
@@ -521,14 +581,24 @@ impl<D: TransactionExecute> Transaction<D> {
 
     /// Convert `self` to protobuf encoded bytes.
     ///
+    /// Transactions that haven't been frozen yet can be serialized too,
+    /// [`AnyTransaction::from_bytes`] restores them to the same (editable) builder state,
+    /// including unset fields and chunk settings.
+    /// Signers added to an unfrozen transaction are not serialized.
+    ///
     /// # Errors
     /// - If `freeze_with` wasn't called with an operator.
-    ///
-    /// # Panics
-    /// - If `!self.is_frozen()`.
+    /// - [`Error::GasNotEstimated`] if the transaction has [`auto_gas`](crate::ContractExecuteTransaction::auto_gas)
+    ///   and hasn't been executed, since the estimator isn't serialized.
     pub fn to_bytes(&self) -> crate::Result<Vec<u8>> {
-        let transaction_list = self.make_transaction_list().unwrap();
-        Ok(hedera_proto::sdk::TransactionList { transaction_list }.encode_to_vec())
+        if !self.is_frozen() && self.sources.is_none() {
+            self.data().require_prepared()?;
+
+            return Ok(self.to_draft_bytes());
+        }
+
+        let transaction_list = self.make_transaction_list()?;
+        Ok(hedera_proto::sdk::TransactionList { transaction_list, draft: None }.encode_to_vec())
     }
 
     pub(crate) fn add_signature_signer(&mut self, signer: &AnySigner) -> Vec<u8> {
@@ -585,9 +655,13 @@ impl<D: TransactionExecute> Transaction<D> {
     /// panics if the transaction is not schedulable, a transaction can be non-schedulable due to:
     /// - if `self.is_frozen`
     /// - being a transaction kind that's non-schedulable, IE, `EthereumTransaction`, or
-    /// - being a chunked transaction with multiple chunks.
+    /// - being a chunked transaction with multiple chunks, or
+    /// - having [`auto_gas`](crate::ContractExecuteTransaction::auto_gas), since a scheduled transaction's gas can't be estimated.
     pub fn schedule(self) -> ScheduleCreateTransaction {
         self.require_not_frozen();
+        if let Err(error) = self.data().require_prepared() {
+            panic!("{error}");
+        }
         assert!(self.get_node_account_ids().is_none(), "The underlying transaction for a scheduled transaction cannot have node account IDs set");
 
         let mut transaction = ScheduleCreateTransaction::new();
@@ -660,6 +734,50 @@ impl<D: TransactionExecute> Transaction<D> {
         Ok(iter.collect())
     }
 
+    /// Returns the unsigned body of every chunk of this transaction, as it would be sent to the first node.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`] if no transaction ID has been set.
+    pub(crate) fn chunk_bodies(&self) -> crate::Result<Vec<services::TransactionBody>> {
+        let transaction_id =
+            self.get_transaction_id().ok_or(Error::NoPayerAccountOrTransactionId)?;
+
+        let node_account_id =
+            self.body.node_account_ids.as_deref().and_then(<[AccountId]>::first).copied();
+
+        let total = self.data().maybe_chunk_data().map_or(1, ChunkData::used_chunks);
+
+        let bodies = (0..total)
+            .map(|current| {
+                self.to_transaction_body_protobuf(&ChunkInfo {
+                    current,
+                    total,
+                    initial_transaction_id: transaction_id,
+                    current_transaction_id: transaction_id,
+                    node_account_id,
+                })
+            })
+            .collect();
+
+        Ok(bodies)
+    }
+
+    /// Returns the number of distinct signatures this transaction will currently be submitted with.
+    pub(crate) fn signature_count(&self) -> usize {
+        let existing = self
+            .sources
+            .as_ref()
+            .and_then(|it| it.signed_transactions().first())
+            .and_then(|it| it.sig_map.as_ref())
+            .map_or(0, |it| it.sig_pair.len());
+
+        let operator = self.body.operator.as_ref().map(|it| it.signer.public_key());
+
+        let signers = self.signers.iter().filter(|it| Some(it.public_key()) != operator).count();
+
+        existing + signers + usize::from(operator.is_some())
+    }
+
     #[allow(deprecated)]
     fn make_transaction_list_chunked(&self) -> crate::Result<Vec<services::Transaction>> {
         // todo: fix this with chunked transactions.
@@ -797,6 +915,40 @@ where
     pub fn default_max_transaction_fee(&self) -> Hbar {
         self.data().default_max_transaction_fee()
     }
+
+    /// Check this transaction against the network's documented limits without submitting it.
+    ///
+    /// This catches problems that would otherwise only be reported by the network's pre-check,
+    /// such as transfers that don't net to zero, memos that are too long, or duplicated token IDs.
+    ///
+    /// Checks that need network state (like whether an account exists) aren't done.
+    ///
+    /// See also [`Client::set_auto_validate_transactions`].
+    ///
+    /// # Errors
+    /// - [`Error::TransactionValidation`] with every problem found, if any.
+    pub fn validate(&self) -> crate::Result<()> {
+        let mut errors = Vec::new();
+
+        validation::validate_memo("transaction_memo", &self.body.transaction_memo, &mut errors);
+
+        if let Some(duration) = self.body.transaction_valid_duration {
+            validation::validate_duration(
+                "transaction_valid_duration",
+                duration,
+                validation::MIN_TRANSACTION_VALID_DURATION,
+                validation::MAX_TRANSACTION_VALID_DURATION,
+                &mut errors,
+            );
+        }
+
+        self.data().validate_data(&mut errors);
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(Error::TransactionValidation(errors)),
+        }
+    }
 }
 
 impl<D> Transaction<D>
@@ -837,9 +989,19 @@ where
         client: &Client,
         timeout: Option<std::time::Duration>,
     ) -> crate::Result<TransactionResponse> {
+        if !self.is_frozen() {
+            if let Some(prepare) = self.data_mut().prepare(client) {
+                prepare.await?;
+            }
+        }
+
         // it's fine to call freeze while already frozen, so, let `freeze_with` handle the freeze check.
         self.freeze_with(Some(client))?;
 
+        if client.auto_validate_transactions() {
+            self.validate()?;
+        }
+
         if let Some(sources) = self.sources() {
             // Check if sources are "empty" (no transaction IDs and no node IDs)
             let has_transaction_ids =
@@ -878,63 +1040,231 @@ where
         client: &Client,
         timeout_per_chunk: Option<std::time::Duration>,
     ) -> crate::Result<Vec<TransactionResponse>> {
-        assert!(self.is_frozen());
+        let mut execution = ChunkedExecution::new();
 
-        let wait_for_receipts = self.data().wait_for_receipt();
+        self.execute_chunks(chunk_data, client, timeout_per_chunk, &mut execution).await?;
+
+        Ok(execution.into_responses())
+    }
+
+    /// Submits every chunk that `execution` doesn't have yet, recording each one as it lands.
+    async fn execute_chunks(
+        &self,
+        chunk_data: &ChunkData,
+        client: &Client,
+        timeout_per_chunk: Option<std::time::Duration>,
+        execution: &mut ChunkedExecution,
+    ) -> crate::Result<()> {
+        assert!(self.is_frozen());
 
-        // fixme: error with an actual error.
-        #[allow(clippy::manual_assert)]
         if chunk_data.data.len() > chunk_data.max_message_len() {
-            todo!("error: message too big")
+            return Err(Error::MessageTooLarge {
+                len: chunk_data.data.len(),
+                max: chunk_data.max_message_len(),
+            });
         }
 
         let used_chunks = chunk_data.used_chunks();
 
-        let mut responses = Vec::with_capacity(chunk_data.used_chunks());
+        if execution.total_chunks != 0 && execution.total_chunks != used_chunks {
+            return Err(Error::ChunkCountMismatch {
+                expected: execution.total_chunks,
+                actual: used_chunks,
+            });
+        }
+
+        execution.total_chunks = used_chunks;
+
+        let wait_for_receipts = self.data().wait_for_receipt();
+        let max_concurrent_chunks = chunk_data.max_concurrent_chunks.get();
+
+        if !wait_for_receipts && max_concurrent_chunks > 1 {
+            return self
+                .execute_chunks_concurrently(
+                    client,
+                    timeout_per_chunk,
+                    max_concurrent_chunks,
+                    execution,
+                )
+                .await;
+        }
+
+        loop {
+            // the last chunk may have been submitted without its receipt being fetched (if fetching it failed).
+            if let Some(chunk) = execution
+                .chunks
+                .last_mut()
+                .and_then(Option::as_mut)
+                .filter(|it| wait_for_receipts && it.receipt.is_none())
+            {
+                let receipt = chunk
+                    .response
+                    .get_receipt_query()
+                    .execute_with_optional_timeout(client, timeout_per_chunk)
+                    .await;
+
+                match receipt {
+                    Ok(receipt) => chunk.receipt = Some(receipt),
+                    Err(error) => {
+                        // the chunk reached consensus and failed, so it has to be submitted again.
+                        if matches!(error, Error::ReceiptStatus { .. }) {
+                            execution.chunks.pop();
+                        }
+
+                        return Err(error);
+                    }
+                }
+            }
+
+            let current_chunk = execution.chunks.len();
+
+            if current_chunk == used_chunks {
+                return Ok(());
+            }
+
+            let response = match execution.initial_transaction_id() {
+                None => {
+                    execute(
+                        client,
+                        &chunked::FirstChunkView { transaction: self, total_chunks: used_chunks },
+                        timeout_per_chunk,
+                    )
+                    .await?
+                }
+                Some(initial_transaction_id) => {
+                    execute(
+                        client,
+                        &chunked::ChunkView {
+                            transaction: self,
+                            initial_transaction_id,
+                            current_chunk,
+                            total_chunks: used_chunks,
+                        },
+                        timeout_per_chunk,
+                    )
+                    .await?
+                }
+            };
+
+            execution.chunks.push(Some(ExecutedChunk { response, receipt: None }));
+        }
+    }
 
-        let initial_transaction_id = {
-            let resp = execute(
+    /// Submits the first chunk, then up to `max_concurrent_chunks` of the remaining chunks at a time,
+    /// and then fetches the receipt of every chunk.
+    async fn execute_chunks_concurrently(
+        &self,
+        client: &Client,
+        timeout_per_chunk: Option<std::time::Duration>,
+        max_concurrent_chunks: usize,
+        execution: &mut ChunkedExecution,
+    ) -> crate::Result<()> {
+        let total_chunks = execution.total_chunks;
+
+        // every other chunk references the first chunk's transaction ID, so it has to land first.
+        if execution.chunks.is_empty() {
+            let response = execute(
                 client,
-                &chunked::FirstChunkView { transaction: self, total_chunks: used_chunks },
+                &chunked::FirstChunkView { transaction: self, total_chunks },
                 timeout_per_chunk,
             )
             .await?;
 
-            if wait_for_receipts {
-                resp.get_receipt_query()
-                    .execute_with_optional_timeout(client, timeout_per_chunk)
-                    .await?;
-            }
+            execution.chunks.push(Some(ExecutedChunk { response, receipt: None }));
+        }
 
-            let initial_transaction_id = resp.transaction_id;
-            responses.push(resp);
+        let initial_transaction_id =
+            execution.initial_transaction_id().expect("the first chunk has been submitted");
 
-            initial_transaction_id
-        };
+        // chunks that failed are submitted again in place, the chunks after them already landed.
+        let failed: Vec<_> = execution.failed_chunks().collect();
+        let mut unsubmitted = failed.into_iter().chain(execution.chunks.len()..total_chunks);
 
-        for chunk in 1..used_chunks {
-            let resp = execute(
+        let submit = |current_chunk| async move {
+            let response = execute(
                 client,
                 &chunked::ChunkView {
                     transaction: self,
                     initial_transaction_id,
-                    current_chunk: chunk,
-                    total_chunks: used_chunks,
+                    current_chunk,
+                    total_chunks,
                 },
                 timeout_per_chunk,
             )
-            .await?;
+            .await;
 
-            if wait_for_receipts {
-                resp.get_receipt_query()
-                    .execute_with_optional_timeout(client, timeout_per_chunk)
-                    .await?;
+            (current_chunk, response)
+        };
+
+        let mut in_flight = FuturesUnordered::new();
+        let mut first_error = None;
+
+        loop {
+            // once a chunk fails no more chunks are submitted,
+            // but the chunks in flight may still reach consensus, so they're waited for and recorded.
+            while first_error.is_none() && in_flight.len() < max_concurrent_chunks {
+                match unsubmitted.next() {
+                    Some(current_chunk) => in_flight.push(submit(current_chunk)),
+                    None => break,
+                }
+            }
+
+            let Some((index, response)) = in_flight.next().await else {
+                break;
+            };
+
+            match response {
+                Ok(response) => {
+                    execution.chunk_landed(index, ExecutedChunk { response, receipt: None });
+                }
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
             }
+        }
+
+        if let Some(error) = first_error {
+            // only the chunks that didn't land are submitted again, the chunks after them are kept.
+            execution.missing_chunks_failed();
+
+            return Err(error);
+        }
+
+        let pending: Vec<_> = execution
+            .chunks
+            .iter()
+            .enumerate()
+            .filter_map(|(index, chunk)| Some((index, chunk.as_ref()?)))
+            .filter(|(index, chunk)| chunk.receipt.is_none() && !execution.failed.contains(index))
+            .map(|(index, chunk)| (index, chunk.response.get_receipt_query()))
+            .collect();
+
+        let mut receipts = futures_util::stream::iter(pending)
+            .map(|(index, mut query)| async move {
+                (index, query.execute_with_optional_timeout(client, timeout_per_chunk).await)
+            })
+            .buffered(max_concurrent_chunks);
+
+        while let Some((index, receipt)) = receipts.next().await {
+            match receipt {
+                Ok(receipt) => {
+                    if let Some(chunk) = &mut execution.chunks[index] {
+                        chunk.receipt = Some(receipt);
+                    }
+                }
+                Err(error) => {
+                    // the chunk reached consensus and failed, so it has to be submitted again,
+                    // but the chunks after it may have landed, and are kept.
+                    if matches!(error, Error::ReceiptStatus { .. }) {
+                        execution.chunk_failed(index);
+                    }
 
-            responses.push(resp);
+                    return Err(error);
+                }
+            }
         }
 
-        Ok(responses)
+        Ok(())
     }
 
     /// Execute this transaction against the provided client of the Hiero network.
@@ -962,6 +1292,47 @@ where
         self.execute_all_with_optional_timeout(client, None).await
     }
 
+    /// Execute all transactions, recording the chunks that land in `execution`.
+    ///
+    /// If a chunk fails, call this again with the same transaction and `execution` to resume from the first missing chunk,
+    /// the remaining chunks still reference the first chunk's transaction ID.
+    ///
+    /// Transactions restored with [`AnyTransaction::from_bytes`] after being frozen are already split into chunks,
+    /// and are executed in one go.
+    ///
+    /// # Errors
+    /// - [`Error::MessageTooLarge`] if the data doesn't fit in `max_chunks` chunks.
+    /// - [`Error::ChunkCountMismatch`] if `execution` was used for a transaction with a different number of chunks.
+    /// - Any error from submitting a chunk or fetching its receipt, `execution` keeps every chunk that landed.
+    pub async fn execute_all_resumable(
+        &mut self,
+        client: &Client,
+        execution: &mut ChunkedExecution,
+    ) -> crate::Result<()> {
+        if self.sources().is_some() || self.data().maybe_chunk_data().is_none() {
+            let responses = self.execute_all_with_optional_timeout(client, None).await?;
+
+            execution.total_chunks = responses.len();
+            execution.chunks = responses
+                .into_iter()
+                .map(|response| Some(ExecutedChunk { response, receipt: None }))
+                .collect();
+
+            return Ok(());
+        }
+
+        // it's fine to call freeze while already frozen, so, let `freeze_with` handle the freeze check.
+        self.freeze_with(Some(client))?;
+
+        if client.auto_validate_transactions() {
+            self.validate()?;
+        }
+
+        let chunk_data = self.data().maybe_chunk_data().expect("checked above");
+
+        self.execute_chunks(chunk_data, client, None, execution).await
+    }
+
     pub(crate) async fn execute_all_with_optional_timeout(
         &mut self,
         client: &Client,
@@ -970,6 +1341,10 @@ where
         // it's fine to call freeze while already frozen, so, let `freeze_with` handle the freeze check.
         self.freeze_with(Some(client))?;
 
+        if client.auto_validate_transactions() {
+            self.validate()?;
+        }
+
         // fixme: dedup this with `execute_with_optional_timeout`
         if let Some(sources) = self.sources() {
             // Check if sources are "empty" (no transaction IDs and no node IDs)
@@ -1026,6 +1401,10 @@ impl AnyTransaction {
         let list: hedera_proto::sdk::TransactionList =
             hedera_proto::sdk::TransactionList::decode(bytes).map_err(Error::from_protobuf)?;
 
+        if let Some(draft) = list.draft {
+            return Self::from_draft(list.transaction_list, draft);
+        }
+
         let list = if list.transaction_list.is_empty() {
             Vec::from([services::Transaction::decode(bytes).map_err(Error::from_protobuf)?])
         } else {
@@ -4,15 +4,46 @@ use assert_matches::assert_matches;
 use hex_literal::hex;
 use time::OffsetDateTime;
 
+use crate::transaction::test_helpers::unused_private_key;
 use crate::transaction::AnyTransactionData;
 use crate::{
+    AccountAllowanceApproveTransaction,
+    AccountAllowanceDeleteTransaction,
+    AccountChange,
+    AccountCreateTransaction,
+    AccountDeleteTransaction,
+    AccountId,
+    AccountUpdateTransaction,
+    AllowanceAmount,
+    AllowanceDescription,
     AnyTransaction,
+    ChunkedExecution,
     Client,
+    ContractExecuteTransaction,
+    ContractId,
+    Error,
+    EthereumTransaction,
+    EvmAddress,
+    ExecutedChunk,
     Hbar,
+    HbarMovementDescription,
+    HbarMovementParty,
+    KeyChange,
+    NftId,
     PrivateKey,
+    TokenBurnTransaction,
+    TokenId,
+    TokenMintTransaction,
+    TokenSupplyChange,
+    TokenUpdateTransaction,
+    TokenWipeTransaction,
     TopicMessageSubmitTransaction,
+    TransactionHash,
     TransactionId,
+    TransactionResponse,
     TransferTransaction,
+    ValidationError,
+    ValidationErrorKind,
 };
 
 #[test]
@@ -119,3 +150,564 @@ async fn chunked_to_from_bytes() -> crate::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn unfrozen_to_from_bytes() -> crate::Result<()> {
+    let mut tx = TransferTransaction::new();
+
+    tx.transaction_memo("draft")
+        .hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2));
+
+    let tx2 = AnyTransaction::from_bytes(&tx.to_bytes()?)?;
+
+    assert_eq!(tx2.get_transaction_id(), None);
+    assert_eq!(tx2.get_node_account_ids(), None);
+    assert_eq!(tx2.get_max_transaction_fee(), None);
+    assert_eq!(tx2.get_transaction_valid_duration(), None);
+    assert_eq!(tx2.get_transaction_memo(), "draft");
+
+    let mut tx2: TransferTransaction = tx2.downcast().unwrap();
+
+    assert_eq!(tx2.data(), tx.data());
+
+    // the restored transaction is still editable.
+    tx2.transaction_memo("edited").max_transaction_fee(Hbar::new(3));
+
+    let tx3 = AnyTransaction::from_bytes(&tx2.to_bytes()?)?;
+
+    assert_eq!(tx3.get_transaction_memo(), "edited");
+    assert_eq!(tx3.get_max_transaction_fee(), Some(Hbar::new(3)));
+
+    Ok(())
+}
+
+#[test]
+fn unfrozen_chunked_to_from_bytes() -> crate::Result<()> {
+    let mut tx = TopicMessageSubmitTransaction::new();
+
+    tx.topic_id(314)
+        .message(b"Hello, world!".to_vec())
+        .chunk_size(8)
+        .max_chunks(3)
+        .max_concurrent_chunks(4)
+        .node_account_ids([6.into(), 7.into()]);
+
+    let tx2 = AnyTransaction::from_bytes(&tx.to_bytes()?)?;
+
+    assert_eq!(
+        tx2.get_node_account_ids(),
+        Some([AccountId::from(6), AccountId::from(7)].as_slice())
+    );
+
+    let tx2: TopicMessageSubmitTransaction = tx2.downcast().unwrap();
+
+    assert_eq!(tx2.get_message(), Some(b"Hello, world!".as_slice()));
+    assert_eq!(tx2.get_chunk_size(), 8);
+    assert_eq!(tx2.get_max_chunks(), 3);
+    assert_eq!(tx2.get_max_concurrent_chunks(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn max_concurrent_chunks_defaults_to_serial() {
+    assert_eq!(TopicMessageSubmitTransaction::new().get_max_concurrent_chunks(), 1);
+}
+
+#[test]
+#[should_panic]
+fn max_concurrent_chunks_zero_panics() {
+    TopicMessageSubmitTransaction::new().max_concurrent_chunks(0);
+}
+
+#[test]
+fn validate_ok() {
+    let mut tx = TransferTransaction::new();
+
+    tx.transaction_memo("hi hashgraph")
+        .hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .token_transfer(TokenId::new(0, 0, 42), 2.into(), 5)
+        .token_transfer(TokenId::new(0, 0, 42), 101.into(), -5);
+
+    tx.validate().unwrap();
+}
+
+#[test]
+fn validate_transfers() {
+    let token_id = TokenId::new(0, 0, 42);
+
+    let mut tx = TransferTransaction::new();
+
+    tx.transaction_memo("a".repeat(101))
+        .transaction_valid_duration(time::Duration::seconds(181))
+        .hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-1))
+        .nft_transfer(NftId { token_id, serial: 1 }, 2.into(), 101.into())
+        .nft_transfer(NftId { token_id, serial: 1 }, 2.into(), 101.into());
+
+    let errors =
+        assert_matches!(tx.validate(), Err(Error::TransactionValidation(errors)) => errors);
+
+    assert_eq!(
+        errors,
+        [
+            ValidationError {
+                field: "transaction_memo",
+                kind: ValidationErrorKind::TooLong { len: 101, max: 100 },
+            },
+            ValidationError {
+                field: "transaction_valid_duration",
+                kind: ValidationErrorKind::DurationOutOfRange {
+                    duration: time::Duration::seconds(181),
+                    min: time::Duration::seconds(15),
+                    max: time::Duration::seconds(180),
+                },
+            },
+            ValidationError {
+                field: "hbar_transfers",
+                kind: ValidationErrorKind::TransfersDoNotNetToZero {
+                    token_id: None,
+                    sum: 100_000_000,
+                },
+            },
+            ValidationError {
+                field: "nft_transfers",
+                kind: ValidationErrorKind::DuplicateNftTransfer(NftId { token_id, serial: 1 }),
+            },
+        ]
+    );
+}
+
+#[test]
+fn validate_serials() {
+    let mut tx = TokenBurnTransaction::new();
+
+    tx.token_id(TokenId::new(0, 0, 42)).serials([3, 1, 1, 0]);
+
+    let errors =
+        assert_matches!(tx.validate(), Err(Error::TransactionValidation(errors)) => errors);
+
+    let kinds: Vec<_> = errors.into_iter().map(|it| it.kind).collect();
+
+    assert_eq!(
+        kinds,
+        [
+            ValidationErrorKind::DuplicateSerial(1),
+            ValidationErrorKind::InvalidSerial(0),
+            ValidationErrorKind::UnsortedSerials,
+        ]
+    );
+}
+
+#[test]
+fn validate_update() {
+    let mut tx = AccountUpdateTransaction::new();
+
+    tx.account_id(2.into()).account_memo("a\0").auto_renew_period(time::Duration::seconds(1));
+
+    let errors =
+        assert_matches!(tx.validate(), Err(Error::TransactionValidation(errors)) => errors);
+
+    let fields: Vec<_> = errors.into_iter().map(|it| it.field).collect();
+
+    assert_eq!(fields, ["account_memo", "auto_renew_period"]);
+}
+
+#[test]
+fn validate_scheduled() {
+    let mut tx = TokenBurnTransaction::new();
+
+    tx.token_id(TokenId::new(0, 0, 42)).serials([1, 0]);
+
+    let mut tx = tx.schedule();
+
+    tx.schedule_memo("a".repeat(101));
+
+    let errors =
+        assert_matches!(tx.validate(), Err(Error::TransactionValidation(errors)) => errors);
+
+    let kinds: Vec<_> = errors.into_iter().map(|it| (it.field, it.kind)).collect();
+
+    assert_eq!(
+        kinds,
+        [
+            ("schedule_memo", ValidationErrorKind::TooLong { len: 101, max: 100 }),
+            ("serials", ValidationErrorKind::InvalidSerial(0)),
+            ("serials", ValidationErrorKind::UnsortedSerials),
+        ]
+    );
+}
+
+#[test]
+fn describe_transfer() -> crate::Result<()> {
+    let mut tx = TransferTransaction::new();
+
+    tx.transaction_id(TransactionId {
+        account_id: 5006.into(),
+        valid_start: OffsetDateTime::from_unix_timestamp(1554158542).unwrap(),
+        nonce: None,
+        scheduled: false,
+    })
+    .node_account_ids([3.into()])
+    .max_transaction_fee(Hbar::new(2))
+    .transaction_memo("payroll")
+    .hbar_transfer(5006.into(), Hbar::from_tinybars(-150_000_000))
+    .hbar_transfer(3.into(), Hbar::from_tinybars(150_000_000))
+    .token_transfer_with_decimals(TokenId::new(0, 0, 100), 5006.into(), -150, 2)
+    .token_transfer_with_decimals(TokenId::new(0, 0, 100), 3.into(), 150, 2)
+    .freeze()?;
+
+    let tx = AnyTransaction::from_bytes(&tx.to_bytes()?)?;
+
+    assert_eq!(
+        tx.describe()?.to_string(),
+        "TransferTransaction
+  transaction id: 0.0.5006@1554158542.0
+  payer: 0.0.5006
+  valid from: 1554158542.000000000
+  valid until: 1554158662.000000000
+  max transaction fee: 2 ℏ
+  memo: \"payroll\"
+  nodes: 0.0.3
+  hbar transfers:
+    0.0.5006: -1.5 ℏ
+    0.0.3: 1.5 ℏ
+  token transfers:
+    0.0.100 0.0.5006: -1.50
+    0.0.100 0.0.3: 1.50
+"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn describe_scheduled() -> crate::Result<()> {
+    let key = unused_private_key();
+
+    let transaction_id = TransactionId {
+        account_id: 5006.into(),
+        valid_start: OffsetDateTime::from_unix_timestamp(1554158542).unwrap(),
+        nonce: None,
+        scheduled: false,
+    };
+
+    let mut inner = TransferTransaction::new();
+    inner.hbar_transfer(5006.into(), Hbar::new(-1)).hbar_transfer(3.into(), Hbar::new(1));
+
+    let mut tx = inner.schedule();
+    tx.admin_key(key.public_key())
+        .payer_account_id(7.into())
+        .transaction_id(transaction_id)
+        .node_account_ids([3.into()])
+        .sign(key.clone());
+
+    let description = tx.describe()?;
+
+    assert_eq!(description.transaction_type, "ScheduleCreateTransaction");
+    assert_eq!(
+        description.key_changes,
+        [KeyChange { field: "admin_key", key: key.public_key().into() }]
+    );
+
+    assert_eq!(description.signatures.len(), 1);
+    assert_eq!(description.signatures[0].public_key, Some(key.public_key()));
+    assert!(description.signatures[0].is_pending);
+
+    let scheduled = description.scheduled_transaction.unwrap();
+
+    assert_eq!(scheduled.transaction_type, "TransferTransaction");
+    assert_eq!(scheduled.transaction_id, Some(TransactionId { scheduled: true, ..transaction_id }));
+    assert_eq!(scheduled.payer_account_id, Some(7.into()));
+    assert_eq!(scheduled.transaction_valid_duration, None);
+    assert_eq!(scheduled.hbar_transfers.len(), 2);
+    assert_eq!(scheduled.hbar_transfers[0].amount, Hbar::new(-1));
+
+    Ok(())
+}
+
+#[test]
+fn describe_hbar_movements() -> crate::Result<()> {
+    let transaction_id = TransactionId {
+        account_id: 5006.into(),
+        valid_start: OffsetDateTime::from_unix_timestamp(1554158542).unwrap(),
+        nonce: None,
+        scheduled: false,
+    };
+
+    let mut tx = AccountCreateTransaction::new();
+
+    tx.transaction_id(transaction_id)
+        .node_account_ids([3.into()])
+        .key(unused_private_key().public_key())
+        .initial_balance(Hbar::new(5))
+        .freeze()?;
+
+    let description = tx.describe()?;
+
+    // the fee that's signed, rather than the unset field.
+    assert_eq!(description.max_transaction_fee, Some(Hbar::new(2)));
+    assert_eq!(
+        description.hbar_movements,
+        [HbarMovementDescription {
+            field: "initial_balance",
+            from: HbarMovementParty::Payer,
+            to: HbarMovementParty::NewAccount,
+            amount: Some(Hbar::new(5)),
+        }]
+    );
+
+    let mut tx = AccountDeleteTransaction::new();
+
+    tx.transaction_id(transaction_id)
+        .node_account_ids([3.into()])
+        .account_id(7.into())
+        .transfer_account_id(3.into())
+        .freeze()?;
+
+    let tx = AnyTransaction::from_bytes(&tx.to_bytes()?)?;
+
+    assert!(tx.describe()?.to_string().contains(
+        "hbar movements:\n    transfer_account_id: entire balance from 0.0.7 to 0.0.3\n"
+    ));
+
+    let mut tx = ContractExecuteTransaction::new();
+
+    tx.contract_id(ContractId::new(0, 0, 5007)).payable_amount(Hbar::from_tinybars(10));
+
+    assert_eq!(
+        tx.describe()?.hbar_movements,
+        [HbarMovementDescription {
+            field: "payable_amount",
+            from: HbarMovementParty::Payer,
+            to: HbarMovementParty::Contract(ContractId::new(0, 0, 5007)),
+            amount: Some(Hbar::from_tinybars(10)),
+        }]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn describe_allowances() -> crate::Result<()> {
+    let mut tx = AccountAllowanceApproveTransaction::new();
+
+    tx.transaction_id(TransactionId {
+        account_id: 5006.into(),
+        valid_start: OffsetDateTime::from_unix_timestamp(1554158542).unwrap(),
+        nonce: None,
+        scheduled: false,
+    })
+    .node_account_ids([3.into()])
+    .approve_hbar_allowance(5006.into(), 7.into(), Hbar::new(5))
+    .approve_token_allowance(TokenId::new(0, 0, 100), 5006.into(), 7.into(), 250)
+    .approve_token_nft_allowance(NftId::from((TokenId::new(0, 0, 200), 1)), 5006.into(), 7.into())
+    .approve_token_nft_allowance(NftId::from((TokenId::new(0, 0, 200), 2)), 5006.into(), 7.into())
+    .approve_token_nft_allowance_all_serials(TokenId::new(0, 0, 300), 5006.into(), 8.into())
+    .freeze()?;
+
+    let description = AnyTransaction::from_bytes(&tx.to_bytes()?)?.describe()?;
+
+    assert_eq!(description.transaction_type, "AccountAllowanceApproveTransaction");
+    assert_eq!(
+        description.allowances[0],
+        AllowanceDescription {
+            owner_account_id: Some(5006.into()),
+            spender_account_id: Some(7.into()),
+            amount: AllowanceAmount::Hbar(Hbar::new(5)),
+        }
+    );
+
+    assert!(description.to_string().contains(
+        "  allowances:
+    0.0.5006 -> 0.0.7: 5 ℏ
+    0.0.5006 -> 0.0.7: 0.0.100 250 (smallest unit)
+    0.0.5006 -> 0.0.7: 0.0.200 serials 1, 2
+    0.0.5006 -> 0.0.8: 0.0.300 all serials
+"
+    ));
+
+    let mut tx = AccountAllowanceDeleteTransaction::new();
+
+    tx.delete_all_token_nft_allowances(NftId::from((TokenId::new(0, 0, 200), 1)), 5006.into());
+
+    assert_eq!(
+        tx.describe()?.allowances,
+        [AllowanceDescription {
+            owner_account_id: Some(5006.into()),
+            spender_account_id: None,
+            amount: AllowanceAmount::Nfts {
+                token_id: TokenId::new(0, 0, 200),
+                serials: vec![1],
+                delegating_spender_account_id: None,
+            },
+        }]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn describe_token_supply_changes() -> crate::Result<()> {
+    let mut tx = TokenMintTransaction::new();
+    tx.token_id(TokenId::new(0, 0, 100)).amount(500);
+
+    assert!(tx
+        .describe()?
+        .to_string()
+        .contains("  token supply change:\n    mint 0.0.100: 500 (smallest unit)\n"));
+
+    let mut tx = TokenWipeTransaction::new();
+    tx.token_id(TokenId::new(0, 0, 200)).account_id(7.into()).serials([1, 2]);
+
+    assert_eq!(
+        tx.describe()?.token_supply_change,
+        Some(TokenSupplyChange::Wipe {
+            token_id: Some(TokenId::new(0, 0, 200)),
+            account_id: Some(7.into()),
+            amount: 0,
+            serials: vec![1, 2],
+        })
+    );
+
+    let mut tx = TokenUpdateTransaction::new();
+    tx.token_id(TokenId::new(0, 0, 100)).treasury_account_id(7.into());
+
+    assert_eq!(
+        tx.describe()?.account_changes,
+        [AccountChange { field: "treasury_account_id", account_id: 7.into() }]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn describe_ethereum_transaction() -> crate::Result<()> {
+    let mut tx = EthereumTransaction::new();
+
+    // an EIP-2930 transaction sending 1 hbar (10^18 weibar).
+    tx.ethereum_data(hex!("01f8a882012a022f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181880de0b6b3a764000083123456f838f7947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181e1a0000000000000000000000000000000000000000000000000000000000000000101a0df48f2efd10421811de2bfb125ab75b2d3c44139c4642837fb1fccce911fd479a01aaf7ae92bee896651dfc9d99ae422a296bf5d9f1ca49b2d96d82b79eb112d66").to_vec())
+        .max_gas_allowance_hbar(Hbar::new(2));
+
+    let description = tx.describe()?.ethereum_transaction.unwrap();
+
+    assert_eq!(
+        description.to,
+        Some(EvmAddress::from(hex!("7e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181")))
+    );
+    assert_eq!(description.value, 10_u64.pow(18).into());
+    assert_eq!(description.max_gas_allowance, Hbar::new(2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute_all_message_too_large() {
+    let client = Client::for_testnet();
+    client.set_operator(0.into(), PrivateKey::generate_ed25519());
+
+    let mut tx = TopicMessageSubmitTransaction::new();
+
+    tx.topic_id(314)
+        .message(b"Hello, world!".to_vec())
+        .chunk_size(4)
+        .max_chunks(2)
+        .node_account_ids([6.into()]);
+
+    let mut execution = ChunkedExecution::new();
+
+    let error = tx.execute_all_resumable(&client, &mut execution).await.unwrap_err();
+
+    assert_matches!(error, Error::MessageTooLarge { len: 13, max: 8 });
+    assert_eq!(execution.total_chunks(), 0);
+    assert_eq!(execution.next_chunk(), Some(0));
+}
+
+#[test]
+fn chunked_execution_failed_chunks() {
+    let chunk = |index: u8| ExecutedChunk {
+        response: TransactionResponse {
+            node_account_id: 3.into(),
+            transaction_id: TransactionId::generate(5006.into()),
+            transaction_hash: TransactionHash::new(&[index]),
+            validate_status: true,
+        },
+        receipt: None,
+    };
+
+    let mut execution = ChunkedExecution::new();
+    execution.total_chunks = 4;
+    execution.chunks = vec![Some(chunk(0)), Some(chunk(1)), Some(chunk(2))];
+
+    // chunks after a failed chunk are kept, only the failed one is submitted again.
+    execution.chunk_failed(1);
+
+    assert_eq!(execution.chunks().len(), 3);
+    assert_eq!(execution.failed_chunks().collect::<Vec<_>>(), [1]);
+    assert_eq!(execution.next_chunk(), Some(1));
+
+    execution.failed.remove(&1);
+
+    assert_eq!(execution.next_chunk(), Some(3));
+
+    // every other chunk references the first one, so they all have to be submitted again.
+    execution.chunk_failed(2);
+    execution.chunk_failed(0);
+
+    assert!(execution.chunks().is_empty());
+    assert_eq!(execution.failed_chunks().count(), 0);
+    assert_eq!(execution.next_chunk(), Some(0));
+}
+
+#[test]
+fn chunked_execution_missing_chunks() {
+    let chunk = |index: u8| ExecutedChunk {
+        response: TransactionResponse {
+            node_account_id: 3.into(),
+            transaction_id: TransactionId::generate(5006.into()),
+            transaction_hash: TransactionHash::new(&[index]),
+            validate_status: true,
+        },
+        receipt: None,
+    };
+
+    let mut execution = ChunkedExecution::new();
+    execution.total_chunks = 5;
+    execution.chunk_landed(0, chunk(0));
+
+    // chunk 1 failed to submit while chunks 2 and 3 were in flight, those landed and are kept.
+    execution.chunk_landed(3, chunk(3));
+    execution.chunk_landed(2, chunk(2));
+    execution.missing_chunks_failed();
+
+    assert_eq!(execution.chunks().len(), 4);
+    assert!(execution.chunks()[1].is_none());
+    assert_eq!(execution.failed_chunks().collect::<Vec<_>>(), [1]);
+    assert_eq!(execution.next_chunk(), Some(1));
+
+    execution.chunk_landed(1, chunk(1));
+
+    assert_eq!(execution.failed_chunks().count(), 0);
+    assert_eq!(execution.next_chunk(), Some(4));
+    assert_eq!(execution.into_responses().len(), 4);
+}
+
+#[tokio::test]
+async fn execute_all_chunk_count_mismatch() {
+    let client = Client::for_testnet();
+    client.set_operator(0.into(), PrivateKey::generate_ed25519());
+
+    let mut tx = TopicMessageSubmitTransaction::new();
+
+    tx.topic_id(314).message(b"Hello, world!".to_vec()).chunk_size(4).node_account_ids([6.into()]);
+
+    let mut execution = ChunkedExecution::new();
+    execution.total_chunks = 3;
+
+    let error = tx.execute_all_resumable(&client, &mut execution).await.unwrap_err();
+
+    assert_matches!(error, Error::ChunkCountMismatch { expected: 3, actual: 4 });
+    assert!(execution.chunks().is_empty());
+}
@@ -0,0 +1,288 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+use std::fmt;
+
+use time::Duration;
+
+use crate::{
+    AccountId,
+    NftId,
+    TokenId,
+};
+
+/// The maximum length of a memo, in bytes.
+pub(crate) const MAX_MEMO_LEN: usize = 100;
+
+/// The maximum length of a token's name or symbol, in bytes.
+pub(crate) const MAX_TOKEN_NAME_LEN: usize = 100;
+
+/// The maximum number of hbar adjustments in a single transfer list.
+pub(crate) const MAX_HBAR_TRANSFERS: usize = 10;
+
+/// The maximum number of fungible token adjustments across all tokens in a transaction.
+pub(crate) const MAX_TOKEN_TRANSFERS: usize = 10;
+
+/// The maximum number of NFT transfers across all tokens in a transaction.
+pub(crate) const MAX_NFT_TRANSFERS: usize = 10;
+
+/// The maximum number of NFTs minted, burned, wiped, or updated in a single transaction.
+pub(crate) const MAX_NFT_BATCH_SIZE: usize = 10;
+
+/// The maximum length of a single NFT's metadata, in bytes.
+pub(crate) const MAX_NFT_METADATA_LEN: usize = 100;
+
+/// The shortest allowed transaction valid duration.
+pub(crate) const MIN_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(15);
+
+/// The longest allowed transaction valid duration.
+pub(crate) const MAX_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(180);
+
+/// The shortest allowed auto renew period.
+pub(crate) const MIN_AUTO_RENEW_PERIOD: Duration = Duration::seconds(2_592_000);
+
+/// The longest allowed auto renew period.
+pub(crate) const MAX_AUTO_RENEW_PERIOD: Duration = Duration::seconds(8_000_001);
+
+/// A problem with a single field of a transaction, found by [`Transaction::validate`](crate::Transaction::validate).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The name of the field with the problem, as named by its setter (for instance, `transaction_memo`).
+    pub field: &'static str,
+
+    /// What is wrong with the field.
+    pub kind: ValidationErrorKind,
+}
+
+impl ValidationError {
+    pub(crate) fn new(field: &'static str, kind: ValidationErrorKind) -> Self {
+        Self { field, kind }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`: {}", self.field, self.kind)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// The reason a [`ValidationError`] was reported.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ValidationErrorKind {
+    /// A string or byte field is longer than the network allows.
+    #[error("is {len} bytes long, but at most {max} are allowed")]
+    TooLong {
+        /// The length of the field, in bytes.
+        len: usize,
+
+        /// The maximum allowed length, in bytes.
+        max: usize,
+    },
+
+    /// A string field contains a zero byte, which the network rejects.
+    #[error("contains a zero byte")]
+    ContainsZeroByte,
+
+    /// A list field has more entries than the network allows.
+    #[error("has {count} entries, but at most {max} are allowed")]
+    TooManyEntries {
+        /// The number of entries.
+        count: usize,
+
+        /// The maximum allowed number of entries.
+        max: usize,
+    },
+
+    /// A duration is outside of the range the network allows.
+    #[error("{duration} is outside of the allowed range of {min} to {max}")]
+    DurationOutOfRange {
+        /// The duration that was set.
+        duration: Duration,
+
+        /// The shortest allowed duration.
+        min: Duration,
+
+        /// The longest allowed duration.
+        max: Duration,
+    },
+
+    /// The amounts of a transfer list don't sum to zero.
+    #[error("transfers for {} sum to {sum} instead of zero", .token_id.map_or_else(|| "hbar".to_owned(), |it| format!("`{it}`")))]
+    TransfersDoNotNetToZero {
+        /// The token whose transfers don't net to zero, or `None` for hbar transfers.
+        token_id: Option<TokenId>,
+
+        /// What the transfers sum to.
+        sum: i128,
+    },
+
+    /// The same account appears more than once in a single transfer list.
+    #[error("account `{account_id}` appears more than once in the transfers for {}", .token_id.map_or_else(|| "hbar".to_owned(), |it| format!("`{it}`")))]
+    DuplicateAccountId {
+        /// The token whose transfer list has the repeated account, or `None` for hbar transfers.
+        token_id: Option<TokenId>,
+
+        /// The repeated account.
+        account_id: AccountId,
+    },
+
+    /// The same token appears more than once.
+    #[error("token `{0}` appears more than once")]
+    DuplicateTokenId(TokenId),
+
+    /// The same NFT is transferred more than once.
+    #[error("NFT `{0}` is transferred more than once")]
+    DuplicateNftTransfer(NftId),
+
+    /// An NFT serial number is not positive.
+    #[error("serial number {0} is not positive")]
+    InvalidSerial(i64),
+
+    /// The same NFT serial number appears more than once.
+    #[error("serial number {0} appears more than once")]
+    DuplicateSerial(i64),
+
+    /// NFT serial numbers are not in ascending order.
+    #[error("serial numbers are not in ascending order")]
+    UnsortedSerials,
+}
+
+/// Checks that `memo` fits in [`MAX_MEMO_LEN`] bytes and has no zero bytes.
+pub(crate) fn validate_memo(field: &'static str, memo: &str, errors: &mut Vec<ValidationError>) {
+    validate_string(field, memo, MAX_MEMO_LEN, errors);
+}
+
+/// Checks that `value` fits in `max` bytes and has no zero bytes.
+pub(crate) fn validate_string(
+    field: &'static str,
+    value: &str,
+    max: usize,
+    errors: &mut Vec<ValidationError>,
+) {
+    if value.len() > max {
+        errors.push(ValidationError::new(
+            field,
+            ValidationErrorKind::TooLong { len: value.len(), max },
+        ));
+    }
+
+    if value.contains('\0') {
+        errors.push(ValidationError::new(field, ValidationErrorKind::ContainsZeroByte));
+    }
+}
+
+/// Checks that a list of `count` entries has at most `max` entries.
+pub(crate) fn validate_count(
+    field: &'static str,
+    count: usize,
+    max: usize,
+    errors: &mut Vec<ValidationError>,
+) {
+    if count > max {
+        errors
+            .push(ValidationError::new(field, ValidationErrorKind::TooManyEntries { count, max }));
+    }
+}
+
+/// Checks that `duration` is in `min..=max`.
+pub(crate) fn validate_duration(
+    field: &'static str,
+    duration: Duration,
+    min: Duration,
+    max: Duration,
+    errors: &mut Vec<ValidationError>,
+) {
+    if duration < min || duration > max {
+        errors.push(ValidationError::new(
+            field,
+            ValidationErrorKind::DurationOutOfRange { duration, min, max },
+        ));
+    }
+}
+
+/// Checks that `period`, if set, is a valid auto renew period.
+pub(crate) fn validate_auto_renew_period(
+    field: &'static str,
+    period: Option<Duration>,
+    errors: &mut Vec<ValidationError>,
+) {
+    if let Some(period) = period {
+        validate_duration(field, period, MIN_AUTO_RENEW_PERIOD, MAX_AUTO_RENEW_PERIOD, errors);
+    }
+}
+
+/// Checks that `token_ids` has no duplicates.
+pub(crate) fn validate_unique_token_ids(
+    field: &'static str,
+    token_ids: impl IntoIterator<Item = TokenId>,
+    errors: &mut Vec<ValidationError>,
+) {
+    let mut seen = HashSet::new();
+
+    for token_id in token_ids {
+        if !seen.insert(token_id) {
+            errors
+                .push(ValidationError::new(field, ValidationErrorKind::DuplicateTokenId(token_id)));
+        }
+    }
+}
+
+/// Checks a batch of NFT serial numbers.
+///
+/// Serials must be positive, unique, and, while the network itself doesn't require it, in ascending order,
+/// so that the same batch always serializes (and is therefore signed) the same way.
+pub(crate) fn validate_serials(
+    field: &'static str,
+    serials: &[i64],
+    errors: &mut Vec<ValidationError>,
+) {
+    validate_count(field, serials.len(), MAX_NFT_BATCH_SIZE, errors);
+
+    let mut seen = HashSet::with_capacity(serials.len());
+
+    for &serial in serials {
+        if serial <= 0 {
+            errors.push(ValidationError::new(field, ValidationErrorKind::InvalidSerial(serial)));
+        }
+
+        if !seen.insert(serial) {
+            errors.push(ValidationError::new(field, ValidationErrorKind::DuplicateSerial(serial)));
+        }
+    }
+
+    if serials.windows(2).any(|it| it[0] > it[1]) {
+        errors.push(ValidationError::new(field, ValidationErrorKind::UnsortedSerials));
+    }
+}
+
+/// Checks that a transfer list of `(account, amount)` pairs nets to zero and has no repeated accounts.
+pub(crate) fn validate_transfer_list(
+    field: &'static str,
+    token_id: Option<TokenId>,
+    transfers: impl IntoIterator<Item = (AccountId, i64)>,
+    errors: &mut Vec<ValidationError>,
+) {
+    let mut seen = HashSet::new();
+    let mut sum: i128 = 0;
+
+    for (account_id, amount) in transfers {
+        sum += i128::from(amount);
+
+        if !seen.insert(account_id) {
+            errors.push(ValidationError::new(
+                field,
+                ValidationErrorKind::DuplicateAccountId { token_id, account_id },
+            ));
+        }
+    }
+
+    if sum != 0 {
+        errors.push(ValidationError::new(
+            field,
+            ValidationErrorKind::TransfersDoNotNetToZero { token_id, sum },
+        ));
+    }
+}
@@ -134,10 +134,12 @@ impl QueryExecute for TransactionRecordQueryData {
         let record = TransactionRecord::from_protobuf(response)?;
 
         if self.validate_status && record.receipt.status != Status::Success {
-            return Err(Error::ReceiptStatus {
+            let error = Error::ReceiptStatus {
                 transaction_id: self.transaction_id.map(Box::new),
                 status: record.receipt.status,
-            });
+            };
+
+            return Err(error.with_revert_reason(record.contract_function_result.as_ref()));
         }
 
         Ok(record)
@@ -152,11 +154,52 @@ impl ValidateChecksums for TransactionRecordQueryData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
-
-    use crate::query::ToQueryProtobuf;
+    use hedera_proto::services;
+    use time::OffsetDateTime;
+
+    use crate::contract::abi::codec;
+    use crate::protobuf::ToProtobuf;
+    use crate::query::{
+        QueryExecute,
+        ToQueryProtobuf,
+    };
     use crate::transaction::test_helpers::TEST_TX_ID;
-    use crate::TransactionRecordQuery;
+    use crate::{
+        AbiType,
+        ContractId,
+        ContractRevertReason,
+        Error,
+        Status,
+        TransactionRecordQuery,
+    };
+
+    fn record_response(status: Status) -> services::response::Response {
+        let revert_data = codec::encode(&[AbiType::String], &["nope".into()]).unwrap();
+
+        services::response::Response::TransactionGetRecord(services::TransactionGetRecordResponse {
+            header: None,
+            transaction_record: Some(services::TransactionRecord {
+                receipt: Some(services::TransactionReceipt {
+                    status: status as i32,
+                    ..Default::default()
+                }),
+                consensus_timestamp: Some(OffsetDateTime::UNIX_EPOCH.into()),
+                transaction_id: Some(TEST_TX_ID.to_protobuf()),
+                body: Some(services::transaction_record::Body::ContractCallResult(
+                    services::ContractFunctionResult {
+                        contract_id: Some(ContractId::new(0, 0, 1001).to_protobuf()),
+                        error_message: format!("0x08c379a0{}", hex::encode(revert_data)),
+                        ..Default::default()
+                    },
+                )),
+                ..Default::default()
+            }),
+            duplicate_transaction_records: Vec::new(),
+            child_transaction_records: Vec::new(),
+        })
+    }
 
     #[test]
     fn serialize() {
@@ -243,4 +286,26 @@ mod tests {
 
         assert_eq!(query.get_validate_status(), true);
     }
+
+    #[test]
+    fn revert_reason() {
+        let mut query = TransactionRecordQuery::new();
+        query.transaction_id(TEST_TX_ID).validate_status(true);
+
+        let error = query.data.make_response(record_response(Status::ContractRevertExecuted));
+
+        let (error, reason) =
+            assert_matches!(error, Err(Error::ContractRevert { error, reason }) => (error, reason));
+
+        assert_matches!(
+            *error,
+            Error::ReceiptStatus { status: Status::ContractRevertExecuted, .. }
+        );
+        assert_eq!(*reason, ContractRevertReason::Error("nope".to_owned()));
+
+        // only reverts have a revert reason.
+        let error = query.data.make_response(record_response(Status::InsufficientGas));
+
+        assert_matches!(error, Err(Error::ReceiptStatus { status: Status::InsufficientGas, .. }));
+    }
 }
@@ -69,6 +69,8 @@ impl TransactionResponse {
     /// Get the receipt for this transaction.
     /// Will wait for consensus.
     ///
+    /// Receipts don't say why a contract reverted, the [record](Self::get_record) does.
+    ///
     /// # Errors
     /// - if [`validate_status`](Self.validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt.
@@ -81,6 +83,8 @@ impl TransactionResponse {
     /// Get the receipt for this transaction.
     /// Will wait for consensus.
     ///
+    /// Receipts don't say why a contract reverted, the [record](Self::get_record) does.
+    ///
     /// # Errors
     /// - if [`validate_status`](Self.validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt.
@@ -97,7 +101,8 @@ impl TransactionResponse {
     ///
     /// # Errors
     /// - if [`validate_status`](Self.validate_status) is `true`:
-    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record,
+    ///   or [`Error::ContractRevert`](crate::Error::ContractRevert) for a contract that reverted.
     pub async fn get_record(&self, client: &Client) -> crate::Result<TransactionRecord> {
         self.get_record_query().execute(client).await
     }
@@ -107,7 +112,8 @@ impl TransactionResponse {
     ///
     /// # Errors
     /// - if [`validate_status`](Self.validate_status) is `true`:
-    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record,
+    ///   or [`Error::ContractRevert`](crate::Error::ContractRevert) for a contract that reverted.
     pub async fn get_record_with_timeout(
         &self,
         client: &Client,
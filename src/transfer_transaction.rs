@@ -1,6 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashMap;
+use std::collections::{
+    HashMap,
+    HashSet,
+};
 use std::ops::Not;
 
 use hedera_proto::services;
@@ -9,6 +12,11 @@ use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
+use crate::transaction::validation::{
+    self,
+    ValidationError,
+    ValidationErrorKind,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -286,7 +294,81 @@ impl TransactionExecute for TransferTransactionData {
     }
 }
 
-impl TransactionData for TransferTransactionData {}
+impl TransactionData for TransferTransactionData {
+    fn validate_data(&self, errors: &mut Vec<ValidationError>) {
+        validation::validate_count(
+            "hbar_transfers",
+            self.transfers.len(),
+            validation::MAX_HBAR_TRANSFERS,
+            errors,
+        );
+
+        if !self.transfers.is_empty() {
+            validation::validate_transfer_list(
+                "hbar_transfers",
+                None,
+                self.transfers.iter().map(|it| (it.account_id, it.amount)),
+                errors,
+            );
+        }
+
+        validate_token_transfers(&self.token_transfers, errors);
+    }
+}
+
+/// Checks the token transfer lists of a transfer or airdrop.
+pub(crate) fn validate_token_transfers(
+    token_transfers: &[TokenTransfer],
+    errors: &mut Vec<ValidationError>,
+) {
+    validation::validate_unique_token_ids(
+        "token_transfers",
+        token_transfers.iter().map(|it| it.token_id),
+        errors,
+    );
+
+    let fungible_count = token_transfers.iter().map(|it| it.transfers.len()).sum();
+    validation::validate_count(
+        "token_transfers",
+        fungible_count,
+        validation::MAX_TOKEN_TRANSFERS,
+        errors,
+    );
+
+    let nft_count = token_transfers.iter().map(|it| it.nft_transfers.len()).sum();
+    validation::validate_count("nft_transfers", nft_count, validation::MAX_NFT_TRANSFERS, errors);
+
+    let mut seen_nfts = HashSet::new();
+
+    for token_transfer in token_transfers {
+        if !token_transfer.transfers.is_empty() {
+            validation::validate_transfer_list(
+                "token_transfers",
+                Some(token_transfer.token_id),
+                token_transfer.transfers.iter().map(|it| (it.account_id, it.amount)),
+                errors,
+            );
+        }
+
+        for nft_transfer in &token_transfer.nft_transfers {
+            let nft_id = NftId { token_id: token_transfer.token_id, serial: nft_transfer.serial };
+
+            if nft_transfer.serial == 0 || nft_transfer.serial > i64::MAX as u64 {
+                errors.push(ValidationError::new(
+                    "nft_transfers",
+                    ValidationErrorKind::InvalidSerial(nft_transfer.serial as i64),
+                ));
+            }
+
+            if !seen_nfts.insert(nft_id) {
+                errors.push(ValidationError::new(
+                    "nft_transfers",
+                    ValidationErrorKind::DuplicateNftTransfer(nft_id),
+                ));
+            }
+        }
+    }
+}
 
 impl ValidateChecksums for TransferTransactionData {
     fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {